@@ -0,0 +1,260 @@
+use rand::Rng;
+use schmimmdee::{
+    Base64Alphabet, Base64Config, Base64Error, format_ns, format_number, simd_base64_decode,
+    simd_base64_encode,
+};
+use std::{hint::black_box, time::Instant};
+
+const CONFIGS: [(&str, Base64Config); 4] = [
+    ("standard", Base64Config::STANDARD),
+    ("no pad", Base64Config::STANDARD_NO_PAD),
+    ("url", Base64Config::URL_SAFE),
+    ("url no pad", Base64Config::URL_SAFE_NO_PAD),
+];
+
+fn main() {
+    let mut rng = rand::rng();
+    let sizes = [1_000, 100_000, 1_000_000];
+    let trials = 50;
+
+    let inputs: Vec<Vec<u8>> = sizes
+        .iter()
+        .map(|&size| (0..size).map(|_| rng.random()).collect())
+        .collect();
+
+    print_header(" Base64 Encode Benchmark ");
+    for (name, config) in CONFIGS {
+        for data in &inputs {
+            let (avg_scalar, avg_simd) = bench(
+                trials,
+                || scalar_base64_encode(data, config),
+                || simd_base64_encode(data, config),
+            );
+
+            let valid = scalar_base64_encode(data, config) == simd_base64_encode(data, config);
+            print_row(name, data.len(), avg_scalar, avg_simd, valid);
+            assert!(valid, "{name} encoding doesn't match");
+        }
+    }
+    println!("{:-^80}", "");
+
+    println!();
+
+    print_header(" Base64 Decode Benchmark ");
+    for (name, config) in CONFIGS {
+        for data in &inputs {
+            let encoded = simd_base64_encode(data, config);
+            let (avg_scalar, avg_simd) = bench(
+                trials,
+                || scalar_base64_decode(&encoded, config),
+                || simd_base64_decode(&encoded, config),
+            );
+
+            let valid = scalar_base64_decode(&encoded, config).as_ref() == Some(data)
+                && simd_base64_decode(&encoded, config).as_ref() == Ok(data);
+            print_row(name, data.len(), avg_scalar, avg_simd, valid);
+            assert!(valid, "{name} decoding doesn't round trip");
+        }
+    }
+    println!("{:-^80}", "");
+
+    check_round_trips(&inputs[0]);
+    check_bad_chars(&inputs[0]);
+    check_padding();
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>10} | {:>10} | {:>12} | {:>12} | {:>9} | {:>9} |",
+        "Config", "Bytes", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^12}|{:-^12}|{:-^14}|{:-^14}|{:-^11}|{:-^11}|",
+        "", "", "", "", "", ""
+    );
+}
+
+fn print_row(name: &str, size: usize, avg_scalar: f64, avg_simd: f64, valid: bool) {
+    println!(
+        "| {:>10} | {:>10} | {:>12} | {:>12} | {:>8.2}x | {:>9} |",
+        name,
+        format_number(size),
+        format_ns(avg_scalar),
+        format_ns(avg_simd),
+        avg_scalar / avg_simd,
+        if valid { "✓" } else { "✗" }
+    );
+}
+
+/// Every length up to a few vectors, so every tail length in every config, against
+/// the scalar encoder and back.
+fn check_round_trips(data: &[u8]) {
+    let valid = CONFIGS.iter().all(|&(_, config)| {
+        (0..=200).all(|len| {
+            let encoded = simd_base64_encode(&data[..len], config);
+            encoded == scalar_base64_encode(&data[..len], config)
+                && simd_base64_decode(&encoded, config).as_deref() == Ok(&data[..len])
+        })
+    });
+    println!(
+        "Every length 0 to 200 round trips in every config: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "a length doesn't round trip");
+}
+
+/// Bad characters dropped in at offsets in the SIMD loop and the tail, in every
+/// config, are reported at their offset.
+fn check_bad_chars(data: &[u8]) {
+    let mut valid = true;
+    for (name, config) in CONFIGS {
+        let encoded = simd_base64_encode(&data[..300], config);
+        // The other alphabet's 62nd character
+        let other = match config.alphabet {
+            Base64Alphabet::Standard => '-',
+            Base64Alphabet::UrlSafe => '+',
+        };
+        let last = encoded.len() - 6;
+        for offset in [0, 1, 15, 16, 17, 63, 64, 250, last] {
+            let with = |bad: &str| {
+                let mut bad_encoding = encoded.clone();
+                bad_encoding.replace_range(offset..offset + bad.len(), bad);
+                simd_base64_decode(&bad_encoding, config)
+            };
+            let expected = |ch| Err(Base64Error::InvalidChar { offset, ch });
+            let ok = with("!") == expected('!')
+                && with(&other.to_string()) == expected(other)
+                && with("é") == expected('é')
+                && with("=") == Err(Base64Error::InvalidPadding { offset });
+            if !ok {
+                println!("{name}: bad character at {offset} not reported");
+            }
+            valid &= ok;
+        }
+    }
+    println!(
+        "Bad characters are reported at their offset: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "bad characters misreported");
+}
+
+/// Padding and length errors in the final quantum.
+fn check_padding() {
+    let (pad, no_pad) = (Base64Config::STANDARD, Base64Config::STANDARD_NO_PAD);
+    let cases = [
+        ("QQ==", pad, Ok(b"A".to_vec())),
+        ("QUI=", pad, Ok(b"AB".to_vec())),
+        ("QQ", no_pad, Ok(b"A".to_vec())),
+        ("QQ", pad, Err(Base64Error::InvalidLength(2))),
+        ("QUJDR", no_pad, Err(Base64Error::InvalidLength(5))),
+        (
+            "QUJDR===",
+            pad,
+            Err(Base64Error::InvalidPadding { offset: 5 }),
+        ),
+        ("Q===", pad, Err(Base64Error::InvalidPadding { offset: 1 })),
+        (
+            "QQ==",
+            no_pad,
+            Err(Base64Error::InvalidPadding { offset: 2 }),
+        ),
+        ("Q=Q=", pad, Err(Base64Error::InvalidPadding { offset: 1 })),
+        ("QR==", pad, Err(Base64Error::TrailingBits { offset: 1 })),
+        ("QUJ=", pad, Err(Base64Error::TrailingBits { offset: 2 })),
+        ("QR", no_pad, Err(Base64Error::TrailingBits { offset: 1 })),
+    ];
+    let mut valid = true;
+    for (encoded, config, expected) in cases {
+        let decoded = simd_base64_decode(encoded, config);
+        if decoded != expected {
+            println!("{encoded:?}: expected {expected:?}, got {decoded:?}");
+            valid = false;
+        }
+    }
+    println!(
+        "Bad padding and lengths are reported: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "bad padding misreported");
+}
+
+/// Average nanos per call of each implementation, after a warmup.
+fn bench<A, B>(trials: u32, scalar: impl Fn() -> A, simd: impl Fn() -> B) -> (f64, f64) {
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(scalar());
+        black_box(simd());
+    });
+
+    let scalar_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(scalar());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    let simd_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(simd());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    (
+        scalar_time as f64 / trials as f64,
+        simd_time as f64 / trials as f64,
+    )
+}
+
+fn alphabet(config: Base64Config) -> [u8; 64] {
+    let mut table = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    if config.alphabet == Base64Alphabet::UrlSafe {
+        (table[62], table[63]) = (b'-', b'_');
+    }
+    table
+}
+
+fn scalar_base64_encode(bytes: &[u8], config: Base64Config) -> String {
+    let table = alphabet(config);
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for group in bytes.chunks(3) {
+        let n = group
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..=group.len() {
+            out.push(table[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+        if config.padding {
+            (group.len()..3).for_each(|_| out.push('='));
+        }
+    }
+    out
+}
+
+/// Only for valid input: no error offsets, just `None`.
+fn scalar_base64_decode(encoded: &str, config: Base64Config) -> Option<Vec<u8>> {
+    let mut sextets = [None; 256];
+    for (sextet, &c) in alphabet(config).iter().enumerate() {
+        sextets[c as usize] = Some(sextet as u32);
+    }
+    let data = if config.padding {
+        encoded.trim_end_matches('=')
+    } else {
+        encoded
+    };
+    let mut out = Vec::with_capacity(data.len() / 4 * 3 + 2);
+    for quantum in data.as_bytes().chunks(4) {
+        let n = quantum.iter().enumerate().try_fold(0u32, |n, (i, c)| {
+            let sextet = sextets[*c as usize]?;
+            Some(n | sextet << (18 - 6 * i))
+        })?;
+        let bytes = n.to_be_bytes();
+        out.extend_from_slice(&bytes[1..quantum.len()]);
+    }
+    Some(out)
+}
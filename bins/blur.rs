@@ -0,0 +1,326 @@
+use image::{GrayImage, RgbaImage};
+use schmimmdee::{
+    Blur, EdgeMode, GaussianKernel, KernelError, SimdImageExt, format_ns, simd_blur_luma,
+    simd_blur_rgba,
+};
+use std::{hint::black_box, time::Instant};
+
+fn main() -> Result<(), image::ImageError> {
+    let img = image::io::Reader::open("./assets/lenna.png")?.decode()?;
+    let rgba_img = img.to_rgba8();
+    let luma_img = img.simd_grayscale();
+    let (width, height) = (rgba_img.width() as usize, rgba_img.height() as usize);
+    let (rgba, _) = rgba_img.as_raw().as_chunks::<4>();
+
+    print_header(" Gaussian blur (lenna) vs image::imageops::blur ");
+    for sigma in [1.0, 3.0, 8.0] {
+        compare_gaussian_rgba(&rgba_img, sigma);
+    }
+    for sigma in [1.0, 3.0, 8.0] {
+        compare_gaussian_luma(&luma_img, sigma);
+    }
+    println!("{:-^80}", "");
+
+    println!();
+    print_box_header(" Box blur (lenna, RGBA) vs a scalar sum per pixel ");
+    for radius in [1, 4, 16, 64] {
+        compare_box(rgba, width, height, radius);
+    }
+    println!("{:-^80}", "");
+
+    check_edge_modes(luma_img.as_raw(), width, height);
+    check_huge_radius();
+    check_custom_kernel(luma_img.as_raw(), width, height);
+    check_kernel_errors();
+
+    // Save results
+    let kernel = GaussianKernel::new(2 * 6 + 1, 3.0).expect("odd size, positive sigma");
+    let blurred = simd_blur_rgba(
+        rgba,
+        width,
+        height,
+        &Blur::Gaussian(kernel),
+        EdgeMode::Clamp,
+    );
+    RgbaImage::from_raw(width as u32, height as u32, blurred.concat())
+        .expect("same size as lenna")
+        .save("lena_simd_blur.png")?;
+    image::imageops::blur(&rgba_img, 3.0).save("lena_baseline_blur.png")?;
+
+    Ok(())
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>5} | {:>5} | {:>12} | {:>12} | {:>8} | {:>8} | {:>7} |",
+        "Image", "Sigma", "imageops", "SIMD", "Speedup", "Max diff", "Valid"
+    );
+    println!(
+        "|{:-^7}|{:-^7}|{:-^14}|{:-^14}|{:-^10}|{:-^10}|{:-^9}|",
+        "", "", "", "", "", "", ""
+    );
+}
+
+/// `imageops::blur` weights taps out to two sigmas, and near the edges drops the
+/// ones that fall off and scales up the rest, so this uses the same size kernel and
+/// only compares pixels that far in.
+fn matching_kernel(sigma: f32) -> (GaussianKernel, usize) {
+    let radius = (2.0 * sigma).ceil() as usize;
+    let kernel = GaussianKernel::new(2 * radius + 1, sigma).expect("odd size, positive sigma");
+    (kernel, radius)
+}
+
+fn compare_gaussian_rgba(img: &RgbaImage, sigma: f32) {
+    let (width, height) = (img.width() as usize, img.height() as usize);
+    let (rgba, _) = img.as_raw().as_chunks::<4>();
+    let (kernel, radius) = matching_kernel(sigma);
+    let blur = Blur::Gaussian(kernel);
+
+    let (baseline, baseline_time) = time(3, || image::imageops::blur(img, sigma));
+    let (blurred, simd_time) = time(20, || {
+        simd_blur_rgba(black_box(rgba), width, height, &blur, EdgeMode::Clamp)
+    });
+
+    let max_diff = interior_diff(blurred.as_flattened(), &baseline, width, height, 4, radius);
+    print_row("RGBA", sigma, baseline_time, simd_time, max_diff);
+}
+
+fn compare_gaussian_luma(img: &GrayImage, sigma: f32) {
+    let (width, height) = (img.width() as usize, img.height() as usize);
+    let (kernel, radius) = matching_kernel(sigma);
+    let blur = Blur::Gaussian(kernel);
+
+    let (baseline, baseline_time) = time(3, || image::imageops::blur(img, sigma));
+    let (blurred, simd_time) = time(20, || {
+        simd_blur_luma(
+            black_box(img.as_raw()),
+            width,
+            height,
+            &blur,
+            EdgeMode::Clamp,
+        )
+    });
+
+    let max_diff = interior_diff(&blurred, &baseline, width, height, 1, radius);
+    print_row("Luma", sigma, baseline_time, simd_time, max_diff);
+}
+
+fn print_row(image: &str, sigma: f32, baseline_time: f64, simd_time: f64, max_diff: u8) {
+    // Fixed-point weights against `f32` ones, so a step either way on a rounding edge
+    let valid = max_diff <= 1;
+    println!(
+        "| {:>5} | {:>5} | {:>12} | {:>12} | {:>7.2}x | {:>8} | {:>7} |",
+        image,
+        sigma,
+        format_ns(baseline_time),
+        format_ns(simd_time),
+        baseline_time / simd_time,
+        max_diff,
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "{image} sigma {sigma} is {max_diff} off imageops");
+}
+
+/// Largest difference between `a` and `b`, leaving out `border` pixels all round.
+fn interior_diff(
+    a: &[u8],
+    b: &[u8],
+    width: usize,
+    height: usize,
+    channels: usize,
+    border: usize,
+) -> u8 {
+    let row = width * channels;
+    let cols = border * channels..(width - border) * channels;
+    (border..height - border)
+        .flat_map(|y| {
+            a[y * row..][cols.clone()]
+                .iter()
+                .zip(&b[y * row..][cols.clone()])
+        })
+        .map(|(&a, &b)| a.abs_diff(b))
+        .max()
+        .unwrap_or(0)
+}
+
+fn print_box_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>8} | {:>15} | {:>15} | {:>10} | {:>16} |",
+        "Radius", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^10}|{:-^17}|{:-^17}|{:-^12}|{:-^18}|",
+        "", "", "", "", ""
+    );
+}
+
+fn compare_box(rgba: &[[u8; 4]], width: usize, height: usize, radius: usize) {
+    let blur = Blur::Box { radius };
+    let (expected, scalar_time) = time(1, || box_blur_scalar(rgba, width, height, radius));
+    let (blurred, simd_time) = time(20, || {
+        simd_blur_rgba(black_box(rgba), width, height, &blur, EdgeMode::Clamp)
+    });
+
+    let valid = blurred == expected;
+    println!(
+        "| {:>8} | {:>15} | {:>15} | {:>9.2}x | {:>16} |",
+        radius,
+        format_ns(scalar_time),
+        format_ns(simd_time),
+        scalar_time / simd_time,
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "box blur radius {radius} doesn't match");
+}
+
+/// Every output pixel summed from scratch over its whole square, with clamped edges.
+fn box_blur_scalar(rgba: &[[u8; 4]], width: usize, height: usize, radius: usize) -> Vec<[u8; 4]> {
+    let r = radius as isize;
+    let size = 2 * radius + 1;
+    let area = (size * size) as u64;
+    let clamp = |i: isize, len: usize| i.clamp(0, len as isize - 1) as usize;
+
+    // Across first, so it's `size` additions per pixel per pass rather than `size²`
+    let mut across = vec![[0u64; 4]; rgba.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let sum = &mut across[y * width + x];
+            for dx in -r..=r {
+                let pixel = rgba[y * width + clamp(x as isize + dx, width)];
+                (0..4).for_each(|c| sum[c] += pixel[c] as u64);
+            }
+        }
+    }
+
+    let mut output = vec![[0; 4]; rgba.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0u64; 4];
+            for dy in -r..=r {
+                let row_sum = across[clamp(y as isize + dy, height) * width + x];
+                (0..4).for_each(|c| sum[c] += row_sum[c]);
+            }
+            // Rounded to nearest, in integers
+            output[y * width + x] = sum.map(|s| ((2 * s + area) / (2 * area)) as u8);
+        }
+    }
+    output
+}
+
+/// The edge modes only differ within a radius of the edge.
+fn check_edge_modes(luma: &[u8], width: usize, height: usize) {
+    let radius = 8;
+    let blur = Blur::Box { radius };
+    let blurred = [EdgeMode::Clamp, EdgeMode::Mirror, EdgeMode::Wrap]
+        .map(|edge| simd_blur_luma(luma, width, height, &blur, edge));
+
+    let interiors_match = blurred[1..]
+        .iter()
+        .all(|other| interior_diff(&blurred[0], other, width, height, 1, radius) == 0);
+    let edges_differ = blurred[0] != blurred[1] && blurred[0] != blurred[2];
+    let valid = interiors_match && edges_differ;
+    println!(
+        "Edge modes (clamp, mirror, wrap) agree away from the edges: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "edge modes disagree in the interior");
+}
+
+/// Radii whose sums don't fit in `u32`, or in an `f32` exactly.
+fn check_huge_radius() {
+    let (width, height, radius) = (5000, 1, 2100);
+    let white = vec![[255; 4]; width * height];
+    let blurred = simd_blur_rgba(
+        &white,
+        width,
+        height,
+        &Blur::Box { radius },
+        EdgeMode::Clamp,
+    );
+    let mut valid = blurred == white;
+
+    let pattern: Vec<[u8; 4]> = (0..width * 2)
+        .map(|i| [(i * 7) as u8, (i * 13) as u8, (i / 20) as u8, 255])
+        .collect();
+    let blurred = simd_blur_rgba(&pattern, width, 2, &Blur::Box { radius }, EdgeMode::Clamp);
+    valid &= blurred == box_blur_scalar(&pattern, width, 2, radius);
+    println!(
+        "Box blur radius {radius} over {width} pixels matches: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "box blur radius {radius} doesn't match");
+}
+
+/// A lopsided kernel of just its first weight reads a pixel up and to the left.
+fn check_custom_kernel(luma: &[u8], width: usize, height: usize) {
+    let kernel = GaussianKernel::from_weights(&[1.0, 0.0, 0.0]).expect("odd, positive sum");
+    let blurred = simd_blur_luma(
+        luma,
+        width,
+        height,
+        &Blur::Gaussian(kernel),
+        EdgeMode::Clamp,
+    );
+    let shifted = (0..height).flat_map(|y| {
+        (0..width).map(move |x| luma[y.saturating_sub(1) * width + x.saturating_sub(1)])
+    });
+    let valid = blurred.iter().copied().eq(shifted);
+    println!(
+        "Custom kernel [1, 0, 0] shifts by a pixel: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "custom kernel doesn't shift");
+}
+
+fn check_kernel_errors() {
+    let results = [
+        (GaussianKernel::new(4, 1.0), KernelError::EvenSize(4)),
+        (GaussianKernel::new(3, 0.0), KernelError::InvalidSigma(0.0)),
+        (
+            GaussianKernel::with_sigma(1e19),
+            KernelError::TooLarge(usize::MAX),
+        ),
+        (GaussianKernel::from_weights(&[]), KernelError::EvenSize(0)),
+        (
+            GaussianKernel::from_weights(&[0.0; 3]),
+            KernelError::InvalidWeights,
+        ),
+        (
+            GaussianKernel::from_weights(&[1.0, -1.0, 1.0]),
+            KernelError::InvalidWeights,
+        ),
+        (
+            GaussianKernel::from_weights(&[f64::NAN; 3]),
+            KernelError::InvalidWeights,
+        ),
+    ];
+    let mut valid = results
+        .iter()
+        .all(|(result, error)| result.as_ref().err() == Some(error));
+    // Finite, but summing them directly overflows to infinity
+    let huge = GaussianKernel::from_weights(&[1e308, 1e308, 1e308]);
+    valid &= huge == GaussianKernel::from_weights(&[1.0, 1.0, 1.0]);
+    let tiny = GaussianKernel::from_weights(&[5e-324, 1e-323, 5e-324]);
+    valid &= tiny == GaussianKernel::from_weights(&[1.0, 2.0, 1.0]);
+    valid &= Blur::box_radius(usize::MAX) == Err(KernelError::TooLarge(usize::MAX));
+    valid &= Blur::box_radius(2100) == Ok(Blur::Box { radius: 2100 });
+    println!(
+        "Bad kernels and radii are rejected, extreme weights aren't: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "a bad kernel got through");
+}
+
+/// The last result of `f`, and average nanos per call over `trials` (after one to
+/// warm up).
+fn time<T>(trials: u32, f: impl Fn() -> T) -> (T, f64) {
+    black_box(f());
+    let start = Instant::now();
+    for _ in 1..trials {
+        black_box(f());
+    }
+    let result = f();
+    (result, start.elapsed().as_nanos() as f64 / trials as f64)
+}
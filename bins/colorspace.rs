@@ -0,0 +1,306 @@
+use schmimmdee::{
+    ChromaSubsampling, PixelLayout, YcbcrFormat, YcbcrImage, YcbcrMatrix, YcbcrRange, format_ns,
+    simd_adjust_hue_saturation, simd_hsl_to_rgba, simd_hsv_to_rgba, simd_rgb_to_ycbcr,
+    simd_rgba_to_hsl, simd_rgba_to_hsv, simd_ycbcr_to_rgb,
+};
+use std::{hint::black_box, time::Instant};
+
+fn main() -> Result<(), image::ImageError> {
+    let img = image::io::Reader::open("./assets/lenna.png")?.decode()?;
+    let rgb_img = img.to_rgb8();
+    let (width, height) = (rgb_img.width() as usize, rgb_img.height() as usize);
+    let rgb = rgb_img.as_raw();
+
+    print_header(" RGB <-> YCbCr (lenna, RGB) ");
+    for matrix in [YcbcrMatrix::Bt601, YcbcrMatrix::Bt709] {
+        for range in [YcbcrRange::Full, YcbcrRange::Limited] {
+            for subsampling in [
+                ChromaSubsampling::Yuv444,
+                ChromaSubsampling::Yuv422,
+                ChromaSubsampling::Yuv420,
+            ] {
+                let format = YcbcrFormat {
+                    matrix,
+                    range,
+                    subsampling,
+                };
+                compare_format(rgb, width, height, format);
+            }
+        }
+    }
+    println!("{:-^80}", "");
+
+    check_greys();
+    check_tiny();
+
+    let rgba_img = img.to_rgba8();
+    let (rgba, _) = rgba_img.as_raw().as_chunks::<4>();
+    println!();
+    print_hue_header(" RGBA <-> HSV/HSL (lenna, round trip of every colour) ");
+    compare_hue_model("HSV", rgba, simd_rgba_to_hsv, simd_hsv_to_rgba);
+    compare_hue_model("HSL", rgba, simd_rgba_to_hsl, simd_hsl_to_rgba);
+    println!("{:-^80}", "");
+
+    check_adjust(rgba);
+
+    Ok(())
+}
+
+/// Times the round trip in `format`, and checks the planes against an exact `f64`
+/// encode (averaged over each block for subsampled chroma).
+fn compare_format(rgb: &[u8], width: usize, height: usize, format: YcbcrFormat) {
+    let trials = 50;
+    let encode = || simd_rgb_to_ycbcr(black_box(rgb), PixelLayout::Rgb, width, height, format);
+    let ycbcr = encode();
+    let decode = || simd_ycbcr_to_rgb(black_box(&ycbcr), PixelLayout::Rgb);
+    let encode_time = average_ns(trials, encode);
+    let decode_time = average_ns(trials, decode);
+
+    // Half a step for rounding each pixel, and half again for averaging chroma
+    let max_error = max_error(&ycbcr, &reference(rgb, width, height, format));
+    let valid = max_error <= 1.0 + 1e-9;
+    println!(
+        "| {:<22} | {:>10} | {:>10} | {:>9.2} | {:>8.1}dB | {:>5} |",
+        format!("{} {} {}", format.matrix, format.range, format.subsampling),
+        format_ns(encode_time),
+        format_ns(decode_time),
+        max_error,
+        psnr(&decode(), rgb),
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "{format:?} is {max_error:.2} off the reference");
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:<22} | {:>10} | {:>10} | {:>9} | {:>10} | {:>5} |",
+        "Format", "Encode", "Decode", "Max error", "Round trip", "Valid"
+    );
+    println!(
+        "|{:-^24}|{:-^12}|{:-^12}|{:-^11}|{:-^12}|{:-^7}|",
+        "", "", "", "", "", ""
+    );
+}
+
+/// Average nanos per call, after a warmup.
+fn average_ns<T>(trials: u32, f: impl Fn() -> T) -> f64 {
+    // warmup to prevent the first format from paying for a cold cache
+    (0..3).for_each(|_| {
+        black_box(f());
+    });
+    let start = Instant::now();
+    for _ in 0..trials {
+        black_box(f());
+    }
+    start.elapsed().as_nanos() as f64 / trials as f64
+}
+
+/// The planes `format` calls for, unrounded.
+struct Reference {
+    y: Vec<f64>,
+    cb: Vec<f64>,
+    cr: Vec<f64>,
+}
+
+/// Each pixel encoded in `f64` from the textbook formulas, then chroma averaged down.
+fn reference(rgb: &[u8], width: usize, height: usize, format: YcbcrFormat) -> Reference {
+    let [kr, kg, kb] = format.matrix.luma_standard().coefficients();
+    let (y_scale, c_scale, y_offset) = match format.range {
+        YcbcrRange::Full => (1.0, 1.0, 0.0),
+        YcbcrRange::Limited => (219.0 / 255.0, 224.0 / 255.0, 16.0),
+    };
+
+    let (mut y, mut cb, mut cr) = (Vec::new(), Vec::new(), Vec::new());
+    for pixel in rgb.chunks_exact(3) {
+        let [r, g, b] = [pixel[0], pixel[1], pixel[2]].map(|c| c as f64);
+        let luma = kr * r + kg * g + kb * b;
+        y.push(y_offset + luma * y_scale);
+        cb.push(128.0 + (b - luma) / (2.0 * (1.0 - kb)) * c_scale);
+        cr.push(128.0 + (r - luma) / (2.0 * (1.0 - kr)) * c_scale);
+    }
+
+    let (chroma_width, chroma_height) = format.subsampling.chroma_size(width, height);
+    let (block_width, block_height) =
+        (width.div_ceil(chroma_width), height.div_ceil(chroma_height));
+    let average = |plane: &[f64]| {
+        let mut averaged = Vec::with_capacity(chroma_width * chroma_height);
+        for cy in 0..chroma_height {
+            for cx in 0..chroma_width {
+                let rows = cy * block_height..((cy + 1) * block_height).min(height);
+                let cols = cx * block_width..((cx + 1) * block_width).min(width);
+                let count = (rows.len() * cols.len()) as f64;
+                let sum: f64 = rows
+                    .flat_map(|row| plane[row * width..][cols.clone()].iter())
+                    .sum();
+                averaged.push(sum / count);
+            }
+        }
+        averaged
+    };
+
+    Reference {
+        cb: average(&cb),
+        cr: average(&cr),
+        y,
+    }
+}
+
+/// Largest difference between any sample of `image` and `reference`.
+fn max_error(image: &YcbcrImage, reference: &Reference) -> f64 {
+    [
+        (&image.y, &reference.y),
+        (&image.cb, &reference.cb),
+        (&image.cr, &reference.cr),
+    ]
+    .into_iter()
+    .flat_map(|(plane, exact)| plane.iter().zip(exact))
+    .map(|(&a, &b)| (a as f64 - b).abs())
+    .fold(0.0, f64::max)
+}
+
+/// Peak signal-to-noise ratio of `a` against `b` in dB, infinite when they're equal.
+fn psnr(a: &[u8], b: &[u8]) -> f64 {
+    let squared: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(&x, &y)| (x as f64 - y as f64).powi(2))
+        .sum();
+    let mse = squared / a.len() as f64;
+    10.0 * (255.0 * 255.0 / mse).log10()
+}
+
+/// Every grey has chroma of exactly 128, and full range greys come back unchanged.
+fn check_greys() {
+    let greys: Vec<u8> = (0..=255).flat_map(|v| [v; 3]).collect();
+    for range in [YcbcrRange::Full, YcbcrRange::Limited] {
+        let format = YcbcrFormat {
+            range,
+            ..Default::default()
+        };
+        let ycbcr = simd_rgb_to_ycbcr(&greys, PixelLayout::Rgb, 256, 1, format);
+        let back = simd_ycbcr_to_rgb(&ycbcr, PixelLayout::Rgb);
+
+        let neutral = ycbcr.cb.iter().chain(&ycbcr.cr).all(|&c| c == 128);
+        let white = ycbcr.y[255] == if range == YcbcrRange::Full { 255 } else { 235 };
+        let valid = neutral && white && (range == YcbcrRange::Limited || back == greys);
+        println!(
+            "Greys ({range} range): chroma 128, white is {} {}",
+            ycbcr.y[255],
+            if valid { "✓" } else { "✗" }
+        );
+        assert!(valid, "greys don't survive {range} range");
+    }
+}
+
+/// Empty and single pixel images in every subsampling, which have no whole chunks or
+/// pairs to work with.
+fn check_tiny() {
+    let pixel = [200, 30, 90];
+    for subsampling in [
+        ChromaSubsampling::Yuv444,
+        ChromaSubsampling::Yuv422,
+        ChromaSubsampling::Yuv420,
+    ] {
+        let format = YcbcrFormat {
+            subsampling,
+            ..Default::default()
+        };
+        let mut valid = true;
+        for (width, height) in [(0, 0), (0, 3), (3, 0)] {
+            let ycbcr = simd_rgb_to_ycbcr(&[], PixelLayout::Rgb, width, height, format);
+            valid &= ycbcr.y.is_empty() && ycbcr.cb.is_empty() && ycbcr.cr.is_empty();
+            valid &= simd_ycbcr_to_rgb(&ycbcr, PixelLayout::Rgba).is_empty();
+        }
+
+        let ycbcr = simd_rgb_to_ycbcr(&pixel, PixelLayout::Rgb, 1, 1, format);
+        let back = simd_ycbcr_to_rgb(&ycbcr, PixelLayout::Rgb);
+        let sizes = [ycbcr.y.len(), ycbcr.cb.len(), ycbcr.cr.len()];
+        valid &= sizes == [1; 3] && back.iter().zip(pixel).all(|(&a, b)| a.abs_diff(b) <= 1);
+        println!(
+            "Empty and 1x1 images ({subsampling}): 1x1 comes back as {back:?} {}",
+            if valid { "✓" } else { "✗" }
+        );
+        assert!(valid, "empty or 1x1 images don't survive {subsampling}");
+    }
+}
+
+fn print_hue_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:<10} | {:>12} | {:>12} | {:>16} | {:>9} |",
+        "Model", "To", "Back", "Round trip error", "Valid"
+    );
+    println!(
+        "|{:-^12}|{:-^14}|{:-^14}|{:-^18}|{:-^11}|",
+        "", "", "", "", ""
+    );
+}
+
+/// Times `to` and `back` on lenna, and round trips every 24-bit colour (with a
+/// different alpha each) to check they all come back exactly.
+fn compare_hue_model(
+    name: &str,
+    rgba: &[[u8; 4]],
+    to: fn(&[[u8; 4]]) -> Vec<[f32; 4]>,
+    back: fn(&[[f32; 4]]) -> Vec<[u8; 4]>,
+) {
+    let trials = 50;
+    let converted = to(rgba);
+    let to_time = average_ns(trials, || to(black_box(rgba)));
+    let back_time = average_ns(trials, || back(black_box(&converted)));
+
+    // A red value at a time, so there's only ever 65536 colours in flight
+    let mut max_error = 0;
+    for r in 0..=255u8 {
+        let colours: Vec<[u8; 4]> = (0..=u16::MAX)
+            .map(|gb| {
+                let [g, b] = gb.to_be_bytes();
+                [r, g, b, g ^ b]
+            })
+            .collect();
+        let round_trip = back(&to(&colours));
+        max_error = colours
+            .iter()
+            .flatten()
+            .zip(round_trip.iter().flatten())
+            .map(|(&a, &b)| a.abs_diff(b))
+            .fold(max_error, u8::max);
+    }
+
+    let valid = max_error == 0;
+    println!(
+        "| {:<10} | {:>12} | {:>12} | {:>16} | {:>9} |",
+        name,
+        format_ns(to_time),
+        format_ns(back_time),
+        max_error,
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "{name} round trip is {max_error} off");
+}
+
+/// Whole turns of hue leave pixels alone, no saturation leaves greys, and a shift
+/// by a third of a turn moves red to green.
+fn check_adjust(rgba: &[[u8; 4]]) {
+    let mut adjusted = rgba.to_vec();
+    let start = Instant::now();
+    simd_adjust_hue_saturation(&mut adjusted, 90.0, 1.5);
+    let time = start.elapsed();
+
+    let mut turned = rgba.to_vec();
+    simd_adjust_hue_saturation(&mut turned, -720.0, 1.0);
+    let mut grey = rgba.to_vec();
+    simd_adjust_hue_saturation(&mut grey, 0.0, 0.0);
+    let mut primaries = [[255, 0, 0, 255], [0, 255, 0, 128], [0, 0, 255, 0]];
+    simd_adjust_hue_saturation(&mut primaries, 120.0, 1.0);
+
+    let valid = turned == rgba
+        && grey.iter().all(|&[r, g, b, _]| r == g && g == b)
+        && primaries == [[0, 255, 0, 255], [0, 0, 255, 128], [255, 0, 0, 0]];
+    println!(
+        "Hue +90°, saturation x1.5: {time:?} {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "hue/saturation adjustment is off");
+}
@@ -0,0 +1,106 @@
+use schmimmdee::{find_min_max_simd, format_ns, format_number, simd_csv_records, simd_parse_i64};
+use std::{env, fs, process, time::Instant};
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let mut path = None;
+    let mut delimiter = b',';
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--tsv" => delimiter = b'\t',
+            "-d" | "--delimiter" => match args.next().as_deref().map(str::as_bytes) {
+                Some([d]) => delimiter = *d,
+                _ => usage("--delimiter takes a single byte"),
+            },
+            _ if path.is_none() => path = Some(arg),
+            _ => usage(&format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    let Some(path) = path else {
+        usage("missing input file");
+    };
+
+    let data = match fs::read(&path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Error reading {path}: {e}");
+            process::exit(1);
+        }
+    };
+
+    // Tokenise and pull every numeric field out, per column
+    let start = Instant::now();
+    let mut records = simd_csv_records(&data, delimiter);
+    let header: Vec<String> = records
+        .next()
+        .map(|record| {
+            record
+                .iter()
+                .map(|name| String::from_utf8_lossy(&name).into_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut columns: Vec<Vec<f32>> = vec![Vec::new(); header.len()];
+    let mut rows = 0;
+    for record in records {
+        rows += 1;
+        for (i, field) in record.iter().enumerate() {
+            if i >= columns.len() {
+                columns.resize(i + 1, Vec::new());
+            }
+            // Integers are the common case, so try the SIMD parser before falling back
+            let value = match simd_parse_i64(field.trim_ascii()) {
+                Ok(n) => Some(n as f32),
+                Err(_) => std::str::from_utf8(&field)
+                    .ok()
+                    .and_then(|s| s.trim().parse::<f32>().ok()),
+            };
+            if let Some(value) = value {
+                columns[i].push(value);
+            }
+        }
+    }
+    let scan_time = start.elapsed();
+
+    println!(
+        "Scanned {} rows ({} bytes) in {}",
+        format_number(rows),
+        format_number(data.len()),
+        format_ns(scan_time.as_nanos() as f64)
+    );
+
+    println!("{:-^80}", format!(" {path} "));
+    println!(
+        "| {:>20} | {:>12} | {:>17} | {:>17} |",
+        "Column", "Numeric", "Min", "Max"
+    );
+    println!("|{:-^22}|{:-^14}|{:-^19}|{:-^19}|", "", "", "", "");
+
+    for (i, values) in columns.iter().enumerate() {
+        let name = header.get(i).cloned().unwrap_or_else(|| format!("#{i}"));
+        let (min, max) = if values.is_empty() {
+            ("-".to_string(), "-".to_string())
+        } else {
+            let (min, max) = find_min_max_simd(values);
+            (min.to_string(), max.to_string())
+        };
+
+        println!(
+            "| {:>20} | {:>12} | {:>17} | {:>17} |",
+            name,
+            format_number(values.len()),
+            min,
+            max
+        );
+    }
+    println!("{:-^80}", "");
+}
+
+fn usage(problem: &str) -> ! {
+    eprintln!("Error: {problem}");
+    eprintln!("Usage: csvstat <file.csv> [--tsv | --delimiter <byte>]");
+    process::exit(1);
+}
@@ -0,0 +1,206 @@
+use image::GrayImage;
+use schmimmdee::{EdgeMode, GradientOperator, Gradients, SimdImageExt, format_ns, simd_gradients};
+use std::{f32::consts::FRAC_PI_8, hint::black_box, time::Instant};
+
+fn main() -> Result<(), image::ImageError> {
+    let img = image::io::Reader::open("./assets/lenna.png")?.decode()?;
+    let luma_img = img.simd_grayscale();
+    let (width, height) = (luma_img.width() as usize, luma_img.height() as usize);
+    let luma = luma_img.as_raw();
+
+    print_header(" Edges (lenna, greyscale) ");
+    for operator in [GradientOperator::Sobel, GradientOperator::Scharr] {
+        compare_operator(luma, width, height, operator);
+    }
+    println!("{:-^80}", "");
+
+    // Save the Sobel edges, brightest at 255
+    let edges = simd_gradients(
+        luma,
+        width,
+        height,
+        GradientOperator::Sobel,
+        EdgeMode::Clamp,
+    );
+    let thinned = edges.non_max_suppression(&edges.magnitude());
+    let max = thinned.iter().copied().fold(0.0, f32::max).max(1.0);
+    let scaled = thinned
+        .iter()
+        .map(|&m| (m / max * 255.0).round() as u8)
+        .collect();
+    GrayImage::from_raw(width as u32, height as u32, scaled)
+        .expect("same size as lenna")
+        .save("lena_edges.png")?;
+
+    Ok(())
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>8} | {:>10} | {:>12} | {:>12} | {:>9} | {:>10} |",
+        "Operator", "Step", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^10}|{:-^12}|{:-^14}|{:-^14}|{:-^11}|{:-^12}|",
+        "", "", "", "", "", ""
+    );
+}
+
+/// Each step against a one-pixel-at-a-time version.
+fn compare_operator(luma: &[u8], width: usize, height: usize, operator: GradientOperator) {
+    let trials = 20;
+    let name = format!("{operator:?}");
+
+    let (gradients, simd_time) = time(trials, || {
+        simd_gradients(black_box(luma), width, height, operator, EdgeMode::Clamp)
+    });
+    let (expected, scalar_time) = time(trials, || gradients_scalar(luma, width, height, operator));
+    print_row(
+        &name,
+        "Gradients",
+        scalar_time,
+        simd_time,
+        gradients == expected,
+    );
+
+    let (magnitude, simd_time) = time(trials, || black_box(&gradients).magnitude());
+    let (expected, scalar_time) = time(trials, || {
+        scalar_map(&gradients, |x, y| (x * x + y * y).sqrt())
+    });
+    print_row(
+        &name,
+        "Magnitude",
+        scalar_time,
+        simd_time,
+        magnitude == expected,
+    );
+
+    // The SIMD atan2 is a polynomial, so it only has to be close
+    let (direction, simd_time) = time(trials, || black_box(&gradients).direction());
+    let (expected, scalar_time) = time(trials, || scalar_map(&gradients, |x, y| y.atan2(x)));
+    let close = direction
+        .iter()
+        .zip(&expected)
+        .all(|(a, b)| (a - b).abs() < 1e-5);
+    print_row(&name, "Direction", scalar_time, simd_time, close);
+
+    let (thinned, simd_time) = time(trials, || {
+        black_box(&gradients).non_max_suppression(&magnitude)
+    });
+    let (expected, scalar_time) = time(trials, || {
+        non_max_suppression_scalar(&gradients, &magnitude)
+    });
+    print_row(&name, "NMS", scalar_time, simd_time, thinned == expected);
+}
+
+fn print_row(operator: &str, step: &str, scalar_time: f64, simd_time: f64, valid: bool) {
+    println!(
+        "| {:>8} | {:>10} | {:>12} | {:>12} | {:>8.2}x | {:>10} |",
+        operator,
+        step,
+        format_ns(scalar_time),
+        format_ns(simd_time),
+        scalar_time / simd_time,
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "{operator} {step} doesn't match");
+}
+
+/// The 3×3 kernel applied to each pixel in turn, with clamped edges.
+fn gradients_scalar(
+    luma: &[u8],
+    width: usize,
+    height: usize,
+    operator: GradientOperator,
+) -> Gradients {
+    let (side, middle) = match operator {
+        GradientOperator::Sobel => (1, 2),
+        GradientOperator::Scharr => (3, 10),
+    };
+    let pixel = |x: isize, y: isize| {
+        let x = x.clamp(0, width as isize - 1) as usize;
+        let y = y.clamp(0, height as isize - 1) as usize;
+        luma[y * width + x] as i16
+    };
+
+    let (mut gx, mut gy) = (Vec::new(), Vec::new());
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            gx.push(
+                side * (pixel(x + 1, y - 1) - pixel(x - 1, y - 1))
+                    + middle * (pixel(x + 1, y) - pixel(x - 1, y))
+                    + side * (pixel(x + 1, y + 1) - pixel(x - 1, y + 1)),
+            );
+            gy.push(
+                side * (pixel(x - 1, y + 1) - pixel(x - 1, y - 1))
+                    + middle * (pixel(x, y + 1) - pixel(x, y - 1))
+                    + side * (pixel(x + 1, y + 1) - pixel(x + 1, y - 1)),
+            );
+        }
+    }
+    Gradients {
+        width,
+        height,
+        x: gx,
+        y: gy,
+    }
+}
+
+fn scalar_map(gradients: &Gradients, f: impl Fn(f32, f32) -> f32) -> Vec<f32> {
+    gradients
+        .x
+        .iter()
+        .zip(&gradients.y)
+        .map(|(&x, &y)| f(x as f32, y as f32))
+        .collect()
+}
+
+/// Picks the two neighbours along each pixel's rounded direction with `if`s.
+fn non_max_suppression_scalar(gradients: &Gradients, magnitude: &[f32]) -> Vec<f32> {
+    let (width, height) = (gradients.width as isize, gradients.height as isize);
+    let at = |x: isize, y: isize| {
+        if (0..width).contains(&x) && (0..height).contains(&y) {
+            magnitude[(y * width + x) as usize]
+        } else {
+            0.0
+        }
+    };
+    let tan_22_5 = FRAC_PI_8.tan();
+
+    let mut output = Vec::with_capacity(magnitude.len());
+    for y in 0..height {
+        for x in 0..width {
+            let i = (y * width + x) as usize;
+            let (gx, gy) = (gradients.x[i] as f32, gradients.y[i] as f32);
+            let (before, after) = if gy.abs() <= gx.abs() * tan_22_5 {
+                (at(x - 1, y), at(x + 1, y))
+            } else if gx.abs() <= gy.abs() * tan_22_5 {
+                (at(x, y - 1), at(x, y + 1))
+            } else if gx * gy > 0.0 {
+                (at(x - 1, y - 1), at(x + 1, y + 1))
+            } else {
+                (at(x + 1, y - 1), at(x - 1, y + 1))
+            };
+            let here = magnitude[i];
+            output.push(if here > before && here >= after {
+                here
+            } else {
+                0.0
+            });
+        }
+    }
+    output
+}
+
+/// The last result of `f`, and average nanos per call over `trials` (after one to
+/// warm up).
+fn time<T>(trials: u32, f: impl Fn() -> T) -> (T, f64) {
+    black_box(f());
+    let start = Instant::now();
+    for _ in 1..trials {
+        black_box(f());
+    }
+    let result = f();
+    (result, start.elapsed().as_nanos() as f64 / trials as f64)
+}
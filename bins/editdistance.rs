@@ -0,0 +1,106 @@
+use schmimmdee::{format_ns, format_number, levenshtein_scalar, simd_levenshtein_batch};
+use std::{fs, hint::black_box, path::Path, time::Instant};
+
+fn main() {
+    let data_path = "datasets/enwiki-latest-all-titles-in-ns0";
+
+    // Check if the data file exists
+    if !Path::new(data_path).exists() {
+        eprintln!("Error: Data file not found at {data_path}");
+        eprintln!("Please download it from:");
+        eprintln!("https://dumps.wikimedia.org/enwiki/latest/enwiki-latest-all-titles-in-ns0.gz");
+        eprintln!("Extract it and place it in the datasets/ directory.");
+        std::process::exit(1);
+    }
+
+    // Read and process the data
+    println!("Reading Wikipedia titles data...");
+    let raw_data = match fs::read_to_string(data_path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Error reading file: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    let titles: Vec<&[u8]> = raw_data.lines().map(str::as_bytes).collect();
+    println!("Titles: {}", titles.len());
+
+    let query = b"Bannana"; // Note: intentionally misspelled
+    let sizes = [1_000, 10_000, 100_000, 1_000_000, titles.len()];
+    let trials = 3;
+
+    println!(
+        "\n{:-^80}",
+        format!(
+            " '{}' Edit Distance Benchmark ",
+            String::from_utf8_lossy(query)
+        )
+    );
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Elements", "Scalar DP", "SIMD Myers", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+
+    for &size in &sizes {
+        let candidates = &titles[..size.min(titles.len())];
+
+        // Warmup to prevent either from winning the benefits of a hot cache
+        black_box(scalar_batch(query, candidates));
+        black_box(simd_levenshtein_batch(query, candidates));
+
+        let scalar_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(scalar_batch(query, candidates));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let simd_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(simd_levenshtein_batch(query, candidates));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let avg_scalar = scalar_time as f64 / trials as f64;
+        let avg_simd = simd_time as f64 / trials as f64;
+
+        let valid = scalar_batch(query, candidates) == simd_levenshtein_batch(query, candidates);
+        assert!(valid);
+
+        println!(
+            "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+            format_number(candidates.len()),
+            format_ns(avg_scalar),
+            format_ns(avg_simd),
+            avg_scalar / avg_simd,
+            if valid { "✓" } else { "✗" }
+        );
+    }
+
+    println!("{:-^80}", "");
+
+    // And what it's actually for: ranking fuzzy matches
+    let distances = simd_levenshtein_batch(query, &titles);
+    let mut ranked: Vec<(usize, &[u8])> = distances.into_iter().zip(titles).collect();
+    ranked.sort_by_key(|&(distance, _)| distance);
+
+    println!("\nClosest titles:");
+    for (distance, title) in ranked.iter().take(10) {
+        println!("{distance:>4}  {}", String::from_utf8_lossy(title));
+    }
+}
+
+fn scalar_batch(query: &[u8], candidates: &[&[u8]]) -> Vec<usize> {
+    candidates
+        .iter()
+        .map(|c| levenshtein_scalar(query, c))
+        .collect()
+}
@@ -0,0 +1,250 @@
+use rand::{Rng, seq::IndexedRandom};
+use schmimmdee::{EscapeSet, format_ns, format_number, simd_escape_json, simd_find_escape};
+use std::{borrow::Cow, hint::black_box, time::Instant};
+
+const SETS: [(&str, EscapeSet<'static>); 3] = [
+    ("json", EscapeSet::Json),
+    ("html", EscapeSet::Html),
+    ("shell", EscapeSet::Custom(b"'\\ $`\"")),
+];
+
+fn main() {
+    let mut rng = rand::rng();
+    let sizes = [1_000, 100_000, 10_000_000];
+    let trials = 20;
+
+    // Mostly plain text with the odd thing to escape, as in log messages
+    let inputs: Vec<String> = sizes
+        .iter()
+        .map(|&size| generate_text(&mut rng, size, 0.01))
+        .collect();
+
+    print_header(" JSON Escape Benchmark ");
+    for text in &inputs {
+        let (avg_scalar, avg_simd) = bench(
+            trials,
+            || scalar_escape_json(text),
+            || simd_escape_json(text),
+        );
+
+        let escaped = simd_escape_json(text);
+        let valid = escaped == scalar_escape_json(text)
+            && scalar_unescape_json(&escaped).as_deref() == Some(text.as_str());
+        print_row(text.len(), avg_scalar, avg_simd, valid);
+        assert!(valid, "escaping doesn't round trip");
+    }
+    println!("{:-^80}", "");
+
+    println!();
+
+    print_header(" Find Escape Benchmark (html) ");
+    for text in &inputs {
+        let bytes = text.as_bytes();
+        let (avg_scalar, avg_simd) = bench(
+            trials,
+            || scalar_find_all(bytes, EscapeSet::Html),
+            || simd_find_all(bytes, EscapeSet::Html),
+        );
+
+        let valid =
+            simd_find_all(bytes, EscapeSet::Html) == scalar_find_all(bytes, EscapeSet::Html);
+        print_row(text.len(), avg_scalar, avg_simd, valid);
+        assert!(valid, "escape offsets don't match");
+    }
+    println!("{:-^80}", "");
+
+    check_offsets();
+    check_round_trips(&mut rng);
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Bytes", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+}
+
+fn print_row(size: usize, avg_scalar: f64, avg_simd: f64, valid: bool) {
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+        format_number(size),
+        format_ns(avg_scalar),
+        format_ns(avg_simd),
+        avg_scalar / avg_simd,
+        if valid { "✓" } else { "✗" }
+    );
+}
+
+/// Every byte of every set, alone in plain text at every offset around a few vectors
+/// (so in the SIMD loop and in the tail), is found there; and bytes outside the set
+/// aren't.
+fn check_offsets() {
+    let mut valid = true;
+    for (name, set) in SETS {
+        for b in 0..=255u8 {
+            let wanted = scalar_in_set(b, set);
+            for len in [1, 15, 16, 17, 64, 100] {
+                for at in 0..len {
+                    let mut bytes = vec![b'a'; len];
+                    bytes[at] = b;
+                    let expected = wanted.then_some(at);
+                    if simd_find_escape(&bytes, set) != expected {
+                        println!("{name}: byte {b:#04x} at {at} of {len} not found");
+                        valid = false;
+                    }
+                }
+            }
+        }
+        valid &= simd_find_escape(b"", set).is_none();
+    }
+    valid &= simd_find_escape(b"plain $text", EscapeSet::Custom(b"")).is_none();
+    valid &= simd_find_escape(b"plain $text", EscapeSet::Custom(b"$")) == Some(6);
+    println!(
+        "Every byte of every set is found at its offset, and nothing else is: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "escape offsets misreported");
+}
+
+/// Dense escapes, every control character and non-ASCII round trip through a scalar
+/// unescape, and strings with nothing to escape come back borrowed.
+fn check_round_trips(rng: &mut impl Rng) {
+    let controls: String = (0..0x20u8).map(char::from).collect();
+    let mut valid = (0..1_000).all(|_| {
+        let len = rng.random_range(0..200);
+        let text = generate_text(rng, len, 0.3);
+        let escaped = simd_escape_json(&text);
+        escaped == scalar_escape_json(&text)
+            && scalar_unescape_json(&escaped).as_deref() == Some(text.as_str())
+    });
+    let escaped = simd_escape_json(&controls);
+    valid &= escaped.contains("\\u001f") && escaped.contains("\\b") && escaped.contains("\\f");
+    valid &= scalar_unescape_json(&escaped).as_deref() == Some(controls.as_str());
+    valid &= matches!(simd_escape_json("naïve ✓ text"), Cow::Borrowed(_));
+    println!(
+        "Escaped strings round trip, unescaped ones are borrowed: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "escaping doesn't round trip");
+}
+
+/// Average nanos per call of each implementation, after a warmup.
+fn bench<A, B>(trials: u32, scalar: impl Fn() -> A, simd: impl Fn() -> B) -> (f64, f64) {
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(scalar());
+        black_box(simd());
+    });
+
+    let scalar_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(scalar());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    let simd_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(simd());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    (
+        scalar_time as f64 / trials as f64,
+        simd_time as f64 / trials as f64,
+    )
+}
+
+/// About `len` bytes of words, with a `density` fraction of the characters being
+/// ones something escapes.
+fn generate_text(rng: &mut impl Rng, len: usize, density: f64) -> String {
+    const SPECIAL: [char; 12] = [
+        '"', '\\', '\n', '\t', '\x01', '\x7f', '<', '&', '\'', '$', 'é', '✓',
+    ];
+    let mut text = String::with_capacity(len + 4);
+    while text.len() < len {
+        if rng.random_bool(density) {
+            text.push(*SPECIAL.choose(rng).unwrap());
+        } else {
+            text.push(rng.random_range(b'a'..=b'z') as char);
+        }
+    }
+    text
+}
+
+fn simd_find_all(bytes: &[u8], set: EscapeSet<'_>) -> usize {
+    let (mut count, mut start) = (0, 0);
+    while let Some(offset) = simd_find_escape(&bytes[start..], set) {
+        count += 1;
+        start += offset + 1;
+    }
+    count
+}
+
+fn scalar_find_all(bytes: &[u8], set: EscapeSet<'_>) -> usize {
+    bytes.iter().filter(|&&b| scalar_in_set(b, set)).count()
+}
+
+fn scalar_in_set(b: u8, set: EscapeSet<'_>) -> bool {
+    match set {
+        EscapeSet::Json => b == b'"' || b == b'\\' || b < 0x20,
+        EscapeSet::Html => b"<>&'\"".contains(&b),
+        EscapeSet::Custom(set) => set.contains(&b),
+    }
+}
+
+fn scalar_escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\x08' => out.push_str("\\b"),
+            '\x0c' => out.push_str("\\f"),
+            c if c < ' ' => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// `None` for anything that isn't a valid escape, or an unescaped quote or control
+/// character.
+fn scalar_unescape_json(s: &str) -> Option<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => out.push(match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'b' => '\x08',
+                'f' => '\x0c',
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                }
+                _ => return None,
+            }),
+            '"' => return None,
+            c if c < ' ' => return None,
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
@@ -1,42 +1,229 @@
-use image::{DynamicImage, ImageBuffer, Rgb};
-use schmimmdee::rgba_to_gray_simd_u8;
-
-/// Extract RGBA pixels as `Vec<[u8; 4]>` from a `DynamicImage`.
-pub fn image_to_rgba_u8(img: &DynamicImage) -> Vec<[u8; 4]> {
-    let rgba = img.to_rgba8();
-    rgba.pixels().map(|p| [p[0], p[1], p[2], p[3]]).collect()
-}
-
-pub fn vec_to_dynamic_u8(pixels: Vec<[u8; 3]>, width: u32, height: u32) -> DynamicImage {
-    let raw_data: Vec<u8> = pixels.into_iter().flat_map(|[r, g, b]| [r, g, b]).collect();
-    let buffer = ImageBuffer::<Rgb<u8>, _>::from_raw(width, height, raw_data)
-        .expect("Invalid buffer dimensions");
-    DynamicImage::ImageRgb8(buffer)
-}
+use image::DynamicImage;
+use schmimmdee::{
+    LumaStandard, PixelLayout, SimdImageExt, pixels_to_luma_simd, rgba_to_gray_linear,
+    rgba_to_gray_simd_u8, rgba_to_luma_into, rgba_to_luma_into_scalar_gather, rgba_to_luma_simd,
+    simd_convert_layout,
+};
 
 fn main() -> Result<(), image::ImageError> {
     // Load image
     let img = image::io::Reader::open("./assets/lenna.png")?.decode()?;
-    let (width, height) = (img.width(), img.height());
 
-    // Convert to RGBA u8
-    let rgba_u8 = image_to_rgba_u8(&img);
+    // Convert to RGBA u8, then borrow its buffer as pixels rather than copying them out
+    let rgba_img = img.to_rgba8();
+    let (rgba_u8, _) = rgba_img.as_raw().as_chunks::<4>();
 
-    // Benchmark SIMD version
+    // Benchmark SIMD version, one byte per pixel
     let start_simd = std::time::Instant::now();
-    let simd_gray = rgba_to_gray_simd_u8(&rgba_u8);
+    let simd_luma = rgba_img.simd_grayscale();
     let simd_time = start_simd.elapsed();
+    assert_eq!(
+        simd_luma.as_raw(),
+        &rgba_to_luma_simd(rgba_u8, LumaStandard::default())
+    );
+
+    // And the luma copied out to RGB, for comparison
+    let start_rgb = std::time::Instant::now();
+    let simd_gray = rgba_to_gray_simd_u8(rgba_u8, LumaStandard::default());
+    let rgb_time = start_rgb.elapsed();
+    assert!(
+        simd_gray
+            .iter()
+            .zip(simd_luma.iter())
+            .all(|(&rgb, &l)| rgb == [l; 3])
+    );
 
     // Benchmark baseline (image crate's grayscale)
     let start_baseline = std::time::Instant::now();
     let baseline_gray = img.grayscale();
     let baseline_time = start_baseline.elapsed();
 
-    println!("SIMD: {simd_time:?}, Baseline: {baseline_time:?}");
+    println!("SIMD: {simd_time:?}, SIMD (RGB out): {rgb_time:?}, Baseline: {baseline_time:?}");
+
+    compare_deinterleave(rgba_u8);
+    compare_layouts(&img, rgba_u8, &simd_luma);
+    compare_linear(rgba_u8, &simd_luma);
+    check_standards(rgba_u8);
+    check_histogram(&img, &simd_luma);
 
     // Save results
-    vec_to_dynamic_u8(simd_gray, width, height).save("lena_simd_gray.png")?;
+    simd_luma.save("lena_simd_gray.png")?;
     baseline_gray.save("lena_baseline_gray.png")?;
 
     Ok(())
 }
+
+/// The `simd_swizzle!` deinterleave against pulling the channels out with a scalar
+/// loop, both into the same reused buffer.
+fn compare_deinterleave(rgba: &[[u8; 4]]) {
+    let trials = 100;
+    let mut swizzled = vec![0; rgba.len()];
+    let mut gathered = vec![0; rgba.len()];
+
+    let time = |f: fn(&[[u8; 4]], &mut [u8], LumaStandard), out: &mut [u8]| {
+        // Warmup to prevent either from winning the benefits of a hot cache
+        f(rgba, out, LumaStandard::default());
+        let start = std::time::Instant::now();
+        for _ in 0..trials {
+            f(std::hint::black_box(rgba), out, LumaStandard::default());
+        }
+        start.elapsed() / trials
+    };
+    let swizzle_time = time(rgba_to_luma_into, &mut swizzled);
+    let gather_time = time(rgba_to_luma_into_scalar_gather, &mut gathered);
+
+    assert_eq!(swizzled, gathered, "deinterleaves disagree");
+    println!(
+        "Swizzle deinterleave: {swizzle_time:?}, Scalar gather: {gather_time:?} ({:.2}x)",
+        gather_time.as_secs_f64() / swizzle_time.as_secs_f64()
+    );
+}
+
+/// Convert to each layout (and back), and check they all give the same luma.
+fn compare_layouts(img: &DynamicImage, rgba: &[[u8; 4]], luma: &[u8]) {
+    let layouts = [
+        PixelLayout::Rgb,
+        PixelLayout::Bgr,
+        PixelLayout::Rgba,
+        PixelLayout::Bgra,
+        PixelLayout::Argb,
+    ];
+    let rgba = rgba.as_flattened();
+    let opaque = rgba.chunks_exact(4).all(|p| p[3] == 255);
+
+    for layout in layouts {
+        let start = std::time::Instant::now();
+        let pixels = simd_convert_layout(rgba, PixelLayout::Rgba, layout);
+        let convert_time = start.elapsed();
+
+        let start = std::time::Instant::now();
+        let layout_luma = pixels_to_luma_simd(&pixels, layout, LumaStandard::default());
+        let luma_time = start.elapsed();
+
+        // Dropping alpha can't be undone, unless it was all 255 anyway
+        let back = simd_convert_layout(&pixels, layout, PixelLayout::Rgba);
+        let valid = layout_luma == luma
+            && (back == rgba || !layout.has_alpha() && !opaque)
+            && (layout != PixelLayout::Rgb || pixels == img.to_rgb8().into_raw());
+        println!(
+            "{layout:>4}: convert {convert_time:?}, luma {luma_time:?} {}",
+            if valid { "✓" } else { "✗" }
+        );
+        assert!(valid, "{layout} doesn't agree with RGBA");
+    }
+}
+
+/// Linear-light greyscale against an exact `f64` reference, and how far the usual
+/// gamma-space luma is from it.
+fn compare_linear(rgba: &[[u8; 4]], gamma_luma: &[u8]) {
+    let standard = LumaStandard::default();
+
+    let start = std::time::Instant::now();
+    let linear = rgba_to_gray_linear(rgba, standard);
+    let simd_time = start.elapsed();
+
+    let start = std::time::Instant::now();
+    let reference = gray_linear_reference(rgba, standard);
+    let reference_time = start.elapsed();
+
+    let max_error = linear
+        .iter()
+        .zip(&reference)
+        .map(|(&a, &b)| a.abs_diff(b))
+        .max()
+        .unwrap_or(0);
+    println!(
+        "Linear: {simd_time:?}, f64 reference: {reference_time:?}, PSNR {:.1}dB (max error {max_error}), gamma-space luma PSNR {:.1}dB",
+        psnr(&linear, &reference),
+        psnr(gamma_luma, &reference),
+    );
+    assert!(max_error <= 1, "linear greyscale is off by {max_error}");
+}
+
+/// sRGB decode, weight and encode each pixel in `f64`, exactly.
+fn gray_linear_reference(rgba: &[[u8; 4]], standard: LumaStandard) -> Vec<u8> {
+    let decode = |c: u8| {
+        let v = c as f64 / 255.0;
+        if v <= 0.04045 {
+            v / 12.92
+        } else {
+            ((v + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let encode = |y: f64| {
+        if y <= 0.0031308 {
+            12.92 * y
+        } else {
+            1.055 * y.powf(1.0 / 2.4) - 0.055
+        }
+    };
+    let [wr, wg, wb] = standard.coefficients();
+
+    rgba.iter()
+        .map(|&[r, g, b, _]| {
+            let y = wr * decode(r) + wg * decode(g) + wb * decode(b);
+            (encode(y.clamp(0.0, 1.0)) * 255.0).round() as u8
+        })
+        .collect()
+}
+
+/// Peak signal-to-noise ratio of `a` against `b` in dB, infinite when they're equal.
+fn psnr(a: &[u8], b: &[u8]) -> f64 {
+    let squared: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(&x, &y)| (x as f64 - y as f64).powi(2))
+        .sum();
+    let mse = squared / a.len() as f64;
+    10.0 * (255.0 * 255.0 / mse).log10()
+}
+
+/// The extension trait's histogram against counting the luma one pixel at a time.
+fn check_histogram(img: &DynamicImage, luma: &[u8]) {
+    let start = std::time::Instant::now();
+    let histogram = img.simd_histogram();
+    let time = start.elapsed();
+
+    let mut expected = [0u32; 256];
+    luma.iter().for_each(|&l| expected[l as usize] += 1);
+    let valid = histogram == expected;
+    println!(
+        "Luma histogram (grayscale included): {time:?} {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "luma histogram doesn't match");
+}
+
+/// Every standard's weights sum to 256, so black stays 0 and white stays 255.
+fn check_standards(rgba: &[[u8; 4]]) {
+    let standards = [
+        LumaStandard::Bt601,
+        LumaStandard::Bt709,
+        LumaStandard::Bt2020,
+        LumaStandard::Average,
+        LumaStandard::Custom([0.5, 0.3, 0.2]),
+    ];
+
+    // Enough pixels for the SIMD chunks and the scalar remainder
+    let black = vec![[0, 0, 0, 255]; 67];
+    let white = vec![[255, 255, 255, 255]; 67];
+
+    for standard in standards {
+        let start = std::time::Instant::now();
+        std::hint::black_box(rgba_to_luma_simd(rgba, standard));
+        let time = start.elapsed();
+
+        let weights = standard.weights();
+        let valid = weights.iter().sum::<u16>() == 256
+            && rgba_to_luma_simd(&black, standard).iter().all(|&p| p == 0)
+            && rgba_to_luma_simd(&white, standard)
+                .iter()
+                .all(|&p| p == 255);
+        println!(
+            "{:>28}: {weights:?} {time:?} {}",
+            format!("{standard:?}"),
+            if valid { "✓" } else { "✗" }
+        );
+        assert!(valid, "{standard:?} doesn't keep black and white");
+    }
+}
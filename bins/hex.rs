@@ -0,0 +1,116 @@
+use rand::Rng;
+use schmimmdee::{format_ns, format_number, simd_hex_decode, simd_hex_encode};
+use std::{hint::black_box, time::Instant};
+
+fn main() {
+    let mut rng = rand::rng();
+    let sizes = [1_000, 10_000, 100_000, 1_000_000, 10_000_000];
+    let trials = 100;
+
+    let inputs: Vec<Vec<u8>> = sizes
+        .iter()
+        .map(|&size| (0..size).map(|_| rng.random()).collect())
+        .collect();
+
+    print_header(" Hex Encode Benchmark ");
+    for data in &inputs {
+        let (avg_scalar, avg_simd) =
+            bench(trials, || scalar_hex_encode(data), || simd_hex_encode(data));
+
+        let valid = scalar_hex_encode(data) == simd_hex_encode(data);
+        assert!(valid);
+
+        print_row(data.len(), avg_scalar, avg_simd, valid);
+    }
+    println!("{:-^80}", "");
+
+    println!();
+
+    print_header(" Hex Decode Benchmark ");
+    for data in &inputs {
+        let hex = simd_hex_encode(data);
+        let (avg_scalar, avg_simd) =
+            bench(trials, || scalar_hex_decode(&hex), || simd_hex_decode(&hex));
+
+        let valid = scalar_hex_decode(&hex).as_ref() == simd_hex_decode(&hex).ok().as_ref()
+            && simd_hex_decode(&hex).as_ref() == Ok(data);
+        assert!(valid);
+
+        print_row(data.len(), avg_scalar, avg_simd, valid);
+    }
+    println!("{:-^80}", "");
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Elements", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+}
+
+fn print_row(size: usize, avg_scalar: f64, avg_simd: f64, valid: bool) {
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+        format_number(size),
+        format_ns(avg_scalar),
+        format_ns(avg_simd),
+        avg_scalar / avg_simd,
+        if valid { "✓" } else { "✗" }
+    );
+}
+
+/// Average nanos per call of each implementation, after a warmup.
+fn bench<A, B>(trials: u32, scalar: impl Fn() -> A, simd: impl Fn() -> B) -> (f64, f64) {
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(scalar());
+        black_box(simd());
+    });
+
+    let scalar_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(scalar());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    let simd_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(simd());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    (
+        scalar_time as f64 / trials as f64,
+        simd_time as f64 / trials as f64,
+    )
+}
+
+fn scalar_hex_encode(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for &b in bytes {
+        out.push(DIGITS[(b >> 4) as usize] as char);
+        out.push(DIGITS[(b & 0x0f) as usize] as char);
+    }
+    out
+}
+
+fn scalar_hex_decode(hex: &str) -> Option<Vec<u8>> {
+    hex.as_bytes()
+        .chunks_exact(2)
+        .map(|pair| {
+            let hi = (pair[0] as char).to_digit(16)?;
+            let lo = (pair[1] as char).to_digit(16)?;
+            Some((hi << 4 | lo) as u8)
+        })
+        .collect()
+}
@@ -1,5 +1,8 @@
 #![allow(unused_imports)]
-use schmimmdee::{format_ns, format_number, simd_histogram_parallel, simd_histogram_single};
+use schmimmdee::{
+    format_ns, format_number, simd_histogram_parallel, simd_histogram_single,
+    simd_normalize_newlines,
+};
 use std::{collections::HashMap, fs, hint::black_box, path::Path, time::Instant};
 
 fn main() {
@@ -25,7 +28,8 @@ fn main() {
     };
 
     println!("Processing data...");
-    let processed_data = raw_data.replace(['\n', '_'], " ");
+    // CRLF dumps would otherwise leave a '\r' per title in the histogram
+    let processed_data = simd_normalize_newlines(&raw_data).replace(['\n', '_'], " ");
 
     // Step 1: Create word count HashMap
     println!("\nCreating word counts...");
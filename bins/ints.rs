@@ -0,0 +1,260 @@
+use rand::{Rng, seq::IndexedRandom};
+use schmimmdee::{
+    IntParseError, format_ns, format_number, parse_ints_in_lines, simd_parse_i64, simd_parse_u32,
+    simd_parse_u64,
+};
+use std::{
+    hint::black_box,
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+    time::Instant,
+};
+
+/// Bytes dropped into the middle of numbers: the ones either side of `'0'..='9'` (the
+/// edges of the SIMD range check), signs, and line ending bytes.
+const BAD_BYTES: &[u8] = b"/:+- \r\t.a\x00\xff";
+
+fn main() {
+    let mut rng = rand::rng();
+    let sizes = [1_000, 100_000, 1_000_000];
+    let trials = 20;
+
+    let inputs: Vec<String> = sizes
+        .iter()
+        .map(|&lines| {
+            (0..lines)
+                .map(|_| {
+                    let n = rng.random::<i64>() >> rng.random_range(0..64);
+                    format!("{n}\n")
+                })
+                .collect()
+        })
+        .collect();
+
+    print_header(" Parse Lines Benchmark ");
+    for text in &inputs {
+        let (avg_scalar, avg_simd) = bench(
+            trials,
+            || scalar_parse_lines(text),
+            || parse_ints_in_lines(text.as_bytes()),
+        );
+
+        let parsed: Vec<_> = parse_ints_in_lines(text.as_bytes())
+            .into_iter()
+            .map(Result::ok)
+            .collect();
+        let valid = parsed == scalar_parse_lines(text);
+        print_row(text.lines().count(), avg_scalar, avg_simd, valid);
+        assert!(valid, "parsed lines don't match str::parse");
+    }
+    println!("{:-^80}", "");
+
+    check_parsers(&mut rng);
+    check_lines(&mut rng);
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Lines", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+}
+
+fn print_row(size: usize, avg_scalar: f64, avg_simd: f64, valid: bool) {
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+        format_number(size),
+        format_ns(avg_scalar),
+        format_ns(avg_simd),
+        avg_scalar / avg_simd,
+        if valid { "✓" } else { "✗" }
+    );
+}
+
+/// `simd_parse_u32`, `simd_parse_u64` and `simd_parse_i64` agree with `str::parse` on
+/// the overflow boundaries of each type, sign-only and empty input, and random
+/// numbers (some zero padded past 16 or 32 digits, some with a bad byte in them).
+fn check_parsers(rng: &mut impl Rng) {
+    let mut inputs: Vec<Vec<u8>> = [
+        "",
+        "+",
+        "-",
+        "+-1",
+        "--1",
+        "0",
+        "+0",
+        "-0",
+        " 1",
+        "1 ",
+        "12a4",
+        "4294967295",
+        "4294967296",
+        "9223372036854775807",
+        "9223372036854775808",
+        "-9223372036854775808",
+        "-9223372036854775809",
+        "18446744073709551615",
+        "18446744073709551616",
+        "99999999999999999999x",
+        "1234567890123456789012345678901234x",
+    ]
+    .map(|s| s.as_bytes().to_vec())
+    .into();
+    inputs.push(format!("{}1", "0".repeat(40)).into_bytes());
+    inputs.push("9".repeat(40).into_bytes());
+    inputs.extend((0..100_000).map(|_| random_number(rng)));
+
+    let mut failures = 0;
+    for s in &inputs {
+        let valid = simd_parse_u32(s) == reference_parse::<u32>(s, b"+")
+            && simd_parse_u64(s) == reference_parse::<u64>(s, b"+")
+            && simd_parse_i64(s) == reference_parse::<i64>(s, b"+-");
+        if !valid && failures < 5 {
+            let input = String::from_utf8_lossy(s);
+            println!("{input:?} parsed as {:?}", simd_parse_i64(s));
+        }
+        failures += !valid as usize;
+    }
+
+    let valid = failures == 0;
+    println!(
+        "{} inputs parse as str::parse does, bad digits at their offset: {}",
+        format_number(inputs.len()),
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "integer parsing doesn't match str::parse");
+}
+
+/// `parse_ints_in_lines` on `\n` and `\r\n` separated lines (with or without a final
+/// newline), including blank lines and lone `\r`s, gives the same results line by line
+/// as `simd_parse_i64`'s reference.
+fn check_lines(rng: &mut impl Rng) {
+    let mut valid = parse_ints_in_lines(b"").is_empty()
+        && parse_ints_in_lines(b"\n").is_empty()
+        && parse_ints_in_lines(b"\r\n") == [Err(IntParseError::Empty)]
+        && parse_ints_in_lines(b"1\r\n\r\n-2\r\n") == [Ok(1), Err(IntParseError::Empty), Ok(-2)]
+        && parse_ints_in_lines(b"1\r2\r\n34x\r\n")
+            == [
+                Err(IntParseError::InvalidDigit {
+                    offset: 1,
+                    byte: b'\r',
+                }),
+                Err(IntParseError::InvalidDigit {
+                    offset: 2,
+                    byte: b'x',
+                }),
+            ];
+
+    for _ in 0..1_000 {
+        let lines = rng.random_range(0..50);
+        let mut text = Vec::new();
+        for i in 0..lines {
+            if i > 0 {
+                text.extend_from_slice([&b"\n"[..], b"\r\n"].choose(rng).unwrap());
+            }
+            text.extend(random_number(rng));
+        }
+        if rng.random_bool(0.5) {
+            text.extend_from_slice([&b"\n"[..], b"\r\n"].choose(rng).unwrap());
+        }
+
+        let body = text.strip_suffix(b"\n").unwrap_or(&text);
+        let expected: Vec<_> = if body.is_empty() {
+            Vec::new()
+        } else {
+            body.split(|&b| b == b'\n')
+                .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+                .map(|line| reference_parse::<i64>(line, b"+-"))
+                .collect()
+        };
+        if parse_ints_in_lines(&text) != expected {
+            println!("{:?} misparsed", String::from_utf8_lossy(&text));
+            valid = false;
+        }
+    }
+
+    println!(
+        "Lines split on \\n and \\r\\n parse as str::parse does: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "parse_ints_in_lines doesn't match str::parse");
+}
+
+/// A decimal number of any width up to a `u64`, maybe signed, maybe zero padded, and
+/// sometimes with one byte swapped for a non-digit.
+fn random_number(rng: &mut impl Rng) -> Vec<u8> {
+    let n = rng.random::<u64>() >> rng.random_range(0..64);
+    let sign = *["", "", "+", "-"].choose(rng).unwrap();
+    let zeros = "0".repeat(*[0, 0, 5, 16, 30].choose(rng).unwrap());
+    let mut bytes = format!("{sign}{zeros}{n}").into_bytes();
+    if rng.random_bool(0.2) {
+        let at = rng.random_range(0..bytes.len());
+        bytes[at] = *BAD_BYTES.choose(rng).unwrap();
+    }
+    bytes
+}
+
+/// What the SIMD parsers should return: `Empty` for nothing (or just a sign) after the
+/// optional sign, the first non-digit as `InvalidDigit`, and otherwise `str::parse`'s
+/// value or overflow (non-UTF-8 never gets that far).
+fn reference_parse<T: FromStr<Err = ParseIntError>>(
+    s: &[u8],
+    signs: &[u8],
+) -> Result<T, IntParseError> {
+    let offset = s.first().is_some_and(|b| signs.contains(b)) as usize;
+    let digits = &s[offset..];
+    if digits.is_empty() {
+        return Err(IntParseError::Empty);
+    }
+    if let Some(i) = digits.iter().position(|b| !b.is_ascii_digit()) {
+        return Err(IntParseError::InvalidDigit {
+            offset: offset + i,
+            byte: digits[i],
+        });
+    }
+
+    let s = std::str::from_utf8(s).unwrap();
+    s.parse::<T>().map_err(|e| match e.kind() {
+        IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => IntParseError::Overflow,
+        kind => panic!("str::parse rejected {s:?} as {kind:?}"),
+    })
+}
+
+fn scalar_parse_lines(text: &str) -> Vec<Option<i64>> {
+    text.lines().map(|line| line.parse().ok()).collect()
+}
+
+/// Average nanos per call of each implementation, after a warmup.
+fn bench<A, B>(trials: u32, scalar: impl Fn() -> A, simd: impl Fn() -> B) -> (f64, f64) {
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(scalar());
+        black_box(simd());
+    });
+
+    let scalar_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(scalar());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    let simd_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(simd());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    (
+        scalar_time as f64 / trials as f64,
+        simd_time as f64 / trials as f64,
+    )
+}
@@ -0,0 +1,235 @@
+use rand::{Rng, seq::IndexedRandom};
+use schmimmdee::{JsonError, format_ns, format_number, json_structural_indices};
+use std::{hint::black_box, time::Instant};
+
+/// Escaped quotes, backslash runs of every length up to 8 (odd ones escaping the
+/// quote after them, even ones not), escaped backslashes right before a closing
+/// quote, structural characters inside strings, and some non-ASCII.
+const SAMPLE: &str = r#"{"id":17,"name":"say \"hi\", [not] {structural}: ok","path":"C:\\Users\\\\x\\","runs":["\\","\\\"","\\\\\"\\","\\\\\\\\\\\"","\\\\\\\\\\\\\\\\"],"nested":{"a":[1,2,{"b":"\"}\""}],"ü":"naïve ✓ \u00e9"},"empty":"","tail":"\\\\\\\\\\\\\\\"\\\\\\\\"}"#;
+
+fn main() {
+    let mut rng = rand::rng();
+
+    check_sample();
+    check_generated(&mut rng);
+    check_errors();
+
+    println!();
+    print_header(" JSON Structural Index Benchmark ");
+    for records in [100, 10_000, 100_000] {
+        let document = generate_document(&mut rng, records);
+        let trials = 20;
+        let (avg_scalar, avg_simd) = bench(
+            trials,
+            || scalar_structural_indices(document.as_bytes()),
+            || json_structural_indices(document.as_bytes()),
+        );
+
+        let valid = json_structural_indices(document.as_bytes())
+            == scalar_structural_indices(document.as_bytes());
+        print_row(document.len(), avg_scalar, avg_simd, valid);
+        assert!(
+            valid,
+            "indices don't match on a {} byte document",
+            document.len()
+        );
+    }
+    println!("{:-^80}", "");
+}
+
+/// The sample behind every amount of padding up to a block, so each escape and
+/// backslash run lands on every position relative to the 64-byte blocks.
+fn check_sample() {
+    let valid = (0..64).all(|padding| {
+        let document = format!("{}{SAMPLE}", " ".repeat(padding));
+        let indices = json_structural_indices(document.as_bytes());
+        indices.is_ok() && indices == scalar_structural_indices(document.as_bytes())
+    });
+    println!(
+        "Sample document at every block offset matches a scalar scan: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "sample indices don't match");
+}
+
+/// Strings made up mostly of backslashes and quotes, so runs and escapes straddle
+/// block boundaries in every way.
+fn check_generated(rng: &mut impl Rng) {
+    let valid = (0..2_000).all(|_| {
+        let document = generate_document(rng, 8);
+        json_structural_indices(document.as_bytes())
+            == scalar_structural_indices(document.as_bytes())
+    });
+    println!(
+        "2,000 generated documents match a scalar scan: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "generated indices don't match");
+}
+
+fn check_errors() {
+    let cases: [(&[u8], JsonError); 5] = [
+        (b"{\"a\":\"open", JsonError::UnclosedString { offset: 5 }),
+        (b"[\"\\\\\\\"]", JsonError::UnclosedString { offset: 1 }),
+        (
+            b"{\"a\":\"tab\there\"}",
+            JsonError::ControlCharInString { offset: 9 },
+        ),
+        (b"[\"\xff\"]", JsonError::InvalidUtf8 { offset: 2 }),
+        (
+            b"[\"\xc3\xa9\", \"\xe2\x9c\"]",
+            JsonError::InvalidUtf8 { offset: 8 },
+        ),
+    ];
+    let mut valid = true;
+    for (document, expected) in cases {
+        // At every block offset too, for the carried state
+        for padding in [0, 60, 63] {
+            let mut padded = vec![b' '; padding];
+            padded.extend_from_slice(document);
+            let expected = Err(shift(expected, padding));
+            let (simd, scalar) = (
+                json_structural_indices(&padded),
+                scalar_structural_indices(&padded),
+            );
+            if simd != expected || scalar != expected {
+                println!(
+                    "\"{}\": expected {expected:?}, got {simd:?} and {scalar:?}",
+                    padded.escape_ascii()
+                );
+                valid = false;
+            }
+        }
+    }
+    println!(
+        "Unclosed strings, control characters and bad UTF-8 are reported: {}",
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "errors misreported");
+}
+
+fn shift(error: JsonError, by: usize) -> JsonError {
+    match error {
+        JsonError::UnclosedString { offset } => JsonError::UnclosedString {
+            offset: offset + by,
+        },
+        JsonError::ControlCharInString { offset } => JsonError::ControlCharInString {
+            offset: offset + by,
+        },
+        JsonError::InvalidUtf8 { offset } => JsonError::InvalidUtf8 {
+            offset: offset + by,
+        },
+        other => other,
+    }
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Bytes", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+}
+
+fn print_row(size: usize, avg_scalar: f64, avg_simd: f64, valid: bool) {
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+        format_number(size),
+        format_ns(avg_scalar),
+        format_ns(avg_simd),
+        avg_scalar / avg_simd,
+        if valid { "✓" } else { "✗" }
+    );
+}
+
+/// Average nanos per call of each implementation, after a warmup.
+fn bench<A, B>(trials: u32, scalar: impl Fn() -> A, simd: impl Fn() -> B) -> (f64, f64) {
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(scalar());
+        black_box(simd());
+    });
+
+    let scalar_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(scalar());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    let simd_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(simd());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    (
+        scalar_time as f64 / trials as f64,
+        simd_time as f64 / trials as f64,
+    )
+}
+
+/// A log-ish array of `records` objects, with string values full of escapes.
+fn generate_document(rng: &mut impl Rng, records: usize) -> String {
+    const PIECES: [&str; 10] = ["\\\\", "\\\"", "\\n", "a", "{", "]", ":", ",", "é", " "];
+    let mut document = String::from("[");
+    for record in 0..records {
+        if record > 0 {
+            document.push(',');
+        }
+        let value: String = (0..rng.random_range(0..40))
+            .map(|_| *PIECES.choose(rng).unwrap())
+            .collect();
+        document.push_str(&format!(
+            r#"{{"id":{record},"ok":true,"tags":["x","y"],"msg":"{value}","n":{}}}"#,
+            rng.random_range(-1000..1000)
+        ));
+    }
+    document.push(']');
+    document
+}
+
+/// A byte at a time, tracking whether we're in a string and whether the last byte
+/// was an escaping backslash.
+fn scalar_structural_indices(json: &[u8]) -> Result<Vec<u32>, JsonError> {
+    let mut indices = Vec::new();
+    let (mut in_string, mut escaped) = (false, false);
+    let mut string_start = 0;
+    for (i, &b) in json.iter().enumerate() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == b'"' {
+                in_string = false;
+            } else if b < 0x20 {
+                return Err(JsonError::ControlCharInString { offset: i });
+            }
+        } else if b == b'"' {
+            in_string = true;
+            string_start = i;
+            indices.push(i as u32);
+        } else if b"{}[]:,".contains(&b) {
+            indices.push(i as u32);
+        }
+    }
+    if in_string {
+        return Err(JsonError::UnclosedString {
+            offset: string_start,
+        });
+    }
+    if let Err(e) = std::str::from_utf8(json) {
+        return Err(JsonError::InvalidUtf8 {
+            offset: e.valid_up_to(),
+        });
+    }
+    Ok(indices)
+}
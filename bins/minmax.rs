@@ -1,9 +1,19 @@
 use rand::Rng;
-use std::{hint::black_box, time::Instant};
+use std::{env, fmt::LowerExp, fs, hint::black_box, process, str::FromStr, time::Instant};
 
 use schmimmdee::*;
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => {}
+        [flag, path] if flag == "--input" => return input_benchmark(path),
+        _ => {
+            eprintln!("Usage: minmax [--input file.txt]");
+            process::exit(1);
+        }
+    }
+
     let mut rng = rand::rng();
     let sizes = [1_000, 10_000, 100_000, 1_000_000, 10_000_000, 100_000_000];
     let trials = 100;
@@ -26,10 +36,8 @@ fn main() {
 
         // warmup to prevent either from winning the benefits of a hot cache.
         (0..3).for_each(|_| {
-            black_box({
-                _ = find_min_max_scalar(&data);
-                _ = find_min_max_simd(&data);
-            });
+            black_box(find_min_max_scalar(&data));
+            black_box(find_min_max_simd(&data));
         });
 
         // Benchmark scalar version
@@ -71,4 +79,260 @@ fn main() {
         );
     });
     println!("{:-^80}", "");
+
+    check_format(&mut rng);
+}
+
+/// `simd_format_f32` and `simd_format_f64` on edge cases and random bit patterns:
+/// each has to parse back (with `str::parse`) to the same bits, with as few
+/// significant digits as `{:e}` uses.
+fn check_format(rng: &mut impl Rng) {
+    let samples = 1_000_000;
+
+    let mut f32s = vec![
+        0.0,
+        -0.0,
+        f32::from_bits(1),
+        f32::from_bits(0x007f_ffff),
+        f32::MIN_POSITIVE,
+        f32::EPSILON,
+        f32::MAX,
+        f32::MIN,
+        1e-5,
+        1e17,
+        1317803.3,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+    ];
+    f32s.extend((0..samples).map(|_| f32::from_bits(rng.random())));
+    f32s.retain(|v| !v.is_nan());
+    let valid_f32 = check_format_values("f32", &f32s, simd_format_f32, |v| v.to_bits() as u64);
+
+    let mut f64s = vec![
+        0.0,
+        -0.0,
+        5e-324,
+        f64::from_bits(0x000f_ffff_ffff_ffff),
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        f64::MAX,
+        f64::MIN,
+        1e-5,
+        1e17,
+        0.1 + 0.2,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ];
+    f64s.extend((0..samples).map(|_| f64::from_bits(rng.random())));
+    f64s.retain(|v| !v.is_nan());
+    let valid_f64 = check_format_values("f64", &f64s, simd_format_f64, f64::to_bits);
+
+    let nan_valid = simd_format_f32(f32::NAN)
+        .parse::<f32>()
+        .is_ok_and(f32::is_nan)
+        && simd_format_f64(f64::NAN)
+            .parse::<f64>()
+            .is_ok_and(f64::is_nan);
+    println!(
+        "NaN formats as something that parses back to NaN: {}",
+        if nan_valid { "✓" } else { "✗" }
+    );
+    assert!(valid_f32 && valid_f64 && nan_valid);
+}
+
+/// Whether every one of `values` round trips through `format` and is as
+/// short as `{:e}`.
+fn check_format_values<T: Copy + LowerExp + FromStr>(
+    name: &str,
+    values: &[T],
+    format: fn(T) -> String,
+    bits: fn(T) -> u64,
+) -> bool {
+    let mut checked = 0;
+    let mut failures = 0;
+    for &v in values {
+        let formatted = format(v);
+        let round_trips = formatted
+            .parse::<T>()
+            .is_ok_and(|back| bits(back) == bits(v));
+        let shortest = significant_digits(&formatted) == significant_digits(&format!("{v:e}"));
+        if !(round_trips && shortest) && failures < 5 {
+            println!("{name} {v:e} formatted as {formatted}");
+        }
+        failures += !(round_trips && shortest) as usize;
+        checked += 1;
+    }
+    let valid = failures == 0;
+    println!(
+        "{} {name}s format to the shortest round trip: {}",
+        format_number(checked),
+        if valid { "✓" } else { "✗" }
+    );
+    valid
+}
+
+/// How many digits the mantissa has, less leading and trailing zeros. Counts
+/// rather than digits are compared: on an exact tie (1317803.25f32) either last
+/// digit is a shortest round trip.
+fn significant_digits(formatted: &str) -> usize {
+    let mantissa = formatted.split(['e', 'E']).next().unwrap_or_default();
+    let digits: String = mantissa.chars().filter(char::is_ascii_digit).collect();
+    digits.trim_matches('0').len()
+}
+
+/// Parse one float per line of `path` with `str::parse` and `simd_parse_f32`, then
+/// reduce with `find_min_max_scalar` and `find_min_max_simd`.
+fn input_benchmark(path: &str) {
+    let text = match fs::read(path) {
+        Ok(text) => text,
+        Err(e) => {
+            eprintln!("Error reading {path}: {e}");
+            process::exit(1);
+        }
+    };
+
+    let lines: Vec<&[u8]> = text
+        .split(|&b| b == b'\n')
+        .map(<[u8]>::trim_ascii)
+        .filter(|line| !line.is_empty())
+        .collect();
+
+    if let Some((i, e)) = lines
+        .iter()
+        .enumerate()
+        .find_map(|(i, line)| simd_parse_f32(line).err().map(|e| (i, e)))
+    {
+        eprintln!("Error parsing {path}, value #{}: {e}", i + 1);
+        process::exit(1);
+    }
+
+    let trials = 10;
+
+    let parse_scalar = |lines: &[&[u8]]| -> Vec<f32> {
+        lines
+            .iter()
+            .map(|line| {
+                std::str::from_utf8(line)
+                    .ok()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(f32::NAN)
+            })
+            .collect()
+    };
+    let parse_simd = |lines: &[&[u8]]| -> Vec<f32> {
+        lines
+            .iter()
+            .map(|line| simd_parse_f32(line).unwrap_or(f32::NAN))
+            .collect()
+    };
+
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(parse_scalar(&lines));
+        black_box(parse_simd(&lines));
+    });
+
+    let time = |f: &dyn Fn() -> Vec<f32>| -> f64 {
+        let total: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f());
+                start.elapsed().as_nanos()
+            })
+            .sum();
+        total as f64 / trials as f64
+    };
+
+    let parse_scalar_time = time(&|| parse_scalar(&lines));
+    let parse_simd_time = time(&|| parse_simd(&lines));
+
+    let scalar_values = parse_scalar(&lines);
+    let data = parse_simd(&lines);
+    let parse_valid = scalar_values
+        .iter()
+        .zip(&data)
+        .all(|(a, b)| a.to_bits() == b.to_bits());
+
+    // `nan` lines parse fine, but NaN has no min or max (the two reductions don't even
+    // agree on what to do with one), so they're left out from here on
+    let nans = data.iter().filter(|v| v.is_nan()).count();
+    let data: Vec<f32> = data.into_iter().filter(|v| !v.is_nan()).collect();
+
+    let reduce = |f: fn(&[f32]) -> (f32, f32)| -> f64 {
+        let total: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f(black_box(&data)));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+        total as f64 / trials as f64
+    };
+
+    (0..3).for_each(|_| {
+        black_box(find_min_max_scalar(&data));
+        black_box(find_min_max_simd(&data));
+    });
+
+    let reduce_scalar_time = reduce(find_min_max_scalar);
+    let reduce_simd_time = reduce(find_min_max_simd);
+
+    let (min, max) = find_min_max_simd(&data);
+    let reduce_valid = (min, max) == find_min_max_scalar(&data);
+
+    let skipped = if nans > 0 {
+        format!(", {} NaN skipped", format_number(nans))
+    } else {
+        String::new()
+    };
+    println!(
+        "{:-^80}",
+        format!(" {path} ({} values{skipped}) ", format_number(data.len()))
+    );
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Stage", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+
+    for (stage, scalar_time, simd_time, valid) in [
+        ("parse", parse_scalar_time, parse_simd_time, parse_valid),
+        (
+            "min/max",
+            reduce_scalar_time,
+            reduce_simd_time,
+            reduce_valid,
+        ),
+        (
+            "total",
+            parse_scalar_time + reduce_scalar_time,
+            parse_simd_time + reduce_simd_time,
+            parse_valid && reduce_valid,
+        ),
+    ] {
+        println!(
+            "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+            stage,
+            format_ns(scalar_time),
+            format_ns(simd_time),
+            scalar_time / simd_time,
+            if valid { "✓" } else { "✗" }
+        );
+    }
+    println!("{:-^80}", "");
+
+    if data.is_empty() {
+        println!("no values to take the min and max of");
+    } else {
+        println!(
+            "min = {}, max = {}",
+            simd_format_f32(min),
+            simd_format_f32(max)
+        );
+    }
+
+    assert!(parse_valid && reduce_valid);
 }
@@ -1,10 +1,18 @@
-use schmimmdee::{format_ns, simd_contains_pattern, simd_find_str};
+use schmimmdee::{
+    ApproxMatch, Finder, ShiftOr, format_ns, simd_contains_pattern, simd_find_approx,
+    simd_find_str, simd_normalize_newlines,
+};
 use std::fs;
 use std::hint::black_box;
 use std::path::Path;
 use std::time::Instant;
 
+/// Typos tolerated by the approximate search benchmark.
+const MAX_MISMATCHES: usize = 1;
+
 fn main() {
+    pathological_benchmark();
+
     let data_path = "datasets/enwiki-latest-all-titles-in-ns0";
 
     // Check if the data file exists
@@ -27,8 +35,10 @@ fn main() {
     };
 
     println!("Processing data...");
-    // Replace newlines with commas and underscores with spaces
-    let processed_data = raw_data.replace('\n', ",").replace('_', " ");
+    // Replace newlines (CRLF too) with commas and underscores with spaces
+    let processed_data = simd_normalize_newlines(&raw_data)
+        .replace('\n', ",")
+        .replace('_', " ");
 
     println!("Data size: {} bytes", processed_data.len());
     println!("Processing complete!\n");
@@ -136,6 +146,69 @@ fn main() {
 
         let find_valid = std_find_result == simd_find_result;
 
+        // Benchmark a precompiled Finder, built once outside the timed loop like a
+        // service searching for the same needle over and over would.
+        let finder = Finder::new(term.as_bytes());
+        let finder_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(finder.find(processed_data.as_bytes()));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let avg_finder = finder_time as f64 / trials as f64;
+        let finder_speedup = avg_std_find / avg_finder;
+
+        let finder_result = finder.find(processed_data.as_bytes());
+        assert_eq!(
+            std_find_result, finder_result,
+            "Finder results don't match for term '{term}': std={std_find_result:?}, finder={finder_result:?}"
+        );
+
+        let finder_valid = std_find_result == finder_result;
+
+        // Benchmark approximate search (one typo allowed) against a scalar reference
+        let approx_trials = 3;
+        let scalar_approx_time: u128 = (0..approx_trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(scalar_find_approx(
+                    processed_data.as_bytes(),
+                    term.as_bytes(),
+                    MAX_MISMATCHES,
+                ));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let simd_approx_time: u128 = (0..approx_trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(simd_find_approx(
+                    processed_data.as_bytes(),
+                    term.as_bytes(),
+                    MAX_MISMATCHES,
+                ));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let avg_scalar_approx = scalar_approx_time as f64 / approx_trials as f64;
+        let avg_simd_approx = simd_approx_time as f64 / approx_trials as f64;
+        let approx_speedup = avg_scalar_approx / avg_simd_approx;
+
+        let scalar_approx_result =
+            scalar_find_approx(processed_data.as_bytes(), term.as_bytes(), MAX_MISMATCHES);
+        let simd_approx_result =
+            simd_find_approx(processed_data.as_bytes(), term.as_bytes(), MAX_MISMATCHES);
+        assert_eq!(
+            scalar_approx_result, simd_approx_result,
+            "Approx results don't match for term '{term}'"
+        );
+
+        let approx_valid = scalar_approx_result == simd_approx_result;
+
         // Print formatted results
         println!(
             "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
@@ -155,9 +228,198 @@ fn main() {
             if find_valid { "✓" } else { "✗" }
         );
 
+        println!(
+            "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+            "finder",
+            format_ns(avg_std_find),
+            format_ns(avg_finder),
+            finder_speedup,
+            if finder_valid { "✓" } else { "✗" }
+        );
+
+        println!(
+            "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+            format!("approx k={MAX_MISMATCHES}"),
+            format_ns(avg_scalar_approx),
+            format_ns(avg_simd_approx),
+            approx_speedup,
+            if approx_valid { "✓" } else { "✗" }
+        );
+        println!(
+            "{} windows within {MAX_MISMATCHES} mismatch(es) of '{term}'",
+            simd_approx_result.len()
+        );
+
         println!("{:-^80}", "");
         println!();
     }
 
+    pattern_benchmark(&processed_data, trials);
+
     println!("Benchmark complete!");
 }
+
+/// Shift-Or with character classes, multi-lane vs one state at a time, finding every
+/// match so both read the whole haystack.
+fn pattern_benchmark(data: &str, trials: u32) {
+    let patterns = [
+        "[Aa][Vv][Xx]-[0-9]",
+        "Path of Exile [0-9]",
+        "B[a-z]nn[a-z]na",
+    ];
+
+    println!("{:-^80}", " Shift-Or Pattern Benchmark ");
+    println!(
+        "| {:>20} | {:>15} | {:>15} | {:>10} | {:>6} |",
+        "Pattern", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^22}|{:-^17}|{:-^17}|{:-^12}|{:-^8}|",
+        "", "", "", "", ""
+    );
+
+    let mut counts = Vec::new();
+    for pattern in patterns {
+        let searcher = ShiftOr::new(pattern.as_bytes()).expect("valid pattern");
+
+        // Warmup to prevent either from winning the benefits of a hot cache
+        black_box(searcher.find_all_scalar(data.as_bytes()));
+        black_box(searcher.find_all(data.as_bytes()));
+
+        let scalar_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(searcher.find_all_scalar(data.as_bytes()));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let simd_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(searcher.find_all(data.as_bytes()));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let avg_scalar = scalar_time as f64 / trials as f64;
+        let avg_simd = simd_time as f64 / trials as f64;
+
+        let scalar_result = searcher.find_all_scalar(data.as_bytes());
+        let simd_result = searcher.find_all(data.as_bytes());
+        let valid = scalar_result == simd_result
+            && searcher.find(data.as_bytes()) == scalar_result.first().copied();
+        assert!(valid, "Results don't match for pattern '{pattern}'");
+
+        println!(
+            "| {:>20} | {:>15} | {:>15} | {:>9.2}x | {:>5} |",
+            pattern,
+            format_ns(avg_scalar),
+            format_ns(avg_simd),
+            avg_scalar / avg_simd,
+            if valid { "✓" } else { "✗" }
+        );
+        counts.push((pattern, simd_result.len()));
+    }
+    println!("{:-^80}", "");
+
+    for (pattern, count) in counts {
+        println!("{count} matches for '{pattern}'");
+    }
+    println!();
+}
+
+/// Needles that make every haystack byte a first-char candidate, i.e. `"aaa…ab"` in
+/// `"aaa…a"`. Naive verification is O(n·m) on these, the Two-Way handover keeps it linear.
+fn pathological_benchmark() {
+    let haystack_len = 1_000_000;
+    let trials = 10;
+
+    let cases: Vec<(String, String, String)> = [16, 64, 256, 1024]
+        .into_iter()
+        .flat_map(|k| {
+            [
+                (
+                    format!("a^{k}b"),
+                    "a".repeat(haystack_len),
+                    format!("{}b", "a".repeat(k)),
+                ),
+                (
+                    format!("(ab)^{k}b"),
+                    "ab".repeat(haystack_len / 2),
+                    format!("{}b", "ab".repeat(k)),
+                ),
+            ]
+        })
+        .collect();
+
+    println!("{:-^80}", " Pathological Needle Benchmark ");
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Needle", "Std Lib", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+
+    for (label, haystack, needle) in &cases {
+        // Warmup to prevent either from winning the benefits of a hot cache
+        (0..3).for_each(|_| {
+            black_box(haystack.find(needle.as_str()));
+            black_box(simd_find_str(haystack, needle));
+        });
+
+        let std_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(haystack.find(needle.as_str()));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let simd_time: u128 = (0..trials)
+            .map(|_| {
+                let start = Instant::now();
+                black_box(simd_find_str(haystack, needle));
+                start.elapsed().as_nanos()
+            })
+            .sum();
+
+        let avg_std = std_time as f64 / trials as f64;
+        let avg_simd = simd_time as f64 / trials as f64;
+
+        let valid = haystack.find(needle.as_str()) == simd_find_str(haystack, needle)
+            && haystack.contains(needle.as_str())
+                == simd_contains_pattern(haystack.as_bytes(), needle.as_bytes());
+        assert!(valid, "Results don't match for needle '{label}'");
+
+        println!(
+            "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+            label,
+            format_ns(avg_std),
+            format_ns(avg_simd),
+            avg_std / avg_simd,
+            if valid { "✓" } else { "✗" }
+        );
+    }
+
+    println!("{:-^80}", "");
+    println!();
+}
+
+/// Scalar reference for [`simd_find_approx`]: count mismatches byte by byte, every window.
+fn scalar_find_approx(haystack: &[u8], needle: &[u8], max_mismatches: usize) -> Vec<ApproxMatch> {
+    if needle.len() > haystack.len() {
+        return Vec::new();
+    }
+
+    haystack
+        .windows(needle.len())
+        .enumerate()
+        .filter_map(|(offset, window)| {
+            let distance = window.iter().zip(needle).filter(|(a, b)| a != b).count();
+            (distance <= max_mismatches).then_some(ApproxMatch { offset, distance })
+        })
+        .collect()
+}
@@ -0,0 +1,167 @@
+use rand::{Rng, seq::IndexedRandom};
+use schmimmdee::{format_ns, format_number, simd_trim_ascii, simd_trim_end_lines};
+use std::{borrow::Cow, hint::black_box, time::Instant};
+
+/// ASCII whitespace, `\x0b` (which isn't, but sits in the middle of it), and
+/// something that is neither in one and two bytes.
+const ALPHABET: [char; 8] = [' ', '\t', '\n', '\x0b', '\x0c', '\r', 'a', 'é'];
+
+fn main() {
+    let mut rng = rand::rng();
+    let sizes = [1_000, 100_000, 10_000_000];
+    let trials = 20;
+
+    print_header(" Trim Benchmark ");
+    for &size in &sizes {
+        // A short value in a lot of padding.
+        let text = format!("{0}value{0}", " \t\r\n".repeat(size / 8));
+        let (avg_scalar, avg_simd) = bench(
+            trials,
+            || text.trim_ascii().len(),
+            || simd_trim_ascii(&text).len(),
+        );
+
+        let valid = simd_trim_ascii(&text) == text.trim_ascii();
+        print_row(text.len(), avg_scalar, avg_simd, valid);
+        assert!(valid, "trimmed text doesn't match str::trim_ascii");
+    }
+    println!("{:-^80}", "");
+
+    println!();
+
+    print_header(" Trim Line Ends Benchmark ");
+    for &size in &sizes {
+        // A fixed-width export from Windows: every line padded to 80 columns.
+        let text: String = (0..size / 82)
+            .map(|_| format!("{:<80}\r\n", rng.random::<u64>()))
+            .collect();
+        let (avg_scalar, avg_simd) = bench(
+            trials,
+            || scalar_trim_end_lines(&text),
+            || simd_trim_end_lines(&text),
+        );
+
+        let valid = simd_trim_end_lines(&text) == scalar_trim_end_lines(&text);
+        print_row(text.len(), avg_scalar, avg_simd, valid);
+        assert!(valid, "trimmed lines don't match str::trim_ascii_end");
+    }
+    println!("{:-^80}", "");
+
+    check_trims(&mut rng);
+}
+
+fn print_header(title: &str) {
+    println!("{title:-^80}");
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>10} | {:>10} |",
+        "Bytes", "Scalar", "SIMD", "Speedup", "Valid"
+    );
+    println!(
+        "|{:-^14}|{:-^17}|{:-^17}|{:-^12}|{:-^12}|",
+        "", "", "", "", ""
+    );
+}
+
+fn print_row(size: usize, avg_scalar: f64, avg_simd: f64, valid: bool) {
+    println!(
+        "| {:>12} | {:>15} | {:>15} | {:>9.2}x | {:>9} |",
+        format_number(size),
+        format_ns(avg_scalar),
+        format_ns(avg_simd),
+        avg_scalar / avg_simd,
+        if valid { "✓" } else { "✗" }
+    );
+}
+
+/// `simd_trim_ascii` and `simd_trim_end_lines` agree with the `str` methods on edge
+/// cases (lone `\r`, `\x0b`, nothing but whitespace, a trailing `\n`) and on random
+/// mixes of whitespace and text a few vectors long, and only allocate when there's
+/// something to trim.
+fn check_trims(rng: &mut impl Rng) {
+    let mut inputs: Vec<String> = [
+        "",
+        "a",
+        "\r",
+        "a\r",
+        "\ra",
+        "a\rb",
+        "\x0b",
+        "\x0b a \x0b",
+        "a \x0b",
+        " \t\n\x0c\r",
+        "a\n",
+        "a \n",
+        "a\r\n",
+        "a\n\n",
+        "a \r\nb\t\r\n\r\n",
+    ]
+    .map(String::from)
+    .into();
+    inputs.push(" \t\n\x0c\r".repeat(20));
+    inputs.push(format!("{0}é{0}", " ".repeat(40)));
+    inputs.extend((0..10_000).map(|_| {
+        let len = rng.random_range(0..100);
+        (0..len).map(|_| *ALPHABET.choose(rng).unwrap()).collect()
+    }));
+
+    let mut failures = 0;
+    for text in &inputs {
+        let trimmed_lines = simd_trim_end_lines(text);
+        let expected = scalar_trim_end_lines(text);
+        let valid = simd_trim_ascii(text) == text.trim_ascii()
+            && trimmed_lines == expected
+            && matches!(trimmed_lines, Cow::Borrowed(_)) == (expected == *text);
+        if !valid && failures < 5 {
+            println!(
+                "{text:?} trimmed to {:?} and {trimmed_lines:?}",
+                simd_trim_ascii(text)
+            );
+        }
+        failures += !valid as usize;
+    }
+
+    let valid = failures == 0;
+    println!(
+        "{} texts trim as str::trim_ascii and str::trim_ascii_end do: {}",
+        format_number(inputs.len()),
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid, "trimming doesn't match the str methods");
+}
+
+fn scalar_trim_end_lines(text: &str) -> String {
+    text.split('\n')
+        .map(str::trim_ascii_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Average nanos per call of each implementation, after a warmup.
+fn bench<A, B>(trials: u32, scalar: impl Fn() -> A, simd: impl Fn() -> B) -> (f64, f64) {
+    // warmup to prevent either from winning the benefits of a hot cache.
+    (0..3).for_each(|_| {
+        black_box(scalar());
+        black_box(simd());
+    });
+
+    let scalar_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(scalar());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    let simd_time: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(simd());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+
+    (
+        scalar_time as f64 / trials as f64,
+        simd_time as f64 / trials as f64,
+    )
+}
@@ -0,0 +1,144 @@
+use schmimmdee::{TitleIndex, format_ns, format_number, simd_find_str, simd_normalize_newlines};
+use std::{fs, hint::black_box, path::Path, time::Instant};
+
+fn main() {
+    let data_path = "datasets/enwiki-latest-all-titles-in-ns0";
+
+    // Check if the data file exists
+    if !Path::new(data_path).exists() {
+        eprintln!("Error: Data file not found at {data_path}");
+        eprintln!("Please download it from:");
+        eprintln!("https://dumps.wikimedia.org/enwiki/latest/enwiki-latest-all-titles-in-ns0.gz");
+        eprintln!("Extract it and place it in the datasets/ directory.");
+        std::process::exit(1);
+    }
+
+    // Read and process the data
+    println!("Reading Wikipedia titles data...");
+    let raw_data = match fs::read_to_string(data_path) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Error reading file: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    // Newlines on both ends, so every title is "\n{title}\n" for the linear scans
+    let text = format!("\n{}\n", simd_normalize_newlines(raw_data.trim_end()));
+
+    let start = Instant::now();
+    let index = TitleIndex::new(text.as_bytes());
+    println!(
+        "Indexed {} unique titles in {}",
+        format_number(index.len()),
+        format_ns(start.elapsed().as_nanos() as f64)
+    );
+
+    // Titles use underscores for spaces
+    let exact_terms = ["Path_of_Exile_2", "AVX-512", "Bannana"]; // Note: intentionally misspelled
+    let prefix_terms = ["Path_of_", "AVX", "Bann"];
+    let trials = 10;
+
+    println!("\n{:-^80}", " Title Lookup Benchmark ");
+    println!(
+        "| {:>20} | {:>15} | {:>15} | {:>10} | {:>4} |",
+        "Query", "Linear", "Index", "Speedup", "OK"
+    );
+    println!(
+        "|{:-^22}|{:-^17}|{:-^17}|{:-^12}|{:-^6}|",
+        "", "", "", "", ""
+    );
+
+    for term in exact_terms {
+        let (linear_time, linear) = bench(trials, || linear_contains(&text, term));
+        let (index_time, indexed) = bench(trials, || index.contains(term.as_bytes()));
+        print_row(
+            &format!("= {term}"),
+            linear_time,
+            index_time,
+            linear == indexed,
+        );
+    }
+
+    for prefix in prefix_terms {
+        let (linear_time, linear) = bench(trials, || linear_with_prefix(&text, prefix));
+        let (index_time, indexed) = bench(trials, || index.with_prefix(prefix.as_bytes()).len());
+
+        // The linear scan sees every duplicate line, the index only unique titles
+        let mut linear = linear;
+        linear.sort_unstable();
+        linear.dedup();
+        print_row(
+            &format!("{prefix}*"),
+            linear_time,
+            index_time,
+            linear.len() == indexed,
+        );
+    }
+    println!("{:-^80}", "");
+
+    for prefix in prefix_terms {
+        let matches = index.with_prefix(prefix.as_bytes());
+        let shown: Vec<_> = matches
+            .iter()
+            .take(5)
+            .map(|title| String::from_utf8_lossy(title))
+            .collect();
+        println!(
+            "{prefix}* ({}): {}{}",
+            format_number(matches.len()),
+            shown.join(", "),
+            if matches.len() > shown.len() {
+                ", ..."
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+/// Average time of `f` over `trials` runs, and its result
+fn bench<T>(trials: u32, f: impl Fn() -> T) -> (f64, T) {
+    // Warmup to prevent either from winning the benefits of a hot cache
+    black_box(f());
+
+    let total: u128 = (0..trials)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed().as_nanos()
+        })
+        .sum();
+    (total as f64 / trials as f64, f())
+}
+
+fn print_row(query: &str, linear: f64, index: f64, valid: bool) {
+    println!(
+        "| {:>20} | {:>15} | {:>15} | {:>9.2}x | {:>4} |",
+        query,
+        format_ns(linear),
+        format_ns(index),
+        linear / index,
+        if valid { "✓" } else { "✗" }
+    );
+    assert!(valid);
+}
+
+/// Whether `title` is one of the lines of `text`, which starts and ends with a newline
+fn linear_contains(text: &str, title: &str) -> bool {
+    simd_find_str(text, &format!("\n{title}\n")).is_some()
+}
+
+/// Every line of `text` starting with `prefix`, duplicates included
+fn linear_with_prefix<'a>(text: &'a str, prefix: &str) -> Vec<&'a str> {
+    let needle = format!("\n{prefix}");
+    let mut matches = Vec::new();
+    let mut pos = 0;
+    while let Some(found) = simd_find_str(&text[pos..], &needle) {
+        let start = pos + found + 1;
+        let end = start + text[start..].find('\n').unwrap_or(text.len() - start);
+        matches.push(&text[start..end]);
+        pos = end;
+    }
+    matches
+}
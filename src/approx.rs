@@ -0,0 +1,60 @@
+//! Approximate substring search under Hamming distance, for misspelling tolerance
+//! (`"Bannana"` should still turn up `"Banana"`-shaped windows).
+
+use std::simd::{Simd, cmp::SimdPartialEq};
+
+use crate::LOGICAL_LANES_;
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// A haystack window within the requested Hamming distance of the needle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApproxMatch {
+    pub offset: usize,
+    /// Number of byte positions where the window differs from the needle.
+    pub distance: usize,
+}
+
+/// Find every (possibly overlapping) window of `haystack` that differs from `needle`
+/// in at most `max_mismatches` byte positions.
+///
+/// Each window is compared `LANES` bytes at a time: `simd_ne` gives a mask of the
+/// mismatching lanes and a popcount of its bitmask is the distance for that chunk.
+/// Windows bail out as soon as they go over budget, so most cost one compare.
+pub fn simd_find_approx(haystack: &[u8], needle: &[u8], max_mismatches: usize) -> Vec<ApproxMatch> {
+    if needle.len() > haystack.len() {
+        return Vec::new();
+    }
+
+    (0..=haystack.len() - needle.len())
+        .filter_map(|offset| {
+            let window = &haystack[offset..offset + needle.len()];
+            hamming_distance_within(window, needle, max_mismatches)
+                .map(|distance| ApproxMatch { offset, distance })
+        })
+        .collect()
+}
+
+/// Hamming distance between two equal-length slices, or `None` once it exceeds `max`.
+fn hamming_distance_within(a: &[u8], b: &[u8], max: usize) -> Option<usize> {
+    debug_assert_eq!(a.len(), b.len());
+    let mut distance = 0;
+
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+
+    for (a, b) in a_chunks.zip(b_chunks) {
+        let ne = Simd::<u8, LANES>::from_slice(a).simd_ne(Simd::from_slice(b));
+        distance += ne.to_bitmask().count_ones() as usize;
+        if distance > max {
+            return None;
+        }
+    }
+
+    // Short needles live entirely in here, so pad with zeros (which always compare
+    // equal) rather than falling back to a scalar loop.
+    let ne = Simd::<u8, LANES>::load_or_default(a_rest).simd_ne(Simd::load_or_default(b_rest));
+    distance += ne.to_bitmask().count_ones() as usize;
+
+    (distance <= max).then_some(distance)
+}
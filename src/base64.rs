@@ -0,0 +1,339 @@
+//! Base64 encoding and decoding (RFC 4648), standard and URL-safe alphabets.
+//!
+//! Both directions work on `LANES` output (or input) characters per step: a constant
+//! lane shuffle lines up the 3-byte groups in `u32` lanes, shifts and masks split or
+//! join the 6-bit sextets, and the sextet <-> ASCII mapping is done with lane-wise
+//! range compares. Only the final, possibly padded, quantum is done one char at a time.
+
+use std::{
+    fmt,
+    simd::{
+        Select, Simd, ToBytes,
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        simd_swizzle,
+    },
+};
+
+use crate::LOGICAL_LANES_;
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// One 3-byte group (4 sextets) per `u32` lane.
+const GROUPS: usize = LANES / 4;
+
+/// Input bytes read per encode step (and output bytes written per decode step).
+const BYTES_PER_STEP: usize = GROUPS * 3;
+
+/// Spread `BYTES_PER_STEP` bytes so each `u32` lane (little endian) holds one group as
+/// `b0 << 16 | b1 << 8 | b2`, which puts the first sextet in the top bits.
+const ENCODE_SHUFFLE: [usize; LANES] = {
+    let mut shuffle = [0; LANES];
+    let mut i = 0;
+    while i < LANES {
+        let (group, byte) = (i / 4, i % 4);
+        shuffle[i] = 3 * group + if byte == 3 { 0 } else { 2 - byte };
+        i += 1;
+    }
+    shuffle
+};
+
+/// Pull the three decoded bytes of each `u32` lane back out in big-endian order.
+/// The last `GROUPS` lanes are don't-cares.
+const DECODE_SHUFFLE: [usize; LANES] = {
+    let mut shuffle = [0; LANES];
+    let mut i = 0;
+    while i < BYTES_PER_STEP {
+        let (group, byte) = (i / 3, i % 3);
+        shuffle[i] = 4 * group + 2 - byte;
+        i += 1;
+    }
+    shuffle
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Alphabet {
+    /// `A-Z a-z 0-9 + /`
+    Standard,
+    /// `A-Z a-z 0-9 - _`, safe in URLs and file names.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    /// The characters for sextets 62 and 63, the only two that differ between alphabets.
+    const fn specials(self) -> (u8, u8) {
+        match self {
+            Self::Standard => (b'+', b'/'),
+            Self::UrlSafe => (b'-', b'_'),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base64Config {
+    pub alphabet: Base64Alphabet,
+    /// Pad the output to a multiple of 4 with `=`. When decoding, padding is then
+    /// required, and without it any `=` is an error.
+    pub padding: bool,
+}
+
+impl Base64Config {
+    pub const STANDARD: Self = Self {
+        alphabet: Base64Alphabet::Standard,
+        padding: true,
+    };
+    pub const STANDARD_NO_PAD: Self = Self {
+        alphabet: Base64Alphabet::Standard,
+        padding: false,
+    };
+    pub const URL_SAFE: Self = Self {
+        alphabet: Base64Alphabet::UrlSafe,
+        padding: true,
+    };
+    pub const URL_SAFE_NO_PAD: Self = Self {
+        alphabet: Base64Alphabet::UrlSafe,
+        padding: false,
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Error {
+    /// A character outside the configured alphabet, and its byte offset.
+    InvalidChar { offset: usize, ch: char },
+    /// The input length can't be valid base64: not a multiple of 4 when padding is
+    /// required, or one char past a multiple of 4 (6 bits can't make a byte).
+    InvalidLength(usize),
+    /// A `=` where one isn't allowed: in the middle, more than two of them, or at all
+    /// when decoding without padding.
+    InvalidPadding { offset: usize },
+    /// The last symbol carries bits that don't fit in the output, so this isn't the
+    /// canonical encoding of anything.
+    TrailingBits { offset: usize },
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidChar { offset, ch } => {
+                write!(f, "invalid base64 character {ch:?} at offset {offset}")
+            }
+            Self::InvalidLength(len) => write!(f, "invalid base64 length ({len})"),
+            Self::InvalidPadding { offset } => write!(f, "invalid padding at offset {offset}"),
+            Self::TrailingBits { offset } => {
+                write!(f, "non-zero trailing bits in symbol at offset {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Base64Error {}
+
+pub fn simd_base64_encode(bytes: &[u8], config: Base64Config) -> String {
+    let (c62, c63) = config.alphabet.specials();
+    let mut out = Vec::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    let sextet = Simd::<u32, GROUPS>::splat(0x3f);
+
+    let mut i = 0;
+    // We load a whole vector but only consume `BYTES_PER_STEP` of it.
+    while i + LANES <= bytes.len() {
+        let v = Simd::<u8, LANES>::from_slice(&bytes[i..]);
+        let groups = Simd::<u32, GROUPS>::from_le_bytes(simd_swizzle!(v, ENCODE_SHUFFLE));
+
+        // One sextet per byte, in output order once viewed as little endian bytes.
+        let sextets = ((groups >> 18) & sextet)
+            | (((groups >> 12) & sextet) << 8)
+            | (((groups >> 6) & sextet) << 16)
+            | ((groups & sextet) << 24);
+
+        out.extend_from_slice(sextets_to_ascii(sextets.to_le_bytes(), c62, c63).as_array());
+        i += BYTES_PER_STEP;
+    }
+
+    // Handle remaining bytes
+    let table = scalar_table(config.alphabet);
+    let remainder = bytes[i..].chunks(3);
+    for group in remainder {
+        let b = [
+            group[0],
+            *group.get(1).unwrap_or(&0),
+            *group.get(2).unwrap_or(&0),
+        ];
+        let chars = [
+            table[(b[0] >> 2) as usize],
+            table[((b[0] << 4 | b[1] >> 4) & 0x3f) as usize],
+            table[((b[1] << 2 | b[2] >> 6) & 0x3f) as usize],
+            table[(b[2] & 0x3f) as usize],
+        ];
+
+        let used = group.len() + 1;
+        out.extend_from_slice(&chars[..used]);
+        if config.padding {
+            out.extend(std::iter::repeat_n(b'=', 4 - used));
+        }
+    }
+
+    // SAFETY: every byte written came from the alphabet or is `=`, all ASCII.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+pub fn simd_base64_decode(encoded: &str, config: Base64Config) -> Result<Vec<u8>, Base64Error> {
+    let input = encoded.as_bytes();
+    if config.padding && !input.len().is_multiple_of(4) {
+        return Err(Base64Error::InvalidLength(input.len()));
+    }
+
+    let (c62, c63) = config.alphabet.specials();
+    let mut out = Vec::with_capacity(input.len() / 4 * 3 + 2);
+
+    let mut i = 0;
+    // Always leave the last quantum for the scalar tail, that's where padding lives.
+    while i + LANES + 4 <= input.len() {
+        let chars = Simd::<u8, LANES>::from_slice(&input[i..i + LANES]);
+        let (sextets, invalid) = ascii_to_sextets(chars, c62, c63);
+        if invalid != 0 {
+            let offset = i + invalid.trailing_zeros() as usize;
+            return Err(invalid_char_or_padding(encoded, offset));
+        }
+
+        let lanes = Simd::<u32, GROUPS>::from_le_bytes(sextets);
+        let groups = ((lanes & Simd::splat(0x3f)) << 18)
+            | (((lanes >> 8) & Simd::splat(0x3f)) << 12)
+            | (((lanes >> 16) & Simd::splat(0x3f)) << 6)
+            | (lanes >> 24);
+
+        let decoded = simd_swizzle!(groups.to_le_bytes(), DECODE_SHUFFLE);
+        out.extend_from_slice(&decoded.as_array()[..BYTES_PER_STEP]);
+        i += LANES;
+    }
+
+    decode_tail(encoded, i, config, &mut out)?;
+    Ok(out)
+}
+
+/// Decode `encoded[start..]` one char at a time, including the final (maybe padded) quantum.
+fn decode_tail(
+    encoded: &str,
+    start: usize,
+    config: Base64Config,
+    out: &mut Vec<u8>,
+) -> Result<(), Base64Error> {
+    let input = encoded.as_bytes();
+    let (c62, c63) = config.alphabet.specials();
+
+    let data_end = if config.padding {
+        let pad = input.iter().rev().take_while(|&&c| c == b'=').count();
+        if pad > 2 {
+            return Err(Base64Error::InvalidPadding {
+                offset: input.len() - pad,
+            });
+        }
+        input.len() - pad
+    } else {
+        input.len()
+    };
+
+    if data_end % 4 == 1 {
+        return Err(Base64Error::InvalidLength(input.len()));
+    }
+
+    let (mut acc, mut bits) = (0u32, 0u32);
+    for (offset, &c) in input.iter().enumerate().take(data_end).skip(start) {
+        let sextet = sextet(c, c62, c63).ok_or_else(|| invalid_char_or_padding(encoded, offset))?;
+        acc = (acc << 6) | sextet as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((acc >> bits) as u8);
+        }
+    }
+
+    if acc & ((1 << bits) - 1) != 0 {
+        return Err(Base64Error::TrailingBits {
+            offset: data_end - 1,
+        });
+    }
+
+    Ok(())
+}
+
+/// Lane-wise sextet (0..64) to ASCII.
+#[inline(always)]
+fn sextets_to_ascii(sextets: Simd<u8, LANES>, c62: u8, c63: u8) -> Simd<u8, LANES> {
+    // Each range of the alphabet is contiguous in ASCII, so it's just an offset per range
+    // (with wrapping adds doing the subtraction for digits and the two specials).
+    let offset = sextets.simd_lt(Simd::splat(26)).select(
+        Simd::splat(b'A'),
+        sextets.simd_lt(Simd::splat(52)).select(
+            Simd::splat(b'a'.wrapping_sub(26)),
+            sextets.simd_lt(Simd::splat(62)).select(
+                Simd::splat(b'0'.wrapping_sub(52)),
+                sextets.simd_eq(Simd::splat(62)).select(
+                    Simd::splat(c62.wrapping_sub(62)),
+                    Simd::splat(c63.wrapping_sub(63)),
+                ),
+            ),
+        ),
+    );
+    sextets + offset
+}
+
+/// Lane-wise ASCII to sextet, plus a bitmask of the lanes that aren't in the alphabet.
+#[inline(always)]
+fn ascii_to_sextets(chars: Simd<u8, LANES>, c62: u8, c63: u8) -> (Simd<u8, LANES>, u64) {
+    // Wrapping subtraction pushes everything below each range up past it,
+    // so one unsigned compare checks both ends.
+    let upper = chars - Simd::splat(b'A');
+    let lower = chars - Simd::splat(b'a');
+    let digit = chars - Simd::splat(b'0');
+
+    let is_upper = upper.simd_lt(Simd::splat(26));
+    let is_lower = lower.simd_lt(Simd::splat(26));
+    let is_digit = digit.simd_lt(Simd::splat(10));
+    let is_62 = chars.simd_eq(Simd::splat(c62));
+    let is_63 = chars.simd_eq(Simd::splat(c63));
+
+    let sextets = is_upper.select(
+        upper,
+        is_lower.select(
+            lower + Simd::splat(26),
+            is_digit.select(
+                digit + Simd::splat(52),
+                is_62.select(Simd::splat(62), Simd::splat(63)),
+            ),
+        ),
+    );
+    let invalid = !(is_upper | is_lower | is_digit | is_62 | is_63);
+
+    (sextets, invalid.to_bitmask())
+}
+
+fn sextet(c: u8, c62: u8, c63: u8) -> Option<u8> {
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        _ if c == c62 => Some(62),
+        _ if c == c63 => Some(63),
+        _ => None,
+    }
+}
+
+fn scalar_table(alphabet: Base64Alphabet) -> [u8; 64] {
+    let (c62, c63) = alphabet.specials();
+    std::array::from_fn(|i| match i as u8 {
+        s @ 0..26 => b'A' + s,
+        s @ 26..52 => b'a' + s - 26,
+        s @ 52..62 => b'0' + s - 52,
+        62 => c62,
+        _ => c63,
+    })
+}
+
+fn invalid_char_or_padding(encoded: &str, offset: usize) -> Base64Error {
+    if encoded.as_bytes()[offset] == b'=' {
+        return Base64Error::InvalidPadding { offset };
+    }
+    // Everything before `offset` was in the (ASCII) alphabet, so it's always a char boundary.
+    let ch = encoded[offset..].chars().next().unwrap_or_default();
+    Base64Error::InvalidChar { offset, ch }
+}
@@ -0,0 +1,302 @@
+//! Shift-Or (bitap) search for short patterns with a character class per position,
+//! like `[Aa]vx-[0-9]`.
+//!
+//! Shift-Or keeps one bit per pattern position in a `u64`: bit `i` is clear when the
+//! last `i + 1` bytes match the first `i + 1` positions. Each haystack byte costs a
+//! shift and an OR with that byte's precomputed mask, so a class costs no more than
+//! a literal, but every step depends on the one before. To get more than one byte in
+//! flight the haystack is cut into `STREAMS` segments, each with its own lane of the
+//! state vector, which all step together.
+
+use std::{
+    array, fmt,
+    simd::{Simd, cmp::SimdPartialEq},
+};
+
+use crate::LOGICAL_LANES_;
+
+/// Independent states stepped together, one `u64` lane each.
+const STREAMS: usize = LOGICAL_LANES_ * 2;
+/// Match starts each stream covers per block.
+const SEGMENT: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternError {
+    Empty,
+    /// More than 64 positions, which don't fit in the `u64` state.
+    TooLong(usize),
+    /// A `[` without its `]`, and the offset of the `[`.
+    UnclosedClass {
+        offset: usize,
+    },
+    /// A `\` as the very last byte, and its offset.
+    TrailingEscape {
+        offset: usize,
+    },
+    /// A range like `z-a` whose end comes before its start, and the offset of its start.
+    InvalidRange {
+        offset: usize,
+    },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty pattern"),
+            Self::TooLong(len) => write!(f, "pattern has {len} positions, at most 64 fit"),
+            Self::UnclosedClass { offset } => write!(f, "unclosed '[' at offset {offset}"),
+            Self::TrailingEscape { offset } => write!(f, "trailing '\\' at offset {offset}"),
+            Self::InvalidRange { offset } => write!(f, "invalid range at offset {offset}"),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// A compiled pattern of up to 64 positions, each matching one byte.
+///
+/// The syntax is deliberately small:
+/// - `[...]` is any one of the bytes listed, with ranges like `0-9` and a leading `^`
+///   to negate it (`[^,]`). A `-` first or last is literal.
+/// - `.` is any byte.
+/// - `\` makes the next byte literal (`\[`, `\.`, `\\`).
+/// - Anything else matches itself.
+///
+/// Everything works on bytes, so a class can't hold a multi-byte UTF-8 character
+/// (outside of a class it's just a run of literal bytes and is fine).
+#[derive(Debug, Clone)]
+pub struct ShiftOr {
+    /// For each byte, bit `i` is clear when position `i` accepts it.
+    masks: Box<[u64; 256]>,
+    len: usize,
+}
+
+impl ShiftOr {
+    pub fn new(pattern: &[u8]) -> Result<Self, PatternError> {
+        let classes = parse(pattern)?;
+        if classes.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        if classes.len() > 64 {
+            return Err(PatternError::TooLong(classes.len()));
+        }
+
+        let mut masks = Box::new([!0u64; 256]);
+        for (i, class) in classes.iter().enumerate() {
+            for (b, mask) in masks.iter_mut().enumerate() {
+                if class[b] {
+                    *mask &= !(1 << i);
+                }
+            }
+        }
+
+        Ok(Self {
+            masks,
+            len: classes.len(),
+        })
+    }
+
+    /// Number of positions, which is also the length of every match.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Always `false`, empty patterns are rejected by [`ShiftOr::new`].
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Offset of the first match in `haystack`.
+    ///
+    /// Blocks of `STREAMS * SEGMENT` starts go through the multi-lane state, which
+    /// only says which segments matched; the first of those is rescanned one state
+    /// at a time for the offset.
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        let mut found = None;
+        self.scan(haystack, |segment, offset| {
+            found = self.find_scalar(segment).map(|pos| offset + pos);
+            found.is_none()
+        });
+        found
+    }
+
+    /// Offsets of every (possibly overlapping) match in `haystack`.
+    pub fn find_all(&self, haystack: &[u8]) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.scan(haystack, |segment, offset| {
+            self.push_matches(segment, offset, &mut found);
+            true
+        });
+        found
+    }
+
+    pub fn contains(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// [`ShiftOr::find`] one state at a time, the textbook loop.
+    pub fn find_scalar(&self, haystack: &[u8]) -> Option<usize> {
+        let accept = 1 << (self.len - 1);
+        let mut state = !0u64;
+        for (i, &b) in haystack.iter().enumerate() {
+            state = (state << 1) | self.masks[b as usize];
+            if state & accept == 0 {
+                return Some(i + 1 - self.len);
+            }
+        }
+        None
+    }
+
+    /// [`ShiftOr::find_all`] one state at a time.
+    pub fn find_all_scalar(&self, haystack: &[u8]) -> Vec<usize> {
+        let mut found = Vec::new();
+        self.push_matches(haystack, 0, &mut found);
+        found
+    }
+
+    /// Pushes the offset (plus `offset`) of every match in `haystack` onto `found`.
+    fn push_matches(&self, haystack: &[u8], offset: usize, found: &mut Vec<usize>) {
+        let accept = 1 << (self.len - 1);
+        let mut state = !0u64;
+        for (i, &b) in haystack.iter().enumerate() {
+            state = (state << 1) | self.masks[b as usize];
+            if state & accept == 0 {
+                found.push(offset + i + 1 - self.len);
+            }
+        }
+    }
+
+    /// Calls `on_match(segment, offset)`, in haystack order, for every stretch of
+    /// `haystack` that has a match starting in it, until it returns `false`.
+    ///
+    /// Each segment is handed over with the `len - 1` bytes after it, so the matches
+    /// starting in it are whole, and a match is only ever in one segment.
+    fn scan(&self, haystack: &[u8], mut on_match: impl FnMut(&[u8], usize) -> bool) {
+        let Some(starts) = (haystack.len() + 1).checked_sub(self.len) else {
+            return;
+        };
+        let block = STREAMS * SEGMENT;
+
+        let mut base = 0;
+        while base + block <= starts {
+            let window = &haystack[base..base + block + self.len - 1];
+            let mut hits = self.scan_block(window);
+            while hits != 0 {
+                let start = hits.trailing_zeros() as usize * SEGMENT;
+                let segment = &window[start..start + SEGMENT + self.len - 1];
+                if !on_match(segment, base + start) {
+                    return;
+                }
+                hits &= hits - 1;
+            }
+            base += block;
+        }
+
+        // Less than a block of starts left, not worth the setup
+        if base < starts {
+            on_match(&haystack[base..], base);
+        }
+    }
+
+    /// Bitmask of the segments of `window` that have a match starting in them.
+    #[inline(always)]
+    fn scan_block(&self, window: &[u8]) -> u64 {
+        let steps = SEGMENT + self.len - 1;
+        // Column `t` starts at lane 0's `t`th byte and reaches lane `STREAMS - 1`'s.
+        let columns = window.windows((STREAMS - 1) * SEGMENT + 1).take(steps);
+
+        // Starting from all ones, the accept bit can't clear before a whole pattern's
+        // worth of bytes, so the first `len - 1` steps of a lane are its warmup.
+        let mut state = Simd::<u64, STREAMS>::splat(!0);
+        let mut seen = state;
+        for column in columns {
+            let mask =
+                Simd::from_array(array::from_fn(|k| self.masks[column[k * SEGMENT] as usize]));
+            state = (state << Simd::splat(1)) | mask;
+            seen &= state;
+        }
+
+        let accept = Simd::splat(1 << (self.len - 1));
+        (seen & accept).simd_eq(Simd::splat(0)).to_bitmask()
+    }
+}
+
+/// One "does this position accept byte `b`" table per position of `pattern`.
+fn parse(pattern: &[u8]) -> Result<Vec<[bool; 256]>, PatternError> {
+    let mut classes = Vec::new();
+    let mut i = 0;
+
+    while i < pattern.len() {
+        let mut class = [false; 256];
+        match pattern[i] {
+            b'[' => {
+                let open = i;
+                i += 1;
+                let negate = pattern.get(i) == Some(&b'^');
+                if negate {
+                    i += 1;
+                }
+
+                let mut first = true;
+                loop {
+                    let Some(&b) = pattern.get(i) else {
+                        return Err(PatternError::UnclosedClass { offset: open });
+                    };
+                    if b == b']' && !first {
+                        break;
+                    }
+                    first = false;
+
+                    let start_offset = i;
+                    let lo = class_byte(pattern, &mut i, open)?;
+                    let hi = if pattern.get(i) == Some(&b'-')
+                        && pattern.get(i + 1).is_some_and(|&b| b != b']')
+                    {
+                        i += 1;
+                        class_byte(pattern, &mut i, open)?
+                    } else {
+                        lo
+                    };
+                    if hi < lo {
+                        return Err(PatternError::InvalidRange {
+                            offset: start_offset,
+                        });
+                    }
+                    class[lo as usize..=hi as usize].fill(true);
+                }
+
+                if negate {
+                    class.iter_mut().for_each(|accept| *accept = !*accept);
+                }
+            }
+            b'.' => class = [true; 256],
+            b'\\' => {
+                let Some(&b) = pattern.get(i + 1) else {
+                    return Err(PatternError::TrailingEscape { offset: i });
+                };
+                class[b as usize] = true;
+                i += 1;
+            }
+            b => class[b as usize] = true,
+        }
+
+        classes.push(class);
+        i += 1;
+    }
+
+    Ok(classes)
+}
+
+/// The (possibly escaped) byte at `pattern[*i]` inside the class opened at `open`,
+/// moving `i` past it.
+fn class_byte(pattern: &[u8], i: &mut usize, open: usize) -> Result<u8, PatternError> {
+    let b = match pattern.get(*i) {
+        Some(b'\\') => {
+            *i += 1;
+            pattern.get(*i).copied()
+        }
+        b => b.copied(),
+    };
+    *i += 1;
+    b.ok_or(PatternError::UnclosedClass { offset: open })
+}
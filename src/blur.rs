@@ -0,0 +1,480 @@
+//! Separable box and Gaussian blur for greyscale and RGBA images, rows split across
+//! threads with rayon.
+//!
+//! Both go a row at a time and then a column at a time, through an intermediate
+//! image of wider integers. Rows are copied out with the edge mode applied first, so
+//! the inner loops never check bounds; columns look up which source row each tap
+//! lands on once per output row, and then go across `LANES` bytes at a time. Bytes
+//! from different channels sit side by side the whole way, which is what lets one
+//! loop handle both greyscale and RGBA.
+
+use std::{
+    fmt,
+    simd::{
+        Simd,
+        num::{SimdFloat, SimdUint},
+    },
+};
+
+use rayon::prelude::*;
+
+use crate::LOGICAL_LANES_;
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Fractional bits of the Gaussian weights, which sum to exactly `1 << WEIGHT_BITS`.
+const WEIGHT_BITS: u32 = 12;
+/// The most taps a kernel can have either way, `2 * radius + 1` for a box blur.
+///
+/// A box blur's column sums are over up to `MAX_KERNEL_SIZE²` pixels, and this is
+/// as far as dividing them in `f64` still rounds exactly.
+pub const MAX_KERNEL_SIZE: usize = (1 << 22) - 1;
+/// Output rows each thread takes at once in the box blur's column pass, which has
+/// to start its running sums over for each.
+const BAND: usize = 32;
+
+/// Where taps that fall off the edge of the image read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EdgeMode {
+    /// The nearest edge pixel, over and over.
+    #[default]
+    Clamp,
+    /// The image reflected at its edge, edge pixel included (`cba|abcd|dcb`).
+    Mirror,
+    /// The other side of the image, as if it were tiled.
+    Wrap,
+}
+
+impl EdgeMode {
+    /// The index that `i` reads from, in a row or column of `len` pixels.
+    pub(crate) fn source(self, i: isize, len: usize) -> usize {
+        let len = len as isize;
+        let source = match self {
+            Self::Clamp => i.clamp(0, len - 1),
+            Self::Mirror => {
+                let i = i.rem_euclid(2 * len);
+                if i < len { i } else { 2 * len - 1 - i }
+            }
+            Self::Wrap => i.rem_euclid(len),
+        };
+        source as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KernelError {
+    /// Kernels need a middle tap, so an even size (or zero) doesn't work.
+    EvenSize(usize),
+    /// Sigma has to be finite and more than zero.
+    InvalidSigma(f32),
+    /// More taps than [`MAX_KERNEL_SIZE`] (saturating, for sizes past `usize`).
+    TooLarge(usize),
+    /// Weights have to be finite and not negative, and can't all be zero.
+    InvalidWeights,
+}
+
+impl fmt::Display for KernelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EvenSize(size) => write!(f, "kernel size {size} isn't odd"),
+            Self::InvalidSigma(sigma) => write!(f, "invalid sigma {sigma}"),
+            Self::TooLarge(size) => {
+                write!(
+                    f,
+                    "kernel size {size} is over the limit of {MAX_KERNEL_SIZE}"
+                )
+            }
+            Self::InvalidWeights => write!(f, "weights must be finite, non-negative and not all 0"),
+        }
+    }
+}
+
+impl std::error::Error for KernelError {}
+
+/// A one-dimensional kernel, applied across and then down. Usually a Gaussian, but
+/// [`GaussianKernel::from_weights`] takes any.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GaussianKernel {
+    /// In 4096ths, summing to exactly 4096 so flat areas stay the same.
+    weights: Vec<u32>,
+}
+
+impl GaussianKernel {
+    /// `size` taps of a Gaussian with standard deviation `sigma`, cut off there.
+    pub fn new(size: usize, sigma: f32) -> Result<Self, KernelError> {
+        check_size(size)?;
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return Err(KernelError::InvalidSigma(sigma));
+        }
+
+        let radius = (size / 2) as f64;
+        let sigma = sigma as f64;
+        let exact: Vec<f64> = (0..size)
+            .map(|i| (-(i as f64 - radius).powi(2) / (2.0 * sigma * sigma)).exp())
+            .collect();
+        Ok(Self {
+            weights: fixed_point(&exact),
+        })
+    }
+
+    /// A kernel of any odd number of `weights`, which don't need to be symmetric.
+    /// They're scaled to sum to 1, so only their ratios matter.
+    pub fn from_weights(weights: &[f64]) -> Result<Self, KernelError> {
+        check_size(weights.len())?;
+        let valid = weights.iter().all(|w| w.is_finite() && *w >= 0.0);
+        let largest = weights.iter().copied().fold(0.0, f64::max);
+        if !valid || largest == 0.0 {
+            return Err(KernelError::InvalidWeights);
+        }
+        // Relative to the largest first, so huge weights can't sum to infinity
+        let weights: Vec<f64> = weights.iter().map(|w| w / largest).collect();
+        Ok(Self {
+            weights: fixed_point(&weights),
+        })
+    }
+
+    /// [`GaussianKernel::new`] with `size` covering three sigmas either side, past
+    /// which the weights round to nothing anyway.
+    pub fn with_sigma(sigma: f32) -> Result<Self, KernelError> {
+        if !sigma.is_finite() || sigma <= 0.0 {
+            return Err(KernelError::InvalidSigma(sigma));
+        }
+        // In `f64`, so a huge sigma can't overflow on the way to being rejected
+        let size = 2.0 * (3.0 * sigma as f64).ceil() + 1.0;
+        if size > MAX_KERNEL_SIZE as f64 {
+            return Err(KernelError::TooLarge(size as usize));
+        }
+        Self::new(size as usize, sigma)
+    }
+
+    pub fn size(&self) -> usize {
+        self.weights.len()
+    }
+}
+
+/// Kernels need a middle tap, and their intermediate sums have to stay exact.
+fn check_size(size: usize) -> Result<(), KernelError> {
+    if size.is_multiple_of(2) {
+        Err(KernelError::EvenSize(size))
+    } else if size > MAX_KERNEL_SIZE {
+        Err(KernelError::TooLarge(size))
+    } else {
+        Ok(())
+    }
+}
+
+/// `weights` scaled to sum to `1 << WEIGHT_BITS`, rounding each down and giving the
+/// units left over to the ones that lost most.
+fn fixed_point(weights: &[f64]) -> Vec<u32> {
+    let one = (1 << WEIGHT_BITS) as f64;
+    let sum: f64 = weights.iter().sum();
+    let exact: Vec<f64> = weights.iter().map(|w| w / sum * one).collect();
+    let mut fixed: Vec<u32> = exact.iter().map(|w| w.floor() as u32).collect();
+
+    let short = (1 << WEIGHT_BITS) - fixed.iter().sum::<u32>();
+    let mut by_remainder: Vec<usize> = (0..fixed.len()).collect();
+    by_remainder
+        .sort_by(|&a, &b| (exact[b] - exact[b].floor()).total_cmp(&(exact[a] - exact[a].floor())));
+    for &i in &by_remainder[..short as usize] {
+        fixed[i] += 1;
+    }
+
+    fixed
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Blur {
+    /// The mean of the `2 * radius + 1` pixel square around each pixel, with running
+    /// sums so the cost doesn't grow with the radius. See [`Blur::box_radius`] for a
+    /// checked radius.
+    Box {
+        radius: usize,
+    },
+    Gaussian(GaussianKernel),
+}
+
+impl Blur {
+    /// [`Blur::Box`], if `2 * radius + 1` is no more than [`MAX_KERNEL_SIZE`].
+    pub fn box_radius(radius: usize) -> Result<Self, KernelError> {
+        check_size(radius.saturating_mul(2).saturating_add(1))?;
+        Ok(Self::Box { radius })
+    }
+}
+
+/// `luma`, a `width` by `height` greyscale image, blurred.
+///
+/// # Panics
+/// If `luma` isn't `width * height` bytes, or `blur` is a box bigger than
+/// [`MAX_KERNEL_SIZE`].
+pub fn simd_blur_luma(
+    luma: &[u8],
+    width: usize,
+    height: usize,
+    blur: &Blur,
+    edge: EdgeMode,
+) -> Vec<u8> {
+    assert_eq!(
+        luma.len(),
+        width * height,
+        "expected a {width}x{height} image"
+    );
+    let mut output = vec![0; luma.len()];
+    blur_channels(luma, &mut output, width, height, 1, blur, edge);
+    output
+}
+
+/// `rgba`, a `width` by `height` image, blurred. Each channel is blurred on its own,
+/// alpha included (it isn't premultiplied first), same as `image::imageops::blur`.
+///
+/// # Panics
+/// If `rgba` isn't `width * height` pixels, or `blur` is a box bigger than
+/// [`MAX_KERNEL_SIZE`].
+pub fn simd_blur_rgba(
+    rgba: &[[u8; 4]],
+    width: usize,
+    height: usize,
+    blur: &Blur,
+    edge: EdgeMode,
+) -> Vec<[u8; 4]> {
+    assert_eq!(
+        rgba.len(),
+        width * height,
+        "expected a {width}x{height} image"
+    );
+    let mut output = vec![[0; 4]; rgba.len()];
+    blur_channels(
+        rgba.as_flattened(),
+        output.as_flattened_mut(),
+        width,
+        height,
+        4,
+        blur,
+        edge,
+    );
+    output
+}
+
+fn blur_channels(
+    pixels: &[u8],
+    output: &mut [u8],
+    width: usize,
+    height: usize,
+    channels: usize,
+    blur: &Blur,
+    edge: EdgeMode,
+) {
+    if let Blur::Box { radius } = blur
+        && let Err(error) = Blur::box_radius(*radius)
+    {
+        panic!("{error}");
+    }
+    if pixels.is_empty() {
+        return;
+    }
+    let image = Image {
+        width,
+        height,
+        channels,
+        edge,
+    };
+    match blur {
+        Blur::Box { radius } => image.box_blur(pixels, output, *radius),
+        Blur::Gaussian(kernel) => image.gaussian(pixels, output, &kernel.weights),
+    }
+}
+
+/// The shape of the image being blurred.
+struct Image {
+    width: usize,
+    height: usize,
+    channels: usize,
+    edge: EdgeMode,
+}
+
+impl Image {
+    /// Bytes in a row.
+    fn row(&self) -> usize {
+        self.width * self.channels
+    }
+
+    /// Elements per row of the intermediate image, rounded up so the column pass
+    /// only ever loads whole vectors.
+    fn stride(&self) -> usize {
+        self.row().next_multiple_of(LANES)
+    }
+
+    /// `row` with `radius` pixels of edge either side, followed by a vector's worth
+    /// of slack, into `padded`.
+    fn pad<T: From<u8> + Default + Clone>(&self, row: &[u8], radius: usize, padded: &mut Vec<T>) {
+        let channels = self.channels;
+        let push_edge = |padded: &mut Vec<T>, x: isize| {
+            let source = self.edge.source(x, self.width);
+            padded.extend(
+                row[source * channels..][..channels]
+                    .iter()
+                    .map(|&b| T::from(b)),
+            );
+        };
+
+        padded.clear();
+        for x in -(radius as isize)..0 {
+            push_edge(padded, x);
+        }
+        padded.extend(row.iter().map(|&b| T::from(b)));
+        for x in self.width..self.width + radius {
+            push_edge(padded, x as isize);
+        }
+        padded.resize(padded.len() + LANES, T::default());
+    }
+
+    /// Which rows the taps of output row `y` read from.
+    fn source_rows(&self, y: usize, radius: usize) -> impl Iterator<Item = usize> {
+        (0..2 * radius + 1).map(move |k| {
+            self.edge
+                .source((y + k) as isize - radius as isize, self.height)
+        })
+    }
+
+    /// Both passes in `f32`, which SSE2 multiplies natively where it has nothing
+    /// for 32-bit integers.
+    fn gaussian(&self, pixels: &[u8], output: &mut [u8], weights: &[u32]) {
+        let (row, stride, channels) = (self.row(), self.stride(), self.channels);
+        let radius = weights.len() / 2;
+        // Still summing to exactly 1, so flat areas stay the same
+        let weights: Vec<Simd<f32, LANES>> = weights
+            .iter()
+            .map(|&w| Simd::splat(w as f32 / (1 << WEIGHT_BITS) as f32))
+            .collect();
+        let symmetric = weights.iter().eq(weights.iter().rev());
+
+        // Across each row
+        let mut across = vec![0.0; stride * self.height];
+        across
+            .par_chunks_mut(stride)
+            .zip(pixels.par_chunks(row))
+            .for_each_init(Vec::new, |padded: &mut Vec<f32>, (out, row)| {
+                self.pad(row, radius, padded);
+                for (x, out) in out.as_chunks_mut::<LANES>().0.iter_mut().enumerate() {
+                    let taps = &padded[x * LANES..];
+                    let tap =
+                        |k: usize| Simd::<f32, LANES>::from_slice(&taps[k * channels..][..LANES]);
+                    *out = convolve(&weights, symmetric, tap).to_array();
+                }
+            });
+
+        // Then down each column, back to bytes
+        output
+            .par_chunks_mut(row)
+            .enumerate()
+            .for_each_init(Vec::new, |sources, (y, out)| {
+                sources.clear();
+                sources.extend(
+                    self.source_rows(y, radius)
+                        .map(|source| &across[source * stride..][..stride]),
+                );
+                for x in (0..row).step_by(LANES) {
+                    let tap = |k: usize| Simd::<f32, LANES>::from_slice(&sources[k][x..x + LANES]);
+                    let sum = convolve(&weights, symmetric, tap);
+                    let blurred = (sum + Simd::splat(0.5)).cast::<u8>();
+                    let len = LANES.min(row - x);
+                    out[x..x + len].copy_from_slice(&blurred.as_array()[..len]);
+                }
+            });
+    }
+
+    fn box_blur(&self, pixels: &[u8], output: &mut [u8], radius: usize) {
+        let (row, stride, channels) = (self.row(), self.stride(), self.channels);
+        let size = 2 * radius + 1;
+
+        // Across each row, as sums of `size` pixels: each is the one before, plus the
+        // pixel coming into the window, less the one leaving it
+        let mut across = vec![0u32; stride * self.height];
+        across
+            .par_chunks_mut(stride)
+            .zip(pixels.par_chunks(row))
+            .for_each_init(Vec::new, |padded: &mut Vec<u8>, (out, row_pixels)| {
+                self.pad(row_pixels, radius, padded);
+                for c in 0..channels {
+                    out[c] = (0..size).map(|k| padded[c + k * channels] as u32).sum();
+                }
+                for i in channels..row {
+                    out[i] = out[i - channels] + padded[i + (size - 1) * channels] as u32
+                        - padded[i - channels] as u32;
+                }
+            });
+
+        // Then down each column the same way, `BAND` rows to a thread. These sums can
+        // be up to `255 * MAX_KERNEL_SIZE²`, so they're `u64`
+        let area = Simd::<f64, LANES>::splat((size * size) as f64);
+        output.par_chunks_mut(row * BAND).enumerate().for_each_init(
+            Vec::new,
+            |sums: &mut Vec<u64>, (band, out)| {
+                let first = band * BAND;
+                sums.clear();
+                sums.resize(stride, 0);
+                for source in self.source_rows(first, radius) {
+                    update_sums(sums, &across[source * stride..][..stride], |sum, add| {
+                        sum + add
+                    });
+                }
+
+                for (y, out) in out.chunks_exact_mut(row).enumerate() {
+                    let y = first + y;
+                    for (x, out) in out.chunks_mut(LANES).enumerate() {
+                        let sum = Simd::<u64, LANES>::from_slice(&sums[x * LANES..][..LANES]);
+                        // A mean of an odd number of pixels is at least 1 / (2 * area)
+                        // from halfway between two steps, which is more than `f64`
+                        // division can be out by, so this rounds the same as integers
+                        let mean = (sum.cast::<f64>() / area + Simd::splat(0.5)).cast::<u8>();
+                        out.copy_from_slice(&mean.as_array()[..out.len()]);
+                    }
+
+                    let leaving = self.edge.source(y as isize - radius as isize, self.height);
+                    let entering = self.edge.source((y + radius + 1) as isize, self.height);
+                    update_sums(sums, &across[entering * stride..][..stride], |sum, add| {
+                        sum + add
+                    });
+                    update_sums(sums, &across[leaving * stride..][..stride], |sum, sub| {
+                        sum - sub
+                    });
+                }
+            },
+        );
+    }
+}
+
+/// `weights` applied to `tap(0)` to `tap(weights.len() - 1)`. Symmetric kernels
+/// (all the Gaussians) add the taps either side of the middle together before
+/// they're weighted, saving a multiply each.
+#[inline(always)]
+fn convolve(
+    weights: &[Simd<f32, LANES>],
+    symmetric: bool,
+    tap: impl Fn(usize) -> Simd<f32, LANES>,
+) -> Simd<f32, LANES> {
+    let last = weights.len() - 1;
+    let radius = last / 2;
+    let mut sum = tap(radius) * weights[radius];
+    for k in 0..radius {
+        sum += if symmetric {
+            (tap(k) + tap(last - k)) * weights[k]
+        } else {
+            tap(k) * weights[k] + tap(last - k) * weights[last - k]
+        };
+    }
+    sum
+}
+
+/// Each of `sums` replaced with `op` of it and the matching element of `row`.
+#[inline(always)]
+fn update_sums(
+    sums: &mut [u64],
+    row: &[u32],
+    op: impl Fn(Simd<u64, LANES>, Simd<u64, LANES>) -> Simd<u64, LANES>,
+) {
+    let (sums, _) = sums.as_chunks_mut::<LANES>();
+    let (row, _) = row.as_chunks::<LANES>();
+    for (sum, &add) in sums.iter_mut().zip(row) {
+        *sum = op(Simd::from_array(*sum), Simd::from_array(add).cast()).to_array();
+    }
+}
@@ -0,0 +1,103 @@
+//! Byte string equality and ordering, `LANES` bytes at a time.
+//!
+//! Everything here comes down to finding the first differing byte: compare a chunk
+//! with `simd_ne`, and if the bitmask isn't empty its `trailing_zeros` is the
+//! offset. [`simd_eq`] is also what [`simd_find_str`](crate::simd_find_str) and
+//! [`Finder`](crate::Finder) verify their candidates with.
+
+use std::{
+    cmp::Ordering,
+    simd::{Simd, cmp::SimdPartialEq},
+};
+
+use crate::LOGICAL_LANES_;
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Length of the longest common prefix of `a` and `b`.
+pub fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    let len = a.len().min(b.len());
+    let (a, b) = (&a[..len], &b[..len]);
+
+    let mut i = 0;
+    while i + LANES <= len {
+        if let Some(offset) = first_difference(&a[i..], &b[i..]) {
+            return i + offset;
+        }
+        i += LANES;
+    }
+
+    if i == len {
+        return len;
+    }
+
+    if len >= LANES {
+        // Redo the last full vector's worth rather than going a byte at a time, the
+        // overlap was already known to be equal.
+        let start = len - LANES;
+        return first_difference(&a[start..], &b[start..]).map_or(len, |offset| start + offset);
+    }
+
+    short_prefix_len(a, b)
+}
+
+/// [`common_prefix_len`] for inputs shorter than a vector (and the same length).
+///
+/// Titles and the like are mostly this short, so rather than a byte at a time this
+/// XORs two overlapping words from each end, where the first set bit is the first
+/// difference.
+#[inline(always)]
+fn short_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    let len = a.len();
+    if len >= 8 {
+        let word = |s: &[u8], at: usize| u64::from_le_bytes(s[at..at + 8].try_into().unwrap());
+        let front = word(a, 0) ^ word(b, 0);
+        if front != 0 {
+            return front.trailing_zeros() as usize / 8;
+        }
+        let back = word(a, len - 8) ^ word(b, len - 8);
+        if back != 0 {
+            len - 8 + back.trailing_zeros() as usize / 8
+        } else {
+            len
+        }
+    } else if len >= 4 {
+        let word = |s: &[u8], at: usize| u32::from_le_bytes(s[at..at + 4].try_into().unwrap());
+        let front = word(a, 0) ^ word(b, 0);
+        if front != 0 {
+            return front.trailing_zeros() as usize / 8;
+        }
+        let back = word(a, len - 4) ^ word(b, len - 4);
+        if back != 0 {
+            len - 4 + back.trailing_zeros() as usize / 8
+        } else {
+            len
+        }
+    } else {
+        a.iter().zip(b).position(|(x, y)| x != y).unwrap_or(len)
+    }
+}
+
+/// `a == b`, comparing `LANES` bytes at a time.
+pub fn simd_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && common_prefix_len(a, b) == a.len()
+}
+
+/// Lexicographic (byte-wise) ordering of `a` and `b`, the same as `a.cmp(b)`.
+pub fn simd_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let prefix = common_prefix_len(a, b);
+    match (a.get(prefix), b.get(prefix)) {
+        (Some(x), Some(y)) => x.cmp(y),
+        // One is a prefix of the other (or they're equal), so the shorter one is first.
+        _ => a.len().cmp(&b.len()),
+    }
+}
+
+/// Offset of the first difference in the first `LANES` bytes of `a` and `b`.
+#[inline(always)]
+fn first_difference(a: &[u8], b: &[u8]) -> Option<usize> {
+    let a = Simd::<u8, LANES>::from_slice(&a[..LANES]);
+    let b = Simd::<u8, LANES>::from_slice(&b[..LANES]);
+    let different = a.simd_ne(b).to_bitmask();
+    (different != 0).then(|| different.trailing_zeros() as usize)
+}
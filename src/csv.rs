@@ -0,0 +1,209 @@
+//! CSV/TSV tokenising with SIMD bitmasks, simdjson style.
+//!
+//! Each 64-byte block is classified into three `u64` masks (quotes, delimiters and
+//! newlines) with the same compare-then-`to_bitmask` trick as the candidate masks in
+//! [`simd_contains_pattern`](crate::simd_contains_pattern). A prefix XOR over the quote
+//! mask then marks every byte that's inside a quoted field, carried across blocks, so
+//! the delimiters and newlines that are left are the real field and record boundaries.
+//! RFC 4180 escaped quotes (`""`) toggle the mask twice and fall out for free.
+
+use std::{
+    borrow::Cow,
+    ops::Range,
+    simd::{Simd, cmp::SimdPartialEq},
+};
+
+use crate::{LOGICAL_LANES_, prefix_xor};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Bytes classified per step, one bit each in a `u64`.
+const BLOCK: usize = 64;
+
+const _: () = assert!(BLOCK.is_multiple_of(LANES), "a block must be whole vectors");
+
+/// Iterate over the records of `data`, split on `delimiter` (`b','` for CSV, `b'\t'` for TSV).
+///
+/// Records end at `\n` (a preceding `\r` is dropped) or at the end of the input.
+pub fn simd_csv_records(data: &[u8], delimiter: u8) -> CsvRecords<'_> {
+    CsvRecords {
+        data,
+        delimiter,
+        block_start: 0,
+        next_block: 0,
+        structurals: 0,
+        in_quote: 0,
+        field_start: 0,
+        fields: Vec::new(),
+    }
+}
+
+/// A record's fields, as ranges into the original input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvRecord<'a> {
+    data: &'a [u8],
+    fields: Vec<Range<usize>>,
+}
+
+impl<'a> CsvRecord<'a> {
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Byte ranges of each field in the input, quotes and all.
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.fields
+    }
+
+    /// The field exactly as it appears in the input, quotes and all.
+    pub fn raw_field(&self, i: usize) -> Option<&'a [u8]> {
+        self.fields.get(i).map(|range| &self.data[range.clone()])
+    }
+
+    /// The field with surrounding quotes stripped and `""` unescaped.
+    pub fn field(&self, i: usize) -> Option<Cow<'a, [u8]>> {
+        self.raw_field(i).map(csv_unescape)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'a, [u8]>> + '_ {
+        (0..self.len()).filter_map(|i| self.field(i))
+    }
+}
+
+/// Iterator over CSV records, see [`simd_csv_records`].
+#[derive(Debug, Clone)]
+pub struct CsvRecords<'a> {
+    data: &'a [u8],
+    delimiter: u8,
+    /// Offset of the block `structurals` was computed for.
+    block_start: usize,
+    /// Offset of the next block to classify.
+    next_block: usize,
+    /// Unvisited delimiters and newlines (outside quotes) in the current block.
+    structurals: u64,
+    /// All ones if the previous block ended inside a quoted field.
+    in_quote: u64,
+    field_start: usize,
+    fields: Vec<Range<usize>>,
+}
+
+impl<'a> Iterator for CsvRecords<'a> {
+    type Item = CsvRecord<'a>;
+
+    fn next(&mut self) -> Option<CsvRecord<'a>> {
+        loop {
+            while self.structurals != 0 {
+                let pos = self.block_start + self.structurals.trailing_zeros() as usize;
+                self.structurals &= self.structurals - 1;
+
+                if self.data[pos] == self.delimiter {
+                    self.fields.push(self.field_start..pos);
+                    self.field_start = pos + 1;
+                } else {
+                    let end = if pos > self.field_start && self.data[pos - 1] == b'\r' {
+                        pos - 1
+                    } else {
+                        pos
+                    };
+                    self.fields.push(self.field_start..end);
+                    self.field_start = pos + 1;
+                    return Some(self.take_record());
+                }
+            }
+
+            if !self.advance_block() {
+                break;
+            }
+        }
+
+        // The last record doesn't need a trailing newline.
+        if self.field_start < self.data.len() || !self.fields.is_empty() {
+            self.fields.push(self.field_start..self.data.len());
+            self.field_start = self.data.len();
+            return Some(self.take_record());
+        }
+
+        None
+    }
+}
+
+impl<'a> CsvRecords<'a> {
+    /// Classify the next block, returning `false` at the end of the input.
+    fn advance_block(&mut self) -> bool {
+        let next = self.next_block;
+        if next >= self.data.len() {
+            return false;
+        }
+
+        let block = &self.data[next..self.data.len().min(next + BLOCK)];
+        let (quotes, delimiters, newlines) = classify(block, self.delimiter);
+
+        let inside = prefix_xor(quotes) ^ self.in_quote;
+        // Smear the top bit: all ones if the block ended inside quotes.
+        self.in_quote = ((inside as i64) >> 63) as u64;
+
+        self.block_start = next;
+        self.next_block = next + BLOCK;
+        self.structurals = (delimiters | newlines) & !inside;
+        true
+    }
+
+    fn take_record(&mut self) -> CsvRecord<'a> {
+        CsvRecord {
+            data: self.data,
+            fields: std::mem::take(&mut self.fields),
+        }
+    }
+}
+
+/// Strip the surrounding quotes of a raw field and collapse `""` into `"`.
+pub fn csv_unescape(raw: &[u8]) -> Cow<'_, [u8]> {
+    match raw {
+        [b'"', inner @ .., b'"'] => {
+            if inner.contains(&b'"') {
+                let mut out = Vec::with_capacity(inner.len());
+                let mut bytes = inner.iter();
+                while let Some(&b) = bytes.next() {
+                    out.push(b);
+                    if b == b'"' {
+                        // Skip the second quote of the pair.
+                        bytes.next();
+                    }
+                }
+                Cow::Owned(out)
+            } else {
+                Cow::Borrowed(inner)
+            }
+        }
+        _ => Cow::Borrowed(raw),
+    }
+}
+
+/// Bitmasks of the quotes, delimiters and newlines in a (up to) 64-byte block.
+fn classify(block: &[u8], delimiter: u8) -> (u64, u64, u64) {
+    let quote = Simd::<u8, LANES>::splat(b'"');
+    let delimiter = Simd::<u8, LANES>::splat(delimiter);
+    let newline = Simd::<u8, LANES>::splat(b'\n');
+
+    let (mut quotes, mut delimiters, mut newlines) = (0u64, 0u64, 0u64);
+    for (i, offset) in (0..BLOCK).step_by(LANES).enumerate() {
+        // The last block may be short, zero padding never matches anything we look for
+        // (NUL delimiters aside, and those get masked off below).
+        let chunk = Simd::<u8, LANES>::load_or_default(block.get(offset..).unwrap_or_default());
+        let shift = i * LANES;
+        quotes |= chunk.simd_eq(quote).to_bitmask() << shift;
+        delimiters |= chunk.simd_eq(delimiter).to_bitmask() << shift;
+        newlines |= chunk.simd_eq(newline).to_bitmask() << shift;
+    }
+
+    let valid = if block.len() >= BLOCK {
+        u64::MAX
+    } else {
+        (1 << block.len()) - 1
+    };
+    (quotes & valid, delimiters & valid, newlines & valid)
+}
@@ -0,0 +1,268 @@
+//! 3×3 Sobel and Scharr gradients of a greyscale image, their magnitude and
+//! direction, and Canny-style non-maximum suppression to thin them down to edges.
+//!
+//! Both operators are a smoothing `[s, m, s]` one way and a difference `[-1, 0, 1]`
+//! the other, so each output row only needs the three source rows around it, each
+//! padded by a pixel either side. `LANES` pixels at a time then come from nine
+//! loads shifted by a pixel, in `i16`, which holds even Scharr's ±4080.
+
+use std::{
+    f32::consts::{FRAC_PI_2, PI},
+    simd::{
+        Select, Simd, StdFloat,
+        cmp::{SimdPartialEq, SimdPartialOrd},
+        num::{SimdFloat, SimdInt},
+    },
+};
+
+use rayon::prelude::*;
+
+use crate::{EdgeMode, LOGICAL_LANES_};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+type Lanes = Simd<f32, LANES>;
+
+/// `tan(π / 8)`, where 22.5° either side of a direction ends.
+const FRAC_PI_8_TAN: f32 = 0.414_213_57;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GradientOperator {
+    /// Smooths with `[1, 2, 1]`, gradients up to ±1020.
+    #[default]
+    Sobel,
+    /// Smooths with `[3, 10, 3]`, which is closer to rotationally symmetric, so
+    /// diagonal edges come out as strong as straight ones. Gradients up to ±4080.
+    Scharr,
+}
+
+impl GradientOperator {
+    /// The side and middle weights of the smoothing.
+    fn weights(self) -> (i16, i16) {
+        match self {
+            Self::Sobel => (1, 2),
+            Self::Scharr => (3, 10),
+        }
+    }
+}
+
+/// The horizontal and vertical derivatives of every pixel of a `width` by `height`
+/// image, row by row. `x` is positive where it gets brighter to the right, `y` where
+/// it gets brighter going down.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gradients {
+    pub width: usize,
+    pub height: usize,
+    pub x: Vec<i16>,
+    pub y: Vec<i16>,
+}
+
+/// The gradients of `luma`, a `width` by `height` greyscale image, with `edge`
+/// deciding what's past the border.
+///
+/// # Panics
+/// If `luma` isn't `width * height` bytes.
+pub fn simd_gradients(
+    luma: &[u8],
+    width: usize,
+    height: usize,
+    operator: GradientOperator,
+    edge: EdgeMode,
+) -> Gradients {
+    assert_eq!(
+        luma.len(),
+        width * height,
+        "expected a {width}x{height} image"
+    );
+    let mut gradients = Gradients {
+        width,
+        height,
+        x: vec![0; luma.len()],
+        y: vec![0; luma.len()],
+    };
+    if luma.is_empty() {
+        return gradients;
+    }
+
+    let (side, middle) = operator.weights();
+    let (side, middle) = (Simd::splat(side), Simd::splat(middle));
+    gradients
+        .x
+        .par_chunks_mut(width)
+        .zip(gradients.y.par_chunks_mut(width))
+        .enumerate()
+        .for_each_init(
+            || [Vec::new(), Vec::new(), Vec::new()],
+            |rows, (y, (out_x, out_y))| {
+                for (row, dy) in rows.iter_mut().zip(-1..=1) {
+                    let source = edge.source(y as isize + dy, height);
+                    pad_row(&luma[source * width..][..width], edge, row);
+                }
+                let [above, here, below] = &*rows;
+
+                for x in (0..width).step_by(LANES) {
+                    // Each row at `x - 1`, `x` and `x + 1`
+                    let taps = |row: &[i16]| {
+                        [0, 1, 2].map(|dx| Simd::<i16, LANES>::from_slice(&row[x + dx..][..LANES]))
+                    };
+                    let ([a0, a1, a2], [h0, _, h2], [b0, b1, b2]) =
+                        (taps(above), taps(here), taps(below));
+
+                    let gx = side * (a2 - a0) + middle * (h2 - h0) + side * (b2 - b0);
+                    let gy = side * (b0 - a0) + middle * (b1 - a1) + side * (b2 - a2);
+                    let len = LANES.min(width - x);
+                    out_x[x..x + len].copy_from_slice(&gx.as_array()[..len]);
+                    out_y[x..x + len].copy_from_slice(&gy.as_array()[..len]);
+                }
+            },
+        );
+
+    gradients
+}
+
+/// `row` widened to `i16` with a pixel of `edge` either side, followed by a vector's
+/// worth of slack, into `padded`.
+fn pad_row(row: &[u8], edge: EdgeMode, padded: &mut Vec<i16>) {
+    let width = row.len();
+    padded.clear();
+    padded.push(row[edge.source(-1, width)] as i16);
+    padded.extend(row.iter().map(|&b| b as i16));
+    padded.push(row[edge.source(width as isize, width)] as i16);
+    padded.resize(padded.len() + LANES, 0);
+}
+
+impl Gradients {
+    /// Length of each gradient.
+    pub fn magnitude(&self) -> Vec<f32> {
+        self.map(|x, y| (x * x + y * y).sqrt())
+    }
+
+    /// Angle of each gradient in radians, in `(-π, π]`, from the `x` axis towards the
+    /// `y` axis (so clockwise, as `y` goes down). Flat pixels have a direction of 0.
+    pub fn direction(&self) -> Vec<f32> {
+        self.map(|x, y| atan2(y, x))
+    }
+
+    /// `magnitude` (from [`Gradients::magnitude`]) with everything that isn't a peak
+    /// across its edge set to 0, leaving edges a pixel wide.
+    ///
+    /// Each direction is rounded to the nearest 45°, and a pixel is kept if it's
+    /// greater than its neighbour on one side along it and no less than the one on
+    /// the other, so plateaus two pixels wide keep one of them. Past the border
+    /// counts as 0.
+    ///
+    /// # Panics
+    /// If `magnitude` isn't one value per pixel.
+    pub fn non_max_suppression(&self, magnitude: &[f32]) -> Vec<f32> {
+        let (width, height) = (self.width, self.height);
+        assert_eq!(magnitude.len(), width * height, "one magnitude per pixel");
+        let mut output = vec![0.0; magnitude.len()];
+        if output.is_empty() {
+            return output;
+        }
+
+        // A border of 0 all round, and slack for the last vector
+        let stride = width + 2;
+        let mut padded = vec![0.0; stride * (height + 2) + LANES];
+        for (row, magnitude) in padded[stride + 1..]
+            .chunks_mut(stride)
+            .zip(magnitude.chunks_exact(width))
+        {
+            row[..width].copy_from_slice(magnitude);
+        }
+
+        let tan_22_5 = Simd::splat(FRAC_PI_8_TAN);
+        output
+            .par_chunks_mut(width)
+            .enumerate()
+            .for_each(|(y, out)| {
+                for x in (0..width).step_by(LANES) {
+                    let at = |dx: usize, dy: usize| {
+                        Lanes::from_slice(&padded[(y + dy) * stride + x + dx..][..LANES])
+                    };
+                    let gradient = |plane: &[i16]| {
+                        Simd::<i16, LANES>::load_or_default(&plane[y * width + x..]).cast::<f32>()
+                    };
+                    let (gx, gy) = (gradient(&self.x), gradient(&self.y));
+                    let (ax, ay) = (gx.abs(), gy.abs());
+
+                    // Neighbours along the gradient, `(1, 1)` being this pixel
+                    let across = ay.simd_le(ax * tan_22_5);
+                    let down = ax.simd_le(ay * tan_22_5);
+                    let main_diagonal = (gx * gy).simd_gt(Simd::splat(0.0));
+                    let before = across.select(
+                        at(0, 1),
+                        down.select(at(1, 0), main_diagonal.select(at(0, 0), at(2, 0))),
+                    );
+                    let after = across.select(
+                        at(2, 1),
+                        down.select(at(1, 2), main_diagonal.select(at(2, 2), at(0, 2))),
+                    );
+
+                    let here = at(1, 1);
+                    let peak = here.simd_gt(before) & here.simd_ge(after);
+                    let thinned = peak.select(here, Simd::splat(0.0));
+                    let len = LANES.min(width - x);
+                    out[x..x + len].copy_from_slice(&thinned.as_array()[..len]);
+                }
+            });
+
+        output
+    }
+
+    /// `f` of each pixel's `x` and `y` gradient, `LANES` at a time.
+    fn map(&self, f: impl Fn(Lanes, Lanes) -> Lanes) -> Vec<f32> {
+        let mut output = vec![0.0; self.x.len()];
+        let (xs, x_rest) = self.x.as_chunks::<LANES>();
+        let (ys, y_rest) = self.y.as_chunks::<LANES>();
+        let (out_chunks, out_rest) = output.as_chunks_mut::<LANES>();
+        for ((x, y), out) in xs.iter().zip(ys).zip(out_chunks) {
+            let (x, y) = (Simd::from_array(*x), Simd::from_array(*y));
+            *out = f(x.cast(), y.cast()).to_array();
+        }
+
+        // The last few, padded out to a whole vector
+        if !out_rest.is_empty() {
+            let x = Simd::<i16, LANES>::load_or_default(x_rest);
+            let y = Simd::<i16, LANES>::load_or_default(y_rest);
+            let len = out_rest.len();
+            out_rest.copy_from_slice(&f(x.cast(), y.cast()).as_array()[..len]);
+        }
+
+        output
+    }
+}
+
+/// `y.atan2(x)` to within 1e-5 radians.
+#[inline(always)]
+fn atan2(y: Lanes, x: Lanes) -> Lanes {
+    // Minimax fit of atan(a) / a in a² over [0, 1]
+    const CURVE: [f32; 6] = [
+        0.999_977_26,
+        -0.332_623_47,
+        0.193_543_46,
+        -0.116_432_87,
+        0.052_653_32,
+        -0.011_721_2,
+    ];
+
+    // atan of the smaller over the larger, then unfolded into the right octant
+    let (ax, ay) = (x.abs(), y.abs());
+    let ratio = ax.simd_min(ay) / ax.simd_max(ay);
+    let squared = ratio * ratio;
+    let curve = CURVE
+        .iter()
+        .rev()
+        .fold(Simd::splat(0.0), |acc, &c| acc * squared + Simd::splat(c));
+    let angle = curve * ratio;
+
+    let angle = ay.simd_gt(ax).select(Simd::splat(FRAC_PI_2) - angle, angle);
+    let angle = x
+        .simd_lt(Simd::splat(0.0))
+        .select(Simd::splat(PI) - angle, angle);
+    let angle = y.simd_lt(Simd::splat(0.0)).select(-angle, angle);
+    // Flat pixels divided 0 by 0 above
+    ax.simd_max(ay)
+        .simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(0.0), angle)
+}
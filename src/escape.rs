@@ -0,0 +1,120 @@
+//! Finding the bytes that need escaping, `LANES` at a time.
+//!
+//! Most strings headed for JSON or HTML output need no escaping at all, and the ones
+//! that do are mostly plain runs between a few hits, so the work is the scan. That's
+//! the same loop as [`simd_find_str`](crate::simd_find_str)'s single byte case with a
+//! few compares OR'd together.
+
+use std::{
+    borrow::Cow,
+    simd::{
+        Mask, Simd,
+        cmp::{SimdPartialEq, SimdPartialOrd},
+    },
+};
+
+use crate::{LOGICAL_LANES_, simd_find_by};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Which bytes [`simd_find_escape`] looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EscapeSet<'a> {
+    /// `"`, `\` and the control characters below `0x20`.
+    Json,
+    /// `<`, `>`, `&`, `'` and `"`.
+    Html,
+    /// Any of these bytes, e.g. `b"'\\ $`"` for shell output. Each one is another
+    /// compare per vector, so keep it short.
+    Custom(&'a [u8]),
+}
+
+const HTML: &[u8] = b"<>&'\"";
+
+/// Offset of the first byte in `bytes` that's in `set`.
+pub fn simd_find_escape(bytes: &[u8], set: EscapeSet<'_>) -> Option<usize> {
+    match set {
+        EscapeSet::Json => find_json_escape(bytes),
+        EscapeSet::Html => find_any(bytes, HTML),
+        EscapeSet::Custom(set) => find_any(bytes, set),
+    }
+}
+
+/// `s` escaped to go between the quotes of a JSON string, borrowed if there was
+/// nothing to escape.
+///
+/// `"` and `\` get a backslash, `\n`, `\r`, `\t`, `\b` and `\f` their short forms,
+/// and the other control characters `\u00XX`. Everything between hits is copied
+/// over in one go.
+pub fn simd_escape_json(s: &str) -> Cow<'_, str> {
+    let bytes = s.as_bytes();
+    let Some(first) = find_json_escape(bytes) else {
+        return Cow::Borrowed(s);
+    };
+
+    // A few escapes per string is typical, so a little headroom saves a regrow.
+    let mut out = String::with_capacity(s.len() + s.len() / 8 + 8);
+    let mut start = 0;
+    let mut hit = Some(first);
+
+    while let Some(offset) = hit {
+        let pos = start + offset;
+        // Escapes are all ASCII, so `pos` is always a char boundary.
+        out.push_str(&s[start..pos]);
+        push_json_escape(&mut out, bytes[pos]);
+
+        start = pos + 1;
+        hit = find_json_escape(&bytes[start..]);
+    }
+
+    out.push_str(&s[start..]);
+    Cow::Owned(out)
+}
+
+fn find_json_escape(bytes: &[u8]) -> Option<usize> {
+    let quote = Simd::splat(b'"');
+    let backslash = Simd::splat(b'\\');
+    let space = Simd::splat(b' ');
+
+    simd_find_by(
+        bytes,
+        |chunk| chunk.simd_eq(quote) | chunk.simd_eq(backslash) | chunk.simd_lt(space),
+        |b| b == b'"' || b == b'\\' || b < b' ',
+    )
+}
+
+fn find_any(bytes: &[u8], set: &[u8]) -> Option<usize> {
+    match set {
+        [] => None,
+        [b] => crate::simd_find_byte(bytes, *b),
+        _ => simd_find_by(
+            bytes,
+            |chunk| {
+                set.iter()
+                    .fold(Mask::<i8, LANES>::splat(false), |found, &b| {
+                        found | chunk.simd_eq(Simd::splat(b))
+                    })
+            },
+            |b| set.contains(&b),
+        ),
+    }
+}
+
+fn push_json_escape(out: &mut String, b: u8) {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    match b {
+        b'"' => out.push_str("\\\""),
+        b'\\' => out.push_str("\\\\"),
+        b'\n' => out.push_str("\\n"),
+        b'\r' => out.push_str("\\r"),
+        b'\t' => out.push_str("\\t"),
+        0x08 => out.push_str("\\b"),
+        0x0c => out.push_str("\\f"),
+        _ => {
+            out.push_str("\\u00");
+            out.push(HEX_DIGITS[(b >> 4) as usize] as char);
+            out.push(HEX_DIGITS[(b & 0x0f) as usize] as char);
+        }
+    }
+}
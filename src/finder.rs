@@ -0,0 +1,280 @@
+//! Precompiled substring searcher for when you look for the same needle over and over.
+//!
+//! [`simd_find_str`](crate::simd_find_str) has to re-splat its vectors and re-analyse
+//! the needle on every call, which is fine for a one-off but adds up when a service
+//! searches for the same thing millions of times. A [`Finder`] does that work once.
+
+use std::simd::{Simd, cmp::SimdPartialEq};
+
+use crate::{LOGICAL_LANES_, VERIFY_BUDGET_FACTOR, simd_eq, two_way::TwoWay};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Rough background frequency of each byte in English-ish text (higher is more common),
+/// used to pick the rarest needle bytes for the prefilter.
+const BYTE_RANK: [u8; 256] = {
+    let mut rank = [0u8; 256];
+
+    // Non-ASCII is mostly UTF-8 continuation bytes: not rare, not common.
+    let mut b = 0x80;
+    while b < 256 {
+        rank[b] = 20;
+        b += 1;
+    }
+
+    let mut b = b'!';
+    while b <= b'~' {
+        rank[b as usize] = 40;
+        b += 1;
+    }
+
+    let mut b = b'0';
+    while b <= b'9' {
+        rank[b as usize] = 80;
+        b += 1;
+    }
+
+    let by_frequency = b"etaoinsrhldcumfpgwybvkxjqz";
+    let mut i = 0;
+    while i < by_frequency.len() {
+        rank[by_frequency[i] as usize] = 250 - 5 * i as u8;
+        rank[by_frequency[i].to_ascii_uppercase() as usize] = 150 - 3 * i as u8;
+        i += 1;
+    }
+
+    rank[b' ' as usize] = 255;
+    rank[b'_' as usize] = 200;
+    rank[b'\n' as usize] = 180;
+    rank[b',' as usize] = 120;
+    rank[b'.' as usize] = 120;
+    rank[b'(' as usize] = 90;
+    rank[b')' as usize] = 90;
+
+    rank
+};
+
+/// A needle with its prefilter and fallback tables precomputed.
+///
+/// The prefilter looks for the two rarest bytes of the needle at their offsets
+/// (so `"Path of Exile 2"` keys on `'x'` and `'2'` rather than `'P'`), which
+/// throws away far more candidates than matching on the first byte alone.
+/// When verification still starts to dominate it hands over to Two-Way,
+/// so every search is linear in the haystack.
+#[derive(Debug, Clone)]
+pub struct Finder {
+    needle: Vec<u8>,
+    needle_rev: Vec<u8>,
+    rare1_idx: usize,
+    rare2_idx: usize,
+    rare1_vec: Simd<u8, LANES>,
+    rare2_vec: Simd<u8, LANES>,
+    two_way: TwoWay,
+    two_way_rev: TwoWay,
+}
+
+impl Finder {
+    pub fn new(needle: &[u8]) -> Self {
+        let (rare1_idx, rare2_idx) = rare_byte_offsets(needle);
+        let needle_rev: Vec<u8> = needle.iter().rev().copied().collect();
+
+        Self {
+            rare1_vec: Simd::splat(needle.get(rare1_idx).copied().unwrap_or_default()),
+            rare2_vec: Simd::splat(needle.get(rare2_idx).copied().unwrap_or_default()),
+            rare1_idx,
+            rare2_idx,
+            two_way: TwoWay::new(needle),
+            two_way_rev: TwoWay::new(&needle_rev),
+            needle: needle.to_vec(),
+            needle_rev,
+        }
+    }
+
+    pub fn needle(&self) -> &[u8] {
+        &self.needle
+    }
+
+    /// Position of the first occurrence of the needle in `haystack`.
+    pub fn find(&self, haystack: &[u8]) -> Option<usize> {
+        self.find_from(haystack, 0)
+    }
+
+    /// Position of the last occurrence of the needle in `haystack`.
+    pub fn rfind(&self, haystack: &[u8]) -> Option<usize> {
+        let n = self.needle.len();
+        if n == 0 {
+            return Some(haystack.len());
+        }
+        if n > haystack.len() {
+            return None;
+        }
+
+        let mut work = 0;
+        let mut verify = |pos: usize| -> Result<bool, usize> {
+            if work > VERIFY_BUDGET_FACTOR * (haystack.len() - pos) {
+                return Err(pos + n);
+            }
+            work += n;
+            Ok(simd_eq(&haystack[pos..pos + n], &self.needle))
+        };
+
+        // Walk candidate starts backwards from the last one that fits.
+        let mut end = haystack.len() - n + 1;
+        let handover = 'scan: {
+            while end >= LANES {
+                let i = end - LANES;
+                let mut candidates = self.candidates_at(haystack, i);
+
+                while candidates != 0 {
+                    let j = 63 - candidates.leading_zeros() as usize;
+                    match verify(i + j) {
+                        Ok(true) => return Some(i + j),
+                        Ok(false) => {}
+                        Err(end) => break 'scan end,
+                    }
+                    candidates &= !(1 << j);
+                }
+                end = i;
+            }
+
+            // Handle remaining bytes
+            for pos in (0..end).rev() {
+                if haystack[pos + self.rare1_idx] == self.needle[self.rare1_idx]
+                    && haystack[pos + self.rare2_idx] == self.needle[self.rare2_idx]
+                {
+                    match verify(pos) {
+                        Ok(true) => return Some(pos),
+                        Ok(false) => {}
+                        Err(end) => break 'scan end,
+                    }
+                }
+            }
+            return None;
+        };
+
+        self.two_way_rev
+            .rfind_to(haystack, &self.needle_rev, handover)
+    }
+
+    pub fn contains(&self, haystack: &[u8]) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Iterate over the start of every non-overlapping match, left to right.
+    pub fn find_iter<'f, 'h>(&'f self, haystack: &'h [u8]) -> FindIter<'f, 'h> {
+        FindIter {
+            finder: self,
+            haystack,
+            pos: 0,
+        }
+    }
+
+    fn find_from(&self, haystack: &[u8], start: usize) -> Option<usize> {
+        let n = self.needle.len();
+        if n == 0 {
+            return (start <= haystack.len()).then_some(start);
+        }
+        if start + n > haystack.len() {
+            return None;
+        }
+
+        let last_start = haystack.len() - n;
+        let max_idx = self.rare1_idx.max(self.rare2_idx);
+
+        let mut work = 0;
+        let mut verify = |pos: usize| -> Result<bool, usize> {
+            if work > VERIFY_BUDGET_FACTOR * (pos - start + n) {
+                return Err(pos);
+            }
+            work += n;
+            Ok(simd_eq(&haystack[pos..pos + n], &self.needle))
+        };
+
+        let mut i = start;
+        let handover = 'scan: {
+            while i + max_idx + LANES <= haystack.len() {
+                let mut candidates = self.candidates_at(haystack, i);
+
+                while candidates != 0 {
+                    let pos = i + candidates.trailing_zeros() as usize;
+                    if pos > last_start {
+                        return None;
+                    }
+                    match verify(pos) {
+                        Ok(true) => return Some(pos),
+                        Ok(false) => {}
+                        Err(pos) => break 'scan pos,
+                    }
+                    candidates &= candidates - 1;
+                }
+                i += LANES;
+            }
+
+            // Handle remaining bytes
+            for pos in i..=last_start {
+                if haystack[pos + self.rare1_idx] == self.needle[self.rare1_idx]
+                    && haystack[pos + self.rare2_idx] == self.needle[self.rare2_idx]
+                {
+                    match verify(pos) {
+                        Ok(true) => return Some(pos),
+                        Ok(false) => {}
+                        Err(pos) => break 'scan pos,
+                    }
+                }
+            }
+            return None;
+        };
+
+        self.two_way.find_from(haystack, &self.needle, handover)
+    }
+
+    /// Bitmask of the candidate starts in `i..i + LANES`, i.e. where both rare bytes line up.
+    #[inline(always)]
+    fn candidates_at(&self, haystack: &[u8], i: usize) -> u64 {
+        let rare1 = Simd::<u8, LANES>::from_slice(&haystack[i + self.rare1_idx..]);
+        let rare2 = Simd::<u8, LANES>::from_slice(&haystack[i + self.rare2_idx..]);
+        (rare1.simd_eq(self.rare1_vec) & rare2.simd_eq(self.rare2_vec)).to_bitmask()
+    }
+}
+
+/// Iterator over non-overlapping matches, see [`Finder::find_iter`].
+#[derive(Debug, Clone)]
+pub struct FindIter<'f, 'h> {
+    finder: &'f Finder,
+    haystack: &'h [u8],
+    pos: usize,
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let found = self.finder.find_from(self.haystack, self.pos)?;
+        // An empty needle matches everywhere, so step by at least one.
+        self.pos = found + self.finder.needle.len().max(1);
+        Some(found)
+    }
+}
+
+/// Offsets of the two rarest bytes in `needle`, preferring two different byte values.
+fn rare_byte_offsets(needle: &[u8]) -> (usize, usize) {
+    let rank = |&(_, &b): &(usize, &u8)| BYTE_RANK[b as usize];
+
+    let Some((rare1_idx, &rare1)) = needle.iter().enumerate().min_by_key(rank) else {
+        return (0, 0);
+    };
+    let rare2_idx = needle
+        .iter()
+        .enumerate()
+        .filter(|&(_, &b)| b != rare1)
+        .min_by_key(rank)
+        .or_else(|| {
+            needle
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != rare1_idx)
+                .min_by_key(rank)
+        })
+        .map_or(rare1_idx, |(i, _)| i);
+
+    (rare1_idx, rare2_idx)
+}
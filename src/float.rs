@@ -0,0 +1,668 @@
+//! Float parsing and shortest round-trip formatting.
+//!
+//! Parsing finds the digit runs with SIMD compares and accumulates them with the
+//! multiply-add ladder from [`simd_parse_u64`](crate::simd_parse_u64), then converts
+//! the decimal mantissa and exponent to binary with Clinger's fast path when that's
+//! exact, and Eisel-Lemire otherwise. The few inputs neither can decide (more than 19
+//! significant digits, or a product too close to halfway) go to `str::parse`.
+//!
+//! Formatting finds the shortest digits that round-trip with Ryu and writes them out
+//! with the same ladder run backwards.
+
+mod tables;
+
+use std::{
+    fmt,
+    simd::{Simd, cmp::SimdPartialOrd},
+};
+
+use crate::ints::{accumulate_around_point, accumulate_digits, format_16};
+use tables::{POW5_128, POW5_128_MIN_EXPONENT, RYU_POW5, RYU_POW5_INV};
+
+/// Most significant digits that always fit in a `u64` mantissa.
+const MAX_DIGITS: usize = 19;
+
+/// Digits the integer ladder takes at once, and bytes `digit_run` checks at once.
+const DIGITS_PER_STEP: usize = 16;
+
+/// Bits of precision in the Ryu tables.
+const RYU_POW5_BITS: i32 = 125;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatParseError {
+    /// No number at all (possibly just a sign).
+    Empty,
+    /// The input ended in the middle of the number, e.g. `1e`.
+    UnexpectedEnd,
+    /// A byte that doesn't fit the float grammar, and its offset in the input.
+    InvalidChar { offset: usize, byte: u8 },
+}
+
+impl fmt::Display for FloatParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse float from empty string"),
+            Self::UnexpectedEnd => write!(f, "float literal ends unexpectedly"),
+            Self::InvalidChar { offset, byte } => {
+                write!(
+                    f,
+                    "invalid character {:?} at offset {offset}",
+                    *byte as char
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FloatParseError {}
+
+/// Parse a decimal `f32`, accepting the same syntax as `str::parse` (`1`, `-1.5`,
+/// `.5`, `2.`, `1e-7`, `inf`, `NaN`, ...).
+pub fn simd_parse_f32(s: &[u8]) -> Result<f32, FloatParseError> {
+    parse(s)
+}
+
+/// Parse a decimal `f64`, see [`simd_parse_f32`].
+pub fn simd_parse_f64(s: &[u8]) -> Result<f64, FloatParseError> {
+    parse(s)
+}
+
+/// The shortest decimal that parses back to exactly `v`.
+///
+/// Like `Display`, but switches to `LowerExp` (`1.5e-7`) outside `1e-5..1e17` rather
+/// than writing out every zero. When two candidates are equally short and equally
+/// close (`1317803.25f32` is `1317803.2` or `1317803.3`) this picks the even digit,
+/// where `Display` rounds up.
+pub fn simd_format_f32(v: f32) -> String {
+    format(v)
+}
+
+/// The shortest decimal that parses back to exactly `v`, see [`simd_format_f32`].
+pub fn simd_format_f64(v: f64) -> String {
+    format(v)
+}
+
+/// What the parser and formatter need to know about `f32` and `f64`.
+trait Float: Copy + std::str::FromStr + 'static {
+    const MANTISSA_BITS: u32;
+    const EXPONENT_BIAS: i32;
+    /// Biased exponent of infinity and NaN.
+    const INFINITE_POWER: i32;
+    /// Decimal exponents beyond these are always zero or infinity.
+    const SMALLEST_POWER_OF_TEN: i64;
+    const LARGEST_POWER_OF_TEN: i64;
+    /// The range where Eisel-Lemire's product can be exactly halfway between two floats.
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64;
+    /// Powers of ten that are exact in this type, for Clinger's fast path.
+    const EXACT_POWERS_OF_TEN: &[Self];
+    const INFINITY: Self;
+    const NAN: Self;
+
+    fn from_bits(bits: u64) -> Self;
+    fn to_bits(self) -> u64;
+    /// Exact for mantissas up to `2 << MANTISSA_BITS`.
+    fn from_mantissa(mantissa: u64) -> Self;
+    fn mul(self, rhs: Self) -> Self;
+    fn div(self, rhs: Self) -> Self;
+}
+
+impl Float for f32 {
+    const MANTISSA_BITS: u32 = 23;
+    const EXPONENT_BIAS: i32 = 127;
+    const INFINITE_POWER: i32 = 0xff;
+    const SMALLEST_POWER_OF_TEN: i64 = -65;
+    const LARGEST_POWER_OF_TEN: i64 = 38;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = -17;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = 10;
+    const EXACT_POWERS_OF_TEN: &[Self] = &[1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+    const INFINITY: Self = f32::INFINITY;
+    const NAN: Self = f32::NAN;
+
+    fn from_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+
+    fn to_bits(self) -> u64 {
+        f32::to_bits(self) as u64
+    }
+
+    fn from_mantissa(mantissa: u64) -> Self {
+        mantissa as f32
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+}
+
+impl Float for f64 {
+    const MANTISSA_BITS: u32 = 52;
+    const EXPONENT_BIAS: i32 = 1023;
+    const INFINITE_POWER: i32 = 0x7ff;
+    const SMALLEST_POWER_OF_TEN: i64 = -342;
+    const LARGEST_POWER_OF_TEN: i64 = 308;
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = -4;
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = 23;
+    const EXACT_POWERS_OF_TEN: &[Self] = &[
+        1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+        1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+    ];
+    const INFINITY: Self = f64::INFINITY;
+    const NAN: Self = f64::NAN;
+
+    fn from_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+
+    fn to_bits(self) -> u64 {
+        f64::to_bits(self)
+    }
+
+    fn from_mantissa(mantissa: u64) -> Self {
+        mantissa as f64
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        self * rhs
+    }
+
+    fn div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+}
+
+fn parse<F: Float>(s: &[u8]) -> Result<F, FloatParseError> {
+    let (negative, rest, start) = match s {
+        [b'-', rest @ ..] => (true, rest, 1),
+        [b'+', rest @ ..] => (false, rest, 1),
+        rest => (false, rest, 0),
+    };
+    if rest.is_empty() {
+        return Err(FloatParseError::Empty);
+    }
+
+    let with_sign = |v: F| {
+        if negative {
+            F::from_bits(v.to_bits() | sign_bit::<F>())
+        } else {
+            v
+        }
+    };
+
+    if !matches!(rest[0], b'0'..=b'9' | b'.') {
+        if rest.eq_ignore_ascii_case(b"inf") || rest.eq_ignore_ascii_case(b"infinity") {
+            return Ok(with_sign(F::INFINITY));
+        }
+        if rest.eq_ignore_ascii_case(b"nan") {
+            return Ok(with_sign(F::NAN));
+        }
+    }
+
+    let int_digits = &rest[..digit_run(rest, 0)];
+    let mut pos = int_digits.len();
+
+    let mut frac_digits: &[u8] = &[];
+    if rest.get(pos) == Some(&b'.') {
+        pos += 1;
+        frac_digits = &rest[pos..pos + digit_run(rest, pos)];
+        pos += frac_digits.len();
+    }
+
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return Err(FloatParseError::InvalidChar {
+            offset: start,
+            byte: rest[0],
+        });
+    }
+
+    let mut exponent = 0i64;
+    if let Some(b'e' | b'E') = rest.get(pos) {
+        pos += 1;
+        let exponent_negative = match rest.get(pos) {
+            Some(b'-') => {
+                pos += 1;
+                true
+            }
+            Some(b'+') => {
+                pos += 1;
+                false
+            }
+            _ => false,
+        };
+
+        let exponent_digits = &rest[pos..pos + digit_run(rest, pos)];
+        if exponent_digits.is_empty() {
+            return Err(match rest.get(pos) {
+                Some(&byte) => FloatParseError::InvalidChar {
+                    offset: start + pos,
+                    byte,
+                },
+                None => FloatParseError::UnexpectedEnd,
+            });
+        }
+        pos += exponent_digits.len();
+
+        // Anything this big is zero or infinity already, so stop before it overflows.
+        for &d in exponent_digits {
+            if exponent < 0x10000 {
+                exponent = exponent * 10 + (d - b'0') as i64;
+            }
+        }
+        if exponent_negative {
+            exponent = -exponent;
+        }
+    }
+
+    if let Some(&byte) = rest.get(pos) {
+        return Err(FloatParseError::InvalidChar {
+            offset: start + pos,
+            byte,
+        });
+    }
+
+    let value = if int_digits.len() + frac_digits.len() < DIGITS_PER_STEP {
+        // Short enough to take the digits and the point in one go, the usual case.
+        let mantissa = if rest.get(int_digits.len()) == Some(&b'.') {
+            let span = &rest[..int_digits.len() + 1 + frac_digits.len()];
+            accumulate_around_point(span, int_digits.len())
+        } else {
+            accumulate_digits(0, int_digits)
+        };
+        from_decimal::<F>(mantissa, exponent - frac_digits.len() as i64)
+    } else {
+        // Leading zeros aren't significant, and nor are trailing zeros after the point.
+        let frac_digits = trim_trailing_zeros(frac_digits);
+        let exponent = exponent - frac_digits.len() as i64;
+        let (int_digits, frac_digits) = match trim_leading_zeros(int_digits) {
+            [] => (&[][..], trim_leading_zeros(frac_digits)),
+            int_digits => (int_digits, frac_digits),
+        };
+
+        if int_digits.len() + frac_digits.len() <= MAX_DIGITS {
+            let mantissa = accumulate_digits(accumulate_digits(0, int_digits), frac_digits);
+            from_decimal::<F>(mantissa, exponent)
+        } else {
+            None
+        }
+    };
+
+    // The grammar's been checked, so the slow path always parses.
+    let value = value.unwrap_or_else(|| {
+        std::str::from_utf8(rest)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(F::NAN)
+    });
+
+    Ok(with_sign(value))
+}
+
+/// Length of the run of ASCII digits starting at `bytes[from]`.
+///
+/// This takes the whole input rather than a subslice so the last, partial window can
+/// be loaded from further back instead of a byte at a time: numbers are short, so
+/// that's usually the only window.
+fn digit_run(bytes: &[u8], from: usize) -> usize {
+    let mut pos = from;
+    while pos < bytes.len() {
+        let (start, width, non_digits) = if bytes.len() >= DIGITS_PER_STEP {
+            let start = pos.min(bytes.len() - DIGITS_PER_STEP);
+            (
+                start,
+                DIGITS_PER_STEP,
+                non_digits::<DIGITS_PER_STEP>(&bytes[start..]),
+            )
+        } else {
+            let run = bytes[pos..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+            return pos + run - from;
+        };
+
+        // Drop the bits for the bytes before `pos` that the window overlaps.
+        let non_digits = non_digits >> (pos - start);
+        if non_digits != 0 {
+            return pos + non_digits.trailing_zeros() as usize - from;
+        }
+        pos = start + width;
+    }
+    bytes.len() - from
+}
+
+/// Bitmask of the bytes in the first `N` of `window` that aren't ASCII digits.
+#[inline(always)]
+fn non_digits<const N: usize>(window: &[u8]) -> u64 {
+    let digits = Simd::<u8, N>::from_slice(&window[..N]) - Simd::splat(b'0');
+    digits.simd_ge(Simd::splat(10)).to_bitmask()
+}
+
+fn trim_leading_zeros(digits: &[u8]) -> &[u8] {
+    let zeros = digits.iter().take_while(|&&d| d == b'0').count();
+    &digits[zeros..]
+}
+
+fn trim_trailing_zeros(digits: &[u8]) -> &[u8] {
+    let zeros = digits.iter().rev().take_while(|&&d| d == b'0').count();
+    &digits[..digits.len() - zeros]
+}
+
+fn sign_bit<F: Float>() -> u64 {
+    1 << (F::MANTISSA_BITS + (F::INFINITE_POWER as u32).count_ones())
+}
+
+/// `mantissa * 10^exponent`, or `None` if it needs the slow path.
+fn from_decimal<F: Float>(mantissa: u64, exponent: i64) -> Option<F> {
+    // Clinger: both operands are exact, so the one multiply or divide rounds correctly.
+    let max_exact = F::EXACT_POWERS_OF_TEN.len() as i64 - 1;
+    if (-max_exact..=max_exact).contains(&exponent) && mantissa <= 2 << F::MANTISSA_BITS {
+        let value = F::from_mantissa(mantissa);
+        let power = F::EXACT_POWERS_OF_TEN[exponent.unsigned_abs() as usize];
+        return Some(if exponent < 0 {
+            value.div(power)
+        } else {
+            value.mul(power)
+        });
+    }
+
+    let (mantissa, power2) = eisel_lemire::<F>(exponent, mantissa)?;
+    Some(F::from_bits(
+        mantissa | ((power2 as u64) << F::MANTISSA_BITS),
+    ))
+}
+
+/// Eisel-Lemire: the float nearest `w * 10^q`, as an explicit mantissa and biased
+/// exponent, or `None` when the truncated 128-bit power of five can't decide the
+/// rounding.
+///
+/// This follows fast_float's `compute_float`.
+fn eisel_lemire<F: Float>(q: i64, mut w: u64) -> Option<(u64, i32)> {
+    if w == 0 || q < F::SMALLEST_POWER_OF_TEN {
+        return Some((0, 0));
+    }
+    if q > F::LARGEST_POWER_OF_TEN {
+        return Some((0, F::INFINITE_POWER));
+    }
+
+    let leading_zeros = w.leading_zeros();
+    w <<= leading_zeros;
+
+    // The mantissa, the hidden bit, a rounding bit, and one for a leading zero in the product.
+    let precision = F::MANTISSA_BITS + 3;
+    let (lo, hi) = product_approx(q, w, precision);
+    if lo == u64::MAX && !(-27..=55).contains(&q) {
+        // Outside that range the power of five isn't exact, and the truncation could
+        // have hidden a carry.
+        return None;
+    }
+
+    let upper_bit = (hi >> 63) as i32;
+    let shift = upper_bit + 64 - precision as i32;
+    let mut mantissa = hi >> shift;
+    let mut power2 = power(q as i32) + upper_bit - leading_zeros as i32 + F::EXPONENT_BIAS;
+
+    if power2 <= 0 {
+        // Subnormal.
+        if -power2 + 1 >= 64 {
+            return Some((0, 0));
+        }
+        mantissa >>= -power2 + 1;
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        // Rounding up can make it the smallest normal.
+        power2 = (mantissa >= 1 << F::MANTISSA_BITS) as i32;
+        return Some((mantissa & !(1 << F::MANTISSA_BITS), power2));
+    }
+
+    // Exactly halfway: round to even rather than up.
+    if lo <= 1
+        && (F::MIN_EXPONENT_ROUND_TO_EVEN..=F::MAX_EXPONENT_ROUND_TO_EVEN).contains(&q)
+        && mantissa & 3 == 1
+        && (mantissa << shift) == hi
+    {
+        mantissa &= !1;
+    }
+
+    mantissa += mantissa & 1;
+    mantissa >>= 1;
+    if mantissa >= 2 << F::MANTISSA_BITS {
+        mantissa = 1 << F::MANTISSA_BITS;
+        power2 += 1;
+    }
+    mantissa &= !(1 << F::MANTISSA_BITS);
+
+    if power2 >= F::INFINITE_POWER {
+        return Some((0, F::INFINITE_POWER));
+    }
+    Some((mantissa, power2))
+}
+
+/// floor(log2(10^q)) + 63, for q in the table's range.
+fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+/// The high 128 bits of `w * 5^q`, only as precise as `precision` bits need.
+fn product_approx(q: i64, w: u64, precision: u32) -> (u64, u64) {
+    let mask = u64::MAX >> precision;
+    let power5 = POW5_128[(q - POW5_128_MIN_EXPONENT) as usize];
+    let (hi5, lo5) = ((power5 >> 64) as u64, power5 as u64);
+
+    let first = w as u128 * hi5 as u128;
+    let (mut first_lo, mut first_hi) = (first as u64, (first >> 64) as u64);
+    if first_hi & mask == mask {
+        // The bits that matter might still change, so bring in the low half of the power.
+        let second_hi = ((w as u128 * lo5 as u128) >> 64) as u64;
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+    (first_lo, first_hi)
+}
+
+fn format<F: Float>(v: F) -> String {
+    let bits = v.to_bits();
+    let negative = bits & sign_bit::<F>() != 0;
+    let ieee_mantissa = bits & ((1 << F::MANTISSA_BITS) - 1);
+    let ieee_exponent = ((bits >> F::MANTISSA_BITS) as i32) & F::INFINITE_POWER;
+
+    let sign = if negative { "-" } else { "" };
+    if ieee_exponent == F::INFINITE_POWER {
+        return if ieee_mantissa != 0 {
+            "NaN".to_string()
+        } else {
+            format!("{sign}inf")
+        };
+    }
+    if ieee_exponent == 0 && ieee_mantissa == 0 {
+        return format!("{sign}0");
+    }
+
+    let (digits, exponent) = ryu::<F>(ieee_mantissa, ieee_exponent);
+
+    let len = digits.ilog10() as usize + 1;
+    let mut buf = [b'0'; 17];
+    buf[1..].copy_from_slice(&format_16(digits % 10_000_000_000_000_000));
+    buf[0] += (digits / 10_000_000_000_000_000) as u8;
+    let digits = &buf[buf.len() - len..];
+
+    // ASCII digits are always UTF-8.
+    let digits = std::str::from_utf8(digits).unwrap_or_default();
+    let point = len as i32 + exponent;
+
+    let mut out = String::with_capacity(24);
+    out.push_str(sign);
+    if (-4..=17).contains(&point) {
+        if point <= 0 {
+            out.push_str("0.");
+            out.extend(std::iter::repeat_n('0', -point as usize));
+            out.push_str(digits);
+        } else if point as usize >= len {
+            out.push_str(digits);
+            out.extend(std::iter::repeat_n('0', point as usize - len));
+        } else {
+            out.push_str(&digits[..point as usize]);
+            out.push('.');
+            out.push_str(&digits[point as usize..]);
+        }
+    } else {
+        out.push_str(&digits[..1]);
+        if len > 1 {
+            out.push('.');
+            out.push_str(&digits[1..]);
+        }
+        out.push('e');
+        out.push_str(&(point - 1).to_string());
+    }
+    out
+}
+
+/// Ryu: the shortest `digits * 10^exponent` inside the rounding interval of the
+/// float, picking the closest when there's a choice.
+///
+/// This is `d2s` from the reference implementation, with the mantissa width as a
+/// parameter so `f32` goes through the same tables.
+fn ryu<F: Float>(ieee_mantissa: u64, ieee_exponent: i32) -> (u64, i32) {
+    let (m2, e2) = if ieee_exponent == 0 {
+        (
+            ieee_mantissa,
+            1 - F::EXPONENT_BIAS - F::MANTISSA_BITS as i32 - 2,
+        )
+    } else {
+        (
+            (1 << F::MANTISSA_BITS) | ieee_mantissa,
+            ieee_exponent - F::EXPONENT_BIAS - F::MANTISSA_BITS as i32 - 2,
+        )
+    };
+    let accept_bounds = m2 & 1 == 0;
+
+    // The interval is [mm, mp] around mv, in units of a quarter of the last bit. The
+    // lower gap is half as big just above a power of two.
+    let mv = 4 * m2;
+    let mm_shift = (ieee_mantissa != 0 || ieee_exponent <= 1) as u64;
+    let (mp, mm) = (mv + 2, mv - 1 - mm_shift);
+
+    let mut vm_is_trailing_zeros = false;
+    let mut vr_is_trailing_zeros = false;
+
+    let (e10, mut vr, mut vp, mut vm) = if e2 >= 0 {
+        let q = log10_pow2(e2) - (e2 > 3) as i32;
+        let k = RYU_POW5_BITS + pow5_bits(q) - 1;
+        let i = -e2 + q + k;
+        let mul = RYU_POW5_INV[q as usize];
+        let (vr, mut vp, vm) = (
+            mul_shift(mv, mul, i),
+            mul_shift(mp, mul, i),
+            mul_shift(mm, mul, i),
+        );
+
+        if q <= 21 {
+            // Only one of mp, mv and mm can be a multiple of 5, if any.
+            if mv % 5 == 0 {
+                vr_is_trailing_zeros = multiple_of_power_of_5(mv, q);
+            } else if accept_bounds {
+                vm_is_trailing_zeros = multiple_of_power_of_5(mm, q);
+            } else {
+                vp -= multiple_of_power_of_5(mp, q) as u64;
+            }
+        }
+        (q, vr, vp, vm)
+    } else {
+        let q = log10_pow5(-e2) - (-e2 > 1) as i32;
+        let i = -e2 - q;
+        let k = pow5_bits(i) - RYU_POW5_BITS;
+        let j = q - k;
+        let mul = RYU_POW5[i as usize];
+        let (vr, mut vp, vm) = (
+            mul_shift(mv, mul, j),
+            mul_shift(mp, mul, j),
+            mul_shift(mm, mul, j),
+        );
+
+        if q <= 1 {
+            // mv has at least q trailing zero bits, so vr is exact.
+            vr_is_trailing_zeros = true;
+            if accept_bounds {
+                vm_is_trailing_zeros = mm_shift == 1;
+            } else {
+                vp -= 1;
+            }
+        } else if q < 63 {
+            vr_is_trailing_zeros = mv & ((1 << q) - 1) == 0;
+        }
+        (q + e2, vr, vp, vm)
+    };
+
+    // Drop digits while the interval still has a number with fewer of them.
+    let mut removed = 0;
+    let mut last_removed_digit = 0;
+    let output = if vm_is_trailing_zeros || vr_is_trailing_zeros {
+        while vp / 10 > vm / 10 {
+            vm_is_trailing_zeros &= vm % 10 == 0;
+            vr_is_trailing_zeros &= last_removed_digit == 0;
+            last_removed_digit = vr % 10;
+            (vr, vp, vm) = (vr / 10, vp / 10, vm / 10);
+            removed += 1;
+        }
+        if vm_is_trailing_zeros {
+            while vm % 10 == 0 {
+                vr_is_trailing_zeros &= last_removed_digit == 0;
+                last_removed_digit = vr % 10;
+                (vr, vp, vm) = (vr / 10, vp / 10, vm / 10);
+                removed += 1;
+            }
+        }
+        if vr_is_trailing_zeros && last_removed_digit == 5 && vr % 2 == 0 {
+            // Exactly halfway, round to even.
+            last_removed_digit = 4;
+        }
+        vr + ((vr == vm && (!accept_bounds || !vm_is_trailing_zeros)) || last_removed_digit >= 5)
+            as u64
+    } else {
+        while vp / 10 > vm / 10 {
+            last_removed_digit = vr % 10;
+            (vr, vp, vm) = (vr / 10, vp / 10, vm / 10);
+            removed += 1;
+        }
+        vr + (vr == vm || last_removed_digit >= 5) as u64
+    };
+
+    (output, e10 + removed)
+}
+
+/// `(m * mul) >> j`, for `mul` below 2^125 and `j` at least 64.
+fn mul_shift(m: u64, mul: u128, j: i32) -> u64 {
+    let low = (m as u128 * (mul as u64) as u128) >> 64;
+    let high = m as u128 * (mul >> 64);
+    ((high + low) >> (j - 64)) as u64
+}
+
+/// Bits in 5^e (1 for e == 0).
+fn pow5_bits(e: i32) -> i32 {
+    ((e as u32 * 1_217_359) >> 19) as i32 + 1
+}
+
+/// floor(log10(2^e)).
+fn log10_pow2(e: i32) -> i32 {
+    ((e as u32 * 78_913) >> 18) as i32
+}
+
+/// floor(log10(5^e)).
+fn log10_pow5(e: i32) -> i32 {
+    ((e as u32 * 732_923) >> 20) as i32
+}
+
+fn multiple_of_power_of_5(mut value: u64, p: i32) -> bool {
+    let mut count = 0;
+    while value.is_multiple_of(5) {
+        value /= 5;
+        count += 1;
+    }
+    count >= p
+}
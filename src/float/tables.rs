@@ -0,0 +1,1352 @@
+//! Generated 128-bit power of five tables, don't edit by hand.
+//!
+//! `POW5_128` is the table from Lemire's fast_float (`script/table_generation.py`),
+//! `RYU_POW5` and `RYU_POW5_INV` are Ryu's `DOUBLE_POW5_SPLIT` and
+//! `DOUBLE_POW5_INV_SPLIT` with 125 bits of precision:
+//!
+//! ```python
+//! for q in range(-342, 0):   # POW5_128, negative powers
+//!     p = 5 ** -q
+//!     z = p.bit_length()
+//!     c = 2 ** (z + 127) // p + 1 if q >= -27 else 2 ** (2 * z + 128) // p + 1
+//!     while c >= 1 << 128: c //= 2
+//! for q in range(0, 309):    # POW5_128, non-negative powers
+//!     p = 5 ** q             # normalised so bit 127 is the top bit, truncated
+//! for i in range(326):       # RYU_POW5
+//!     p = 5 ** i; s = p.bit_length() - 125
+//!     p >> s if s >= 0 else p << -s
+//! for i in range(342):       # RYU_POW5_INV
+//!     p = 5 ** i
+//!     (1 << (p.bit_length() - 1 + 125)) // p + 1
+//! ```
+
+/// Smallest power of ten in [`POW5_128`], i.e. its first entry is 5^-342.
+pub(super) const POW5_128_MIN_EXPONENT: i64 = -342;
+
+pub(super) static POW5_128: [u128; 651] = [
+    0xeef453d6923bd65a_113faa2906a13b3f, // 5^-342
+    0x9558b4661b6565f8_4ac7ca59a424c507, // 5^-341
+    0xbaaee17fa23ebf76_5d79bcf00d2df649, // 5^-340
+    0xe95a99df8ace6f53_f4d82c2c107973dc, // 5^-339
+    0x91d8a02bb6c10594_79071b9b8a4be869, // 5^-338
+    0xb64ec836a47146f9_9748e2826cdee284, // 5^-337
+    0xe3e27a444d8d98b7_fd1b1b2308169b25, // 5^-336
+    0x8e6d8c6ab0787f72_fe30f0f5e50e20f7, // 5^-335
+    0xb208ef855c969f4f_bdbd2d335e51a935, // 5^-334
+    0xde8b2b66b3bc4723_ad2c788035e61382, // 5^-333
+    0x8b16fb203055ac76_4c3bcb5021afcc31, // 5^-332
+    0xaddcb9e83c6b1793_df4abe242a1bbf3d, // 5^-331
+    0xd953e8624b85dd78_d71d6dad34a2af0d, // 5^-330
+    0x87d4713d6f33aa6b_8672648c40e5ad68, // 5^-329
+    0xa9c98d8ccb009506_680efdaf511f18c2, // 5^-328
+    0xd43bf0effdc0ba48_0212bd1b2566def2, // 5^-327
+    0x84a57695fe98746d_014bb630f7604b57, // 5^-326
+    0xa5ced43b7e3e9188_419ea3bd35385e2d, // 5^-325
+    0xcf42894a5dce35ea_52064cac828675b9, // 5^-324
+    0x818995ce7aa0e1b2_7343efebd1940993, // 5^-323
+    0xa1ebfb4219491a1f_1014ebe6c5f90bf8, // 5^-322
+    0xca66fa129f9b60a6_d41a26e077774ef6, // 5^-321
+    0xfd00b897478238d0_8920b098955522b4, // 5^-320
+    0x9e20735e8cb16382_55b46e5f5d5535b0, // 5^-319
+    0xc5a890362fddbc62_eb2189f734aa831d, // 5^-318
+    0xf712b443bbd52b7b_a5e9ec7501d523e4, // 5^-317
+    0x9a6bb0aa55653b2d_47b233c92125366e, // 5^-316
+    0xc1069cd4eabe89f8_999ec0bb696e840a, // 5^-315
+    0xf148440a256e2c76_c00670ea43ca250d, // 5^-314
+    0x96cd2a865764dbca_380406926a5e5728, // 5^-313
+    0xbc807527ed3e12bc_c605083704f5ecf2, // 5^-312
+    0xeba09271e88d976b_f7864a44c633682e, // 5^-311
+    0x93445b8731587ea3_7ab3ee6afbe0211d, // 5^-310
+    0xb8157268fdae9e4c_5960ea05bad82964, // 5^-309
+    0xe61acf033d1a45df_6fb92487298e33bd, // 5^-308
+    0x8fd0c16206306bab_a5d3b6d479f8e056, // 5^-307
+    0xb3c4f1ba87bc8696_8f48a4899877186c, // 5^-306
+    0xe0b62e2929aba83c_331acdabfe94de87, // 5^-305
+    0x8c71dcd9ba0b4925_9ff0c08b7f1d0b14, // 5^-304
+    0xaf8e5410288e1b6f_07ecf0ae5ee44dd9, // 5^-303
+    0xdb71e91432b1a24a_c9e82cd9f69d6150, // 5^-302
+    0x892731ac9faf056e_be311c083a225cd2, // 5^-301
+    0xab70fe17c79ac6ca_6dbd630a48aaf406, // 5^-300
+    0xd64d3d9db981787d_092cbbccdad5b108, // 5^-299
+    0x85f0468293f0eb4e_25bbf56008c58ea5, // 5^-298
+    0xa76c582338ed2621_af2af2b80af6f24e, // 5^-297
+    0xd1476e2c07286faa_1af5af660db4aee1, // 5^-296
+    0x82cca4db847945ca_50d98d9fc890ed4d, // 5^-295
+    0xa37fce126597973c_e50ff107bab528a0, // 5^-294
+    0xcc5fc196fefd7d0c_1e53ed49a96272c8, // 5^-293
+    0xff77b1fcbebcdc4f_25e8e89c13bb0f7a, // 5^-292
+    0x9faacf3df73609b1_77b191618c54e9ac, // 5^-291
+    0xc795830d75038c1d_d59df5b9ef6a2417, // 5^-290
+    0xf97ae3d0d2446f25_4b0573286b44ad1d, // 5^-289
+    0x9becce62836ac577_4ee367f9430aec32, // 5^-288
+    0xc2e801fb244576d5_229c41f793cda73f, // 5^-287
+    0xf3a20279ed56d48a_6b43527578c1110f, // 5^-286
+    0x9845418c345644d6_830a13896b78aaa9, // 5^-285
+    0xbe5691ef416bd60c_23cc986bc656d553, // 5^-284
+    0xedec366b11c6cb8f_2cbfbe86b7ec8aa8, // 5^-283
+    0x94b3a202eb1c3f39_7bf7d71432f3d6a9, // 5^-282
+    0xb9e08a83a5e34f07_daf5ccd93fb0cc53, // 5^-281
+    0xe858ad248f5c22c9_d1b3400f8f9cff68, // 5^-280
+    0x91376c36d99995be_23100809b9c21fa1, // 5^-279
+    0xb58547448ffffb2d_abd40a0c2832a78a, // 5^-278
+    0xe2e69915b3fff9f9_16c90c8f323f516c, // 5^-277
+    0x8dd01fad907ffc3b_ae3da7d97f6792e3, // 5^-276
+    0xb1442798f49ffb4a_99cd11cfdf41779c, // 5^-275
+    0xdd95317f31c7fa1d_40405643d711d583, // 5^-274
+    0x8a7d3eef7f1cfc52_482835ea666b2572, // 5^-273
+    0xad1c8eab5ee43b66_da3243650005eecf, // 5^-272
+    0xd863b256369d4a40_90bed43e40076a82, // 5^-271
+    0x873e4f75e2224e68_5a7744a6e804a291, // 5^-270
+    0xa90de3535aaae202_711515d0a205cb36, // 5^-269
+    0xd3515c2831559a83_0d5a5b44ca873e03, // 5^-268
+    0x8412d9991ed58091_e858790afe9486c2, // 5^-267
+    0xa5178fff668ae0b6_626e974dbe39a872, // 5^-266
+    0xce5d73ff402d98e3_fb0a3d212dc8128f, // 5^-265
+    0x80fa687f881c7f8e_7ce66634bc9d0b99, // 5^-264
+    0xa139029f6a239f72_1c1fffc1ebc44e80, // 5^-263
+    0xc987434744ac874e_a327ffb266b56220, // 5^-262
+    0xfbe9141915d7a922_4bf1ff9f0062baa8, // 5^-261
+    0x9d71ac8fada6c9b5_6f773fc3603db4a9, // 5^-260
+    0xc4ce17b399107c22_cb550fb4384d21d3, // 5^-259
+    0xf6019da07f549b2b_7e2a53a146606a48, // 5^-258
+    0x99c102844f94e0fb_2eda7444cbfc426d, // 5^-257
+    0xc0314325637a1939_fa911155fefb5308, // 5^-256
+    0xf03d93eebc589f88_793555ab7eba27ca, // 5^-255
+    0x96267c7535b763b5_4bc1558b2f3458de, // 5^-254
+    0xbbb01b9283253ca2_9eb1aaedfb016f16, // 5^-253
+    0xea9c227723ee8bcb_465e15a979c1cadc, // 5^-252
+    0x92a1958a7675175f_0bfacd89ec191ec9, // 5^-251
+    0xb749faed14125d36_cef980ec671f667b, // 5^-250
+    0xe51c79a85916f484_82b7e12780e7401a, // 5^-249
+    0x8f31cc0937ae58d2_d1b2ecb8b0908810, // 5^-248
+    0xb2fe3f0b8599ef07_861fa7e6dcb4aa15, // 5^-247
+    0xdfbdcece67006ac9_67a791e093e1d49a, // 5^-246
+    0x8bd6a141006042bd_e0c8bb2c5c6d24e0, // 5^-245
+    0xaecc49914078536d_58fae9f773886e18, // 5^-244
+    0xda7f5bf590966848_af39a475506a899e, // 5^-243
+    0x888f99797a5e012d_6d8406c952429603, // 5^-242
+    0xaab37fd7d8f58178_c8e5087ba6d33b83, // 5^-241
+    0xd5605fcdcf32e1d6_fb1e4a9a90880a64, // 5^-240
+    0x855c3be0a17fcd26_5cf2eea09a55067f, // 5^-239
+    0xa6b34ad8c9dfc06f_f42faa48c0ea481e, // 5^-238
+    0xd0601d8efc57b08b_f13b94daf124da26, // 5^-237
+    0x823c12795db6ce57_76c53d08d6b70858, // 5^-236
+    0xa2cb1717b52481ed_54768c4b0c64ca6e, // 5^-235
+    0xcb7ddcdda26da268_a9942f5dcf7dfd09, // 5^-234
+    0xfe5d54150b090b02_d3f93b35435d7c4c, // 5^-233
+    0x9efa548d26e5a6e1_c47bc5014a1a6daf, // 5^-232
+    0xc6b8e9b0709f109a_359ab6419ca1091b, // 5^-231
+    0xf867241c8cc6d4c0_c30163d203c94b62, // 5^-230
+    0x9b407691d7fc44f8_79e0de63425dcf1d, // 5^-229
+    0xc21094364dfb5636_985915fc12f542e4, // 5^-228
+    0xf294b943e17a2bc4_3e6f5b7b17b2939d, // 5^-227
+    0x979cf3ca6cec5b5a_a705992ceecf9c42, // 5^-226
+    0xbd8430bd08277231_50c6ff782a838353, // 5^-225
+    0xece53cec4a314ebd_a4f8bf5635246428, // 5^-224
+    0x940f4613ae5ed136_871b7795e136be99, // 5^-223
+    0xb913179899f68584_28e2557b59846e3f, // 5^-222
+    0xe757dd7ec07426e5_331aeada2fe589cf, // 5^-221
+    0x9096ea6f3848984f_3ff0d2c85def7621, // 5^-220
+    0xb4bca50b065abe63_0fed077a756b53a9, // 5^-219
+    0xe1ebce4dc7f16dfb_d3e8495912c62894, // 5^-218
+    0x8d3360f09cf6e4bd_64712dd7abbbd95c, // 5^-217
+    0xb080392cc4349dec_bd8d794d96aacfb3, // 5^-216
+    0xdca04777f541c567_ecf0d7a0fc5583a0, // 5^-215
+    0x89e42caaf9491b60_f41686c49db57244, // 5^-214
+    0xac5d37d5b79b6239_311c2875c522ced5, // 5^-213
+    0xd77485cb25823ac7_7d633293366b828b, // 5^-212
+    0x86a8d39ef77164bc_ae5dff9c02033197, // 5^-211
+    0xa8530886b54dbdeb_d9f57f830283fdfc, // 5^-210
+    0xd267caa862a12d66_d072df63c324fd7b, // 5^-209
+    0x8380dea93da4bc60_4247cb9e59f71e6d, // 5^-208
+    0xa46116538d0deb78_52d9be85f074e608, // 5^-207
+    0xcd795be870516656_67902e276c921f8b, // 5^-206
+    0x806bd9714632dff6_00ba1cd8a3db53b6, // 5^-205
+    0xa086cfcd97bf97f3_80e8a40eccd228a4, // 5^-204
+    0xc8a883c0fdaf7df0_6122cd128006b2cd, // 5^-203
+    0xfad2a4b13d1b5d6c_796b805720085f81, // 5^-202
+    0x9cc3a6eec6311a63_cbe3303674053bb0, // 5^-201
+    0xc3f490aa77bd60fc_bedbfc4411068a9c, // 5^-200
+    0xf4f1b4d515acb93b_ee92fb5515482d44, // 5^-199
+    0x991711052d8bf3c5_751bdd152d4d1c4a, // 5^-198
+    0xbf5cd54678eef0b6_d262d45a78a0635d, // 5^-197
+    0xef340a98172aace4_86fb897116c87c34, // 5^-196
+    0x9580869f0e7aac0e_d45d35e6ae3d4da0, // 5^-195
+    0xbae0a846d2195712_8974836059cca109, // 5^-194
+    0xe998d258869facd7_2bd1a438703fc94b, // 5^-193
+    0x91ff83775423cc06_7b6306a34627ddcf, // 5^-192
+    0xb67f6455292cbf08_1a3bc84c17b1d542, // 5^-191
+    0xe41f3d6a7377eeca_20caba5f1d9e4a93, // 5^-190
+    0x8e938662882af53e_547eb47b7282ee9c, // 5^-189
+    0xb23867fb2a35b28d_e99e619a4f23aa43, // 5^-188
+    0xdec681f9f4c31f31_6405fa00e2ec94d4, // 5^-187
+    0x8b3c113c38f9f37e_de83bc408dd3dd04, // 5^-186
+    0xae0b158b4738705e_9624ab50b148d445, // 5^-185
+    0xd98ddaee19068c76_3badd624dd9b0957, // 5^-184
+    0x87f8a8d4cfa417c9_e54ca5d70a80e5d6, // 5^-183
+    0xa9f6d30a038d1dbc_5e9fcf4ccd211f4c, // 5^-182
+    0xd47487cc8470652b_7647c3200069671f, // 5^-181
+    0x84c8d4dfd2c63f3b_29ecd9f40041e073, // 5^-180
+    0xa5fb0a17c777cf09_f468107100525890, // 5^-179
+    0xcf79cc9db955c2cc_7182148d4066eeb4, // 5^-178
+    0x81ac1fe293d599bf_c6f14cd848405530, // 5^-177
+    0xa21727db38cb002f_b8ada00e5a506a7c, // 5^-176
+    0xca9cf1d206fdc03b_a6d90811f0e4851c, // 5^-175
+    0xfd442e4688bd304a_908f4a166d1da663, // 5^-174
+    0x9e4a9cec15763e2e_9a598e4e043287fe, // 5^-173
+    0xc5dd44271ad3cdba_40eff1e1853f29fd, // 5^-172
+    0xf7549530e188c128_d12bee59e68ef47c, // 5^-171
+    0x9a94dd3e8cf578b9_82bb74f8301958ce, // 5^-170
+    0xc13a148e3032d6e7_e36a52363c1faf01, // 5^-169
+    0xf18899b1bc3f8ca1_dc44e6c3cb279ac1, // 5^-168
+    0x96f5600f15a7b7e5_29ab103a5ef8c0b9, // 5^-167
+    0xbcb2b812db11a5de_7415d448f6b6f0e7, // 5^-166
+    0xebdf661791d60f56_111b495b3464ad21, // 5^-165
+    0x936b9fcebb25c995_cab10dd900beec34, // 5^-164
+    0xb84687c269ef3bfb_3d5d514f40eea742, // 5^-163
+    0xe65829b3046b0afa_0cb4a5a3112a5112, // 5^-162
+    0x8ff71a0fe2c2e6dc_47f0e785eaba72ab, // 5^-161
+    0xb3f4e093db73a093_59ed216765690f56, // 5^-160
+    0xe0f218b8d25088b8_306869c13ec3532c, // 5^-159
+    0x8c974f7383725573_1e414218c73a13fb, // 5^-158
+    0xafbd2350644eeacf_e5d1929ef90898fa, // 5^-157
+    0xdbac6c247d62a583_df45f746b74abf39, // 5^-156
+    0x894bc396ce5da772_6b8bba8c328eb783, // 5^-155
+    0xab9eb47c81f5114f_066ea92f3f326564, // 5^-154
+    0xd686619ba27255a2_c80a537b0efefebd, // 5^-153
+    0x8613fd0145877585_bd06742ce95f5f36, // 5^-152
+    0xa798fc4196e952e7_2c48113823b73704, // 5^-151
+    0xd17f3b51fca3a7a0_f75a15862ca504c5, // 5^-150
+    0x82ef85133de648c4_9a984d73dbe722fb, // 5^-149
+    0xa3ab66580d5fdaf5_c13e60d0d2e0ebba, // 5^-148
+    0xcc963fee10b7d1b3_318df905079926a8, // 5^-147
+    0xffbbcfe994e5c61f_fdf17746497f7052, // 5^-146
+    0x9fd561f1fd0f9bd3_feb6ea8bedefa633, // 5^-145
+    0xc7caba6e7c5382c8_fe64a52ee96b8fc0, // 5^-144
+    0xf9bd690a1b68637b_3dfdce7aa3c673b0, // 5^-143
+    0x9c1661a651213e2d_06bea10ca65c084e, // 5^-142
+    0xc31bfa0fe5698db8_486e494fcff30a62, // 5^-141
+    0xf3e2f893dec3f126_5a89dba3c3efccfa, // 5^-140
+    0x986ddb5c6b3a76b7_f89629465a75e01c, // 5^-139
+    0xbe89523386091465_f6bbb397f1135823, // 5^-138
+    0xee2ba6c0678b597f_746aa07ded582e2c, // 5^-137
+    0x94db483840b717ef_a8c2a44eb4571cdc, // 5^-136
+    0xba121a4650e4ddeb_92f34d62616ce413, // 5^-135
+    0xe896a0d7e51e1566_77b020baf9c81d17, // 5^-134
+    0x915e2486ef32cd60_0ace1474dc1d122e, // 5^-133
+    0xb5b5ada8aaff80b8_0d819992132456ba, // 5^-132
+    0xe3231912d5bf60e6_10e1fff697ed6c69, // 5^-131
+    0x8df5efabc5979c8f_ca8d3ffa1ef463c1, // 5^-130
+    0xb1736b96b6fd83b3_bd308ff8a6b17cb2, // 5^-129
+    0xddd0467c64bce4a0_ac7cb3f6d05ddbde, // 5^-128
+    0x8aa22c0dbef60ee4_6bcdf07a423aa96b, // 5^-127
+    0xad4ab7112eb3929d_86c16c98d2c953c6, // 5^-126
+    0xd89d64d57a607744_e871c7bf077ba8b7, // 5^-125
+    0x87625f056c7c4a8b_11471cd764ad4972, // 5^-124
+    0xa93af6c6c79b5d2d_d598e40d3dd89bcf, // 5^-123
+    0xd389b47879823479_4aff1d108d4ec2c3, // 5^-122
+    0x843610cb4bf160cb_cedf722a585139ba, // 5^-121
+    0xa54394fe1eedb8fe_c2974eb4ee658828, // 5^-120
+    0xce947a3da6a9273e_733d226229feea32, // 5^-119
+    0x811ccc668829b887_0806357d5a3f525f, // 5^-118
+    0xa163ff802a3426a8_ca07c2dcb0cf26f7, // 5^-117
+    0xc9bcff6034c13052_fc89b393dd02f0b5, // 5^-116
+    0xfc2c3f3841f17c67_bbac2078d443ace2, // 5^-115
+    0x9d9ba7832936edc0_d54b944b84aa4c0d, // 5^-114
+    0xc5029163f384a931_0a9e795e65d4df11, // 5^-113
+    0xf64335bcf065d37d_4d4617b5ff4a16d5, // 5^-112
+    0x99ea0196163fa42e_504bced1bf8e4e45, // 5^-111
+    0xc06481fb9bcf8d39_e45ec2862f71e1d6, // 5^-110
+    0xf07da27a82c37088_5d767327bb4e5a4c, // 5^-109
+    0x964e858c91ba2655_3a6a07f8d510f86f, // 5^-108
+    0xbbe226efb628afea_890489f70a55368b, // 5^-107
+    0xeadab0aba3b2dbe5_2b45ac74ccea842e, // 5^-106
+    0x92c8ae6b464fc96f_3b0b8bc90012929d, // 5^-105
+    0xb77ada0617e3bbcb_09ce6ebb40173744, // 5^-104
+    0xe55990879ddcaabd_cc420a6a101d0515, // 5^-103
+    0x8f57fa54c2a9eab6_9fa946824a12232d, // 5^-102
+    0xb32df8e9f3546564_47939822dc96abf9, // 5^-101
+    0xdff9772470297ebd_59787e2b93bc56f7, // 5^-100
+    0x8bfbea76c619ef36_57eb4edb3c55b65a, // 5^-99
+    0xaefae51477a06b03_ede622920b6b23f1, // 5^-98
+    0xdab99e59958885c4_e95fab368e45eced, // 5^-97
+    0x88b402f7fd75539b_11dbcb0218ebb414, // 5^-96
+    0xaae103b5fcd2a881_d652bdc29f26a119, // 5^-95
+    0xd59944a37c0752a2_4be76d3346f0495f, // 5^-94
+    0x857fcae62d8493a5_6f70a4400c562ddb, // 5^-93
+    0xa6dfbd9fb8e5b88e_cb4ccd500f6bb952, // 5^-92
+    0xd097ad07a71f26b2_7e2000a41346a7a7, // 5^-91
+    0x825ecc24c873782f_8ed400668c0c28c8, // 5^-90
+    0xa2f67f2dfa90563b_728900802f0f32fa, // 5^-89
+    0xcbb41ef979346bca_4f2b40a03ad2ffb9, // 5^-88
+    0xfea126b7d78186bc_e2f610c84987bfa8, // 5^-87
+    0x9f24b832e6b0f436_0dd9ca7d2df4d7c9, // 5^-86
+    0xc6ede63fa05d3143_91503d1c79720dbb, // 5^-85
+    0xf8a95fcf88747d94_75a44c6397ce912a, // 5^-84
+    0x9b69dbe1b548ce7c_c986afbe3ee11aba, // 5^-83
+    0xc24452da229b021b_fbe85badce996168, // 5^-82
+    0xf2d56790ab41c2a2_fae27299423fb9c3, // 5^-81
+    0x97c560ba6b0919a5_dccd879fc967d41a, // 5^-80
+    0xbdb6b8e905cb600f_5400e987bbc1c920, // 5^-79
+    0xed246723473e3813_290123e9aab23b68, // 5^-78
+    0x9436c0760c86e30b_f9a0b6720aaf6521, // 5^-77
+    0xb94470938fa89bce_f808e40e8d5b3e69, // 5^-76
+    0xe7958cb87392c2c2_b60b1d1230b20e04, // 5^-75
+    0x90bd77f3483bb9b9_b1c6f22b5e6f48c2, // 5^-74
+    0xb4ecd5f01a4aa828_1e38aeb6360b1af3, // 5^-73
+    0xe2280b6c20dd5232_25c6da63c38de1b0, // 5^-72
+    0x8d590723948a535f_579c487e5a38ad0e, // 5^-71
+    0xb0af48ec79ace837_2d835a9df0c6d851, // 5^-70
+    0xdcdb1b2798182244_f8e431456cf88e65, // 5^-69
+    0x8a08f0f8bf0f156b_1b8e9ecb641b58ff, // 5^-68
+    0xac8b2d36eed2dac5_e272467e3d222f3f, // 5^-67
+    0xd7adf884aa879177_5b0ed81dcc6abb0f, // 5^-66
+    0x86ccbb52ea94baea_98e947129fc2b4e9, // 5^-65
+    0xa87fea27a539e9a5_3f2398d747b36224, // 5^-64
+    0xd29fe4b18e88640e_8eec7f0d19a03aad, // 5^-63
+    0x83a3eeeef9153e89_1953cf68300424ac, // 5^-62
+    0xa48ceaaab75a8e2b_5fa8c3423c052dd7, // 5^-61
+    0xcdb02555653131b6_3792f412cb06794d, // 5^-60
+    0x808e17555f3ebf11_e2bbd88bbee40bd0, // 5^-59
+    0xa0b19d2ab70e6ed6_5b6aceaeae9d0ec4, // 5^-58
+    0xc8de047564d20a8b_f245825a5a445275, // 5^-57
+    0xfb158592be068d2e_eed6e2f0f0d56712, // 5^-56
+    0x9ced737bb6c4183d_55464dd69685606b, // 5^-55
+    0xc428d05aa4751e4c_aa97e14c3c26b886, // 5^-54
+    0xf53304714d9265df_d53dd99f4b3066a8, // 5^-53
+    0x993fe2c6d07b7fab_e546a8038efe4029, // 5^-52
+    0xbf8fdb78849a5f96_de98520472bdd033, // 5^-51
+    0xef73d256a5c0f77c_963e66858f6d4440, // 5^-50
+    0x95a8637627989aad_dde7001379a44aa8, // 5^-49
+    0xbb127c53b17ec159_5560c018580d5d52, // 5^-48
+    0xe9d71b689dde71af_aab8f01e6e10b4a6, // 5^-47
+    0x9226712162ab070d_cab3961304ca70e8, // 5^-46
+    0xb6b00d69bb55c8d1_3d607b97c5fd0d22, // 5^-45
+    0xe45c10c42a2b3b05_8cb89a7db77c506a, // 5^-44
+    0x8eb98a7a9a5b04e3_77f3608e92adb242, // 5^-43
+    0xb267ed1940f1c61c_55f038b237591ed3, // 5^-42
+    0xdf01e85f912e37a3_6b6c46dec52f6688, // 5^-41
+    0x8b61313bbabce2c6_2323ac4b3b3da015, // 5^-40
+    0xae397d8aa96c1b77_abec975e0a0d081a, // 5^-39
+    0xd9c7dced53c72255_96e7bd358c904a21, // 5^-38
+    0x881cea14545c7575_7e50d64177da2e54, // 5^-37
+    0xaa242499697392d2_dde50bd1d5d0b9e9, // 5^-36
+    0xd4ad2dbfc3d07787_955e4ec64b44e864, // 5^-35
+    0x84ec3c97da624ab4_bd5af13bef0b113e, // 5^-34
+    0xa6274bbdd0fadd61_ecb1ad8aeacdd58e, // 5^-33
+    0xcfb11ead453994ba_67de18eda5814af2, // 5^-32
+    0x81ceb32c4b43fcf4_80eacf948770ced7, // 5^-31
+    0xa2425ff75e14fc31_a1258379a94d028d, // 5^-30
+    0xcad2f7f5359a3b3e_096ee45813a04330, // 5^-29
+    0xfd87b5f28300ca0d_8bca9d6e188853fc, // 5^-28
+    0x9e74d1b791e07e48_775ea264cf55347e, // 5^-27
+    0xc612062576589dda_95364afe032a819e, // 5^-26
+    0xf79687aed3eec551_3a83ddbd83f52205, // 5^-25
+    0x9abe14cd44753b52_c4926a9672793543, // 5^-24
+    0xc16d9a0095928a27_75b7053c0f178294, // 5^-23
+    0xf1c90080baf72cb1_5324c68b12dd6339, // 5^-22
+    0x971da05074da7bee_d3f6fc16ebca5e04, // 5^-21
+    0xbce5086492111aea_88f4bb1ca6bcf585, // 5^-20
+    0xec1e4a7db69561a5_2b31e9e3d06c32e6, // 5^-19
+    0x9392ee8e921d5d07_3aff322e62439fd0, // 5^-18
+    0xb877aa3236a4b449_09befeb9fad487c3, // 5^-17
+    0xe69594bec44de15b_4c2ebe687989a9b4, // 5^-16
+    0x901d7cf73ab0acd9_0f9d37014bf60a11, // 5^-15
+    0xb424dc35095cd80f_538484c19ef38c95, // 5^-14
+    0xe12e13424bb40e13_2865a5f206b06fba, // 5^-13
+    0x8cbccc096f5088cb_f93f87b7442e45d4, // 5^-12
+    0xafebff0bcb24aafe_f78f69a51539d749, // 5^-11
+    0xdbe6fecebdedd5be_b573440e5a884d1c, // 5^-10
+    0x89705f4136b4a597_31680a88f8953031, // 5^-9
+    0xabcc77118461cefc_fdc20d2b36ba7c3e, // 5^-8
+    0xd6bf94d5e57a42bc_3d32907604691b4d, // 5^-7
+    0x8637bd05af6c69b5_a63f9a49c2c1b110, // 5^-6
+    0xa7c5ac471b478423_0fcf80dc33721d54, // 5^-5
+    0xd1b71758e219652b_d3c36113404ea4a9, // 5^-4
+    0x83126e978d4fdf3b_645a1cac083126ea, // 5^-3
+    0xa3d70a3d70a3d70a_3d70a3d70a3d70a4, // 5^-2
+    0xcccccccccccccccc_cccccccccccccccd, // 5^-1
+    0x8000000000000000_0000000000000000, // 5^0
+    0xa000000000000000_0000000000000000, // 5^1
+    0xc800000000000000_0000000000000000, // 5^2
+    0xfa00000000000000_0000000000000000, // 5^3
+    0x9c40000000000000_0000000000000000, // 5^4
+    0xc350000000000000_0000000000000000, // 5^5
+    0xf424000000000000_0000000000000000, // 5^6
+    0x9896800000000000_0000000000000000, // 5^7
+    0xbebc200000000000_0000000000000000, // 5^8
+    0xee6b280000000000_0000000000000000, // 5^9
+    0x9502f90000000000_0000000000000000, // 5^10
+    0xba43b74000000000_0000000000000000, // 5^11
+    0xe8d4a51000000000_0000000000000000, // 5^12
+    0x9184e72a00000000_0000000000000000, // 5^13
+    0xb5e620f480000000_0000000000000000, // 5^14
+    0xe35fa931a0000000_0000000000000000, // 5^15
+    0x8e1bc9bf04000000_0000000000000000, // 5^16
+    0xb1a2bc2ec5000000_0000000000000000, // 5^17
+    0xde0b6b3a76400000_0000000000000000, // 5^18
+    0x8ac7230489e80000_0000000000000000, // 5^19
+    0xad78ebc5ac620000_0000000000000000, // 5^20
+    0xd8d726b7177a8000_0000000000000000, // 5^21
+    0x878678326eac9000_0000000000000000, // 5^22
+    0xa968163f0a57b400_0000000000000000, // 5^23
+    0xd3c21bcecceda100_0000000000000000, // 5^24
+    0x84595161401484a0_0000000000000000, // 5^25
+    0xa56fa5b99019a5c8_0000000000000000, // 5^26
+    0xcecb8f27f4200f3a_0000000000000000, // 5^27
+    0x813f3978f8940984_4000000000000000, // 5^28
+    0xa18f07d736b90be5_5000000000000000, // 5^29
+    0xc9f2c9cd04674ede_a400000000000000, // 5^30
+    0xfc6f7c4045812296_4d00000000000000, // 5^31
+    0x9dc5ada82b70b59d_f020000000000000, // 5^32
+    0xc5371912364ce305_6c28000000000000, // 5^33
+    0xf684df56c3e01bc6_c732000000000000, // 5^34
+    0x9a130b963a6c115c_3c7f400000000000, // 5^35
+    0xc097ce7bc90715b3_4b9f100000000000, // 5^36
+    0xf0bdc21abb48db20_1e86d40000000000, // 5^37
+    0x96769950b50d88f4_1314448000000000, // 5^38
+    0xbc143fa4e250eb31_17d955a000000000, // 5^39
+    0xeb194f8e1ae525fd_5dcfab0800000000, // 5^40
+    0x92efd1b8d0cf37be_5aa1cae500000000, // 5^41
+    0xb7abc627050305ad_f14a3d9e40000000, // 5^42
+    0xe596b7b0c643c719_6d9ccd05d0000000, // 5^43
+    0x8f7e32ce7bea5c6f_e4820023a2000000, // 5^44
+    0xb35dbf821ae4f38b_dda2802c8a800000, // 5^45
+    0xe0352f62a19e306e_d50b2037ad200000, // 5^46
+    0x8c213d9da502de45_4526f422cc340000, // 5^47
+    0xaf298d050e4395d6_9670b12b7f410000, // 5^48
+    0xdaf3f04651d47b4c_3c0cdd765f114000, // 5^49
+    0x88d8762bf324cd0f_a5880a69fb6ac800, // 5^50
+    0xab0e93b6efee0053_8eea0d047a457a00, // 5^51
+    0xd5d238a4abe98068_72a4904598d6d880, // 5^52
+    0x85a36366eb71f041_47a6da2b7f864750, // 5^53
+    0xa70c3c40a64e6c51_999090b65f67d924, // 5^54
+    0xd0cf4b50cfe20765_fff4b4e3f741cf6d, // 5^55
+    0x82818f1281ed449f_bff8f10e7a8921a4, // 5^56
+    0xa321f2d7226895c7_aff72d52192b6a0d, // 5^57
+    0xcbea6f8ceb02bb39_9bf4f8a69f764490, // 5^58
+    0xfee50b7025c36a08_02f236d04753d5b4, // 5^59
+    0x9f4f2726179a2245_01d762422c946590, // 5^60
+    0xc722f0ef9d80aad6_424d3ad2b7b97ef5, // 5^61
+    0xf8ebad2b84e0d58b_d2e0898765a7deb2, // 5^62
+    0x9b934c3b330c8577_63cc55f49f88eb2f, // 5^63
+    0xc2781f49ffcfa6d5_3cbf6b71c76b25fb, // 5^64
+    0xf316271c7fc3908a_8bef464e3945ef7a, // 5^65
+    0x97edd871cfda3a56_97758bf0e3cbb5ac, // 5^66
+    0xbde94e8e43d0c8ec_3d52eeed1cbea317, // 5^67
+    0xed63a231d4c4fb27_4ca7aaa863ee4bdd, // 5^68
+    0x945e455f24fb1cf8_8fe8caa93e74ef6a, // 5^69
+    0xb975d6b6ee39e436_b3e2fd538e122b44, // 5^70
+    0xe7d34c64a9c85d44_60dbbca87196b616, // 5^71
+    0x90e40fbeea1d3a4a_bc8955e946fe31cd, // 5^72
+    0xb51d13aea4a488dd_6babab6398bdbe41, // 5^73
+    0xe264589a4dcdab14_c696963c7eed2dd1, // 5^74
+    0x8d7eb76070a08aec_fc1e1de5cf543ca2, // 5^75
+    0xb0de65388cc8ada8_3b25a55f43294bcb, // 5^76
+    0xdd15fe86affad912_49ef0eb713f39ebe, // 5^77
+    0x8a2dbf142dfcc7ab_6e3569326c784337, // 5^78
+    0xacb92ed9397bf996_49c2c37f07965404, // 5^79
+    0xd7e77a8f87daf7fb_dc33745ec97be906, // 5^80
+    0x86f0ac99b4e8dafd_69a028bb3ded71a3, // 5^81
+    0xa8acd7c0222311bc_c40832ea0d68ce0c, // 5^82
+    0xd2d80db02aabd62b_f50a3fa490c30190, // 5^83
+    0x83c7088e1aab65db_792667c6da79e0fa, // 5^84
+    0xa4b8cab1a1563f52_577001b891185938, // 5^85
+    0xcde6fd5e09abcf26_ed4c0226b55e6f86, // 5^86
+    0x80b05e5ac60b6178_544f8158315b05b4, // 5^87
+    0xa0dc75f1778e39d6_696361ae3db1c721, // 5^88
+    0xc913936dd571c84c_03bc3a19cd1e38e9, // 5^89
+    0xfb5878494ace3a5f_04ab48a04065c723, // 5^90
+    0x9d174b2dcec0e47b_62eb0d64283f9c76, // 5^91
+    0xc45d1df942711d9a_3ba5d0bd324f8394, // 5^92
+    0xf5746577930d6500_ca8f44ec7ee36479, // 5^93
+    0x9968bf6abbe85f20_7e998b13cf4e1ecb, // 5^94
+    0xbfc2ef456ae276e8_9e3fedd8c321a67e, // 5^95
+    0xefb3ab16c59b14a2_c5cfe94ef3ea101e, // 5^96
+    0x95d04aee3b80ece5_bba1f1d158724a12, // 5^97
+    0xbb445da9ca61281f_2a8a6e45ae8edc97, // 5^98
+    0xea1575143cf97226_f52d09d71a3293bd, // 5^99
+    0x924d692ca61be758_593c2626705f9c56, // 5^100
+    0xb6e0c377cfa2e12e_6f8b2fb00c77836c, // 5^101
+    0xe498f455c38b997a_0b6dfb9c0f956447, // 5^102
+    0x8edf98b59a373fec_4724bd4189bd5eac, // 5^103
+    0xb2977ee300c50fe7_58edec91ec2cb657, // 5^104
+    0xdf3d5e9bc0f653e1_2f2967b66737e3ed, // 5^105
+    0x8b865b215899f46c_bd79e0d20082ee74, // 5^106
+    0xae67f1e9aec07187_ecd8590680a3aa11, // 5^107
+    0xda01ee641a708de9_e80e6f4820cc9495, // 5^108
+    0x884134fe908658b2_3109058d147fdcdd, // 5^109
+    0xaa51823e34a7eede_bd4b46f0599fd415, // 5^110
+    0xd4e5e2cdc1d1ea96_6c9e18ac7007c91a, // 5^111
+    0x850fadc09923329e_03e2cf6bc604ddb0, // 5^112
+    0xa6539930bf6bff45_84db8346b786151c, // 5^113
+    0xcfe87f7cef46ff16_e612641865679a63, // 5^114
+    0x81f14fae158c5f6e_4fcb7e8f3f60c07e, // 5^115
+    0xa26da3999aef7749_e3be5e330f38f09d, // 5^116
+    0xcb090c8001ab551c_5cadf5bfd3072cc5, // 5^117
+    0xfdcb4fa002162a63_73d9732fc7c8f7f6, // 5^118
+    0x9e9f11c4014dda7e_2867e7fddcdd9afa, // 5^119
+    0xc646d63501a1511d_b281e1fd541501b8, // 5^120
+    0xf7d88bc24209a565_1f225a7ca91a4226, // 5^121
+    0x9ae757596946075f_3375788de9b06958, // 5^122
+    0xc1a12d2fc3978937_0052d6b1641c83ae, // 5^123
+    0xf209787bb47d6b84_c0678c5dbd23a49a, // 5^124
+    0x9745eb4d50ce6332_f840b7ba963646e0, // 5^125
+    0xbd176620a501fbff_b650e5a93bc3d898, // 5^126
+    0xec5d3fa8ce427aff_a3e51f138ab4cebe, // 5^127
+    0x93ba47c980e98cdf_c66f336c36b10137, // 5^128
+    0xb8a8d9bbe123f017_b80b0047445d4184, // 5^129
+    0xe6d3102ad96cec1d_a60dc059157491e5, // 5^130
+    0x9043ea1ac7e41392_87c89837ad68db2f, // 5^131
+    0xb454e4a179dd1877_29babe4598c311fb, // 5^132
+    0xe16a1dc9d8545e94_f4296dd6fef3d67a, // 5^133
+    0x8ce2529e2734bb1d_1899e4a65f58660c, // 5^134
+    0xb01ae745b101e9e4_5ec05dcff72e7f8f, // 5^135
+    0xdc21a1171d42645d_76707543f4fa1f73, // 5^136
+    0x899504ae72497eba_6a06494a791c53a8, // 5^137
+    0xabfa45da0edbde69_0487db9d17636892, // 5^138
+    0xd6f8d7509292d603_45a9d2845d3c42b6, // 5^139
+    0x865b86925b9bc5c2_0b8a2392ba45a9b2, // 5^140
+    0xa7f26836f282b732_8e6cac7768d7141e, // 5^141
+    0xd1ef0244af2364ff_3207d795430cd926, // 5^142
+    0x8335616aed761f1f_7f44e6bd49e807b8, // 5^143
+    0xa402b9c5a8d3a6e7_5f16206c9c6209a6, // 5^144
+    0xcd036837130890a1_36dba887c37a8c0f, // 5^145
+    0x802221226be55a64_c2494954da2c9789, // 5^146
+    0xa02aa96b06deb0fd_f2db9baa10b7bd6c, // 5^147
+    0xc83553c5c8965d3d_6f92829494e5acc7, // 5^148
+    0xfa42a8b73abbf48c_cb772339ba1f17f9, // 5^149
+    0x9c69a97284b578d7_ff2a760414536efb, // 5^150
+    0xc38413cf25e2d70d_fef5138519684aba, // 5^151
+    0xf46518c2ef5b8cd1_7eb258665fc25d69, // 5^152
+    0x98bf2f79d5993802_ef2f773ffbd97a61, // 5^153
+    0xbeeefb584aff8603_aafb550ffacfd8fa, // 5^154
+    0xeeaaba2e5dbf6784_95ba2a53f983cf38, // 5^155
+    0x952ab45cfa97a0b2_dd945a747bf26183, // 5^156
+    0xba756174393d88df_94f971119aeef9e4, // 5^157
+    0xe912b9d1478ceb17_7a37cd5601aab85d, // 5^158
+    0x91abb422ccb812ee_ac62e055c10ab33a, // 5^159
+    0xb616a12b7fe617aa_577b986b314d6009, // 5^160
+    0xe39c49765fdf9d94_ed5a7e85fda0b80b, // 5^161
+    0x8e41ade9fbebc27d_14588f13be847307, // 5^162
+    0xb1d219647ae6b31c_596eb2d8ae258fc8, // 5^163
+    0xde469fbd99a05fe3_6fca5f8ed9aef3bb, // 5^164
+    0x8aec23d680043bee_25de7bb9480d5854, // 5^165
+    0xada72ccc20054ae9_af561aa79a10ae6a, // 5^166
+    0xd910f7ff28069da4_1b2ba1518094da04, // 5^167
+    0x87aa9aff79042286_90fb44d2f05d0842, // 5^168
+    0xa99541bf57452b28_353a1607ac744a53, // 5^169
+    0xd3fa922f2d1675f2_42889b8997915ce8, // 5^170
+    0x847c9b5d7c2e09b7_69956135febada11, // 5^171
+    0xa59bc234db398c25_43fab9837e699095, // 5^172
+    0xcf02b2c21207ef2e_94f967e45e03f4bb, // 5^173
+    0x8161afb94b44f57d_1d1be0eebac278f5, // 5^174
+    0xa1ba1ba79e1632dc_6462d92a69731732, // 5^175
+    0xca28a291859bbf93_7d7b8f7503cfdcfe, // 5^176
+    0xfcb2cb35e702af78_5cda735244c3d43e, // 5^177
+    0x9defbf01b061adab_3a0888136afa64a7, // 5^178
+    0xc56baec21c7a1916_088aaa1845b8fdd0, // 5^179
+    0xf6c69a72a3989f5b_8aad549e57273d45, // 5^180
+    0x9a3c2087a63f6399_36ac54e2f678864b, // 5^181
+    0xc0cb28a98fcf3c7f_84576a1bb416a7dd, // 5^182
+    0xf0fdf2d3f3c30b9f_656d44a2a11c51d5, // 5^183
+    0x969eb7c47859e743_9f644ae5a4b1b325, // 5^184
+    0xbc4665b596706114_873d5d9f0dde1fee, // 5^185
+    0xeb57ff22fc0c7959_a90cb506d155a7ea, // 5^186
+    0x9316ff75dd87cbd8_09a7f12442d588f2, // 5^187
+    0xb7dcbf5354e9bece_0c11ed6d538aeb2f, // 5^188
+    0xe5d3ef282a242e81_8f1668c8a86da5fa, // 5^189
+    0x8fa475791a569d10_f96e017d694487bc, // 5^190
+    0xb38d92d760ec4455_37c981dcc395a9ac, // 5^191
+    0xe070f78d3927556a_85bbe253f47b1417, // 5^192
+    0x8c469ab843b89562_93956d7478ccec8e, // 5^193
+    0xaf58416654a6babb_387ac8d1970027b2, // 5^194
+    0xdb2e51bfe9d0696a_06997b05fcc0319e, // 5^195
+    0x88fcf317f22241e2_441fece3bdf81f03, // 5^196
+    0xab3c2fddeeaad25a_d527e81cad7626c3, // 5^197
+    0xd60b3bd56a5586f1_8a71e223d8d3b074, // 5^198
+    0x85c7056562757456_f6872d5667844e49, // 5^199
+    0xa738c6bebb12d16c_b428f8ac016561db, // 5^200
+    0xd106f86e69d785c7_e13336d701beba52, // 5^201
+    0x82a45b450226b39c_ecc0024661173473, // 5^202
+    0xa34d721642b06084_27f002d7f95d0190, // 5^203
+    0xcc20ce9bd35c78a5_31ec038df7b441f4, // 5^204
+    0xff290242c83396ce_7e67047175a15271, // 5^205
+    0x9f79a169bd203e41_0f0062c6e984d386, // 5^206
+    0xc75809c42c684dd1_52c07b78a3e60868, // 5^207
+    0xf92e0c3537826145_a7709a56ccdf8a82, // 5^208
+    0x9bbcc7a142b17ccb_88a66076400bb691, // 5^209
+    0xc2abf989935ddbfe_6acff893d00ea435, // 5^210
+    0xf356f7ebf83552fe_0583f6b8c4124d43, // 5^211
+    0x98165af37b2153de_c3727a337a8b704a, // 5^212
+    0xbe1bf1b059e9a8d6_744f18c0592e4c5c, // 5^213
+    0xeda2ee1c7064130c_1162def06f79df73, // 5^214
+    0x9485d4d1c63e8be7_8addcb5645ac2ba8, // 5^215
+    0xb9a74a0637ce2ee1_6d953e2bd7173692, // 5^216
+    0xe8111c87c5c1ba99_c8fa8db6ccdd0437, // 5^217
+    0x910ab1d4db9914a0_1d9c9892400a22a2, // 5^218
+    0xb54d5e4a127f59c8_2503beb6d00cab4b, // 5^219
+    0xe2a0b5dc971f303a_2e44ae64840fd61d, // 5^220
+    0x8da471a9de737e24_5ceaecfed289e5d2, // 5^221
+    0xb10d8e1456105dad_7425a83e872c5f47, // 5^222
+    0xdd50f1996b947518_d12f124e28f77719, // 5^223
+    0x8a5296ffe33cc92f_82bd6b70d99aaa6f, // 5^224
+    0xace73cbfdc0bfb7b_636cc64d1001550b, // 5^225
+    0xd8210befd30efa5a_3c47f7e05401aa4e, // 5^226
+    0x8714a775e3e95c78_65acfaec34810a71, // 5^227
+    0xa8d9d1535ce3b396_7f1839a741a14d0d, // 5^228
+    0xd31045a8341ca07c_1ede48111209a050, // 5^229
+    0x83ea2b892091e44d_934aed0aab460432, // 5^230
+    0xa4e4b66b68b65d60_f81da84d5617853f, // 5^231
+    0xce1de40642e3f4b9_36251260ab9d668e, // 5^232
+    0x80d2ae83e9ce78f3_c1d72b7c6b426019, // 5^233
+    0xa1075a24e4421730_b24cf65b8612f81f, // 5^234
+    0xc94930ae1d529cfc_dee033f26797b627, // 5^235
+    0xfb9b7cd9a4a7443c_169840ef017da3b1, // 5^236
+    0x9d412e0806e88aa5_8e1f289560ee864e, // 5^237
+    0xc491798a08a2ad4e_f1a6f2bab92a27e2, // 5^238
+    0xf5b5d7ec8acb58a2_ae10af696774b1db, // 5^239
+    0x9991a6f3d6bf1765_acca6da1e0a8ef29, // 5^240
+    0xbff610b0cc6edd3f_17fd090a58d32af3, // 5^241
+    0xeff394dcff8a948e_ddfc4b4cef07f5b0, // 5^242
+    0x95f83d0a1fb69cd9_4abdaf101564f98e, // 5^243
+    0xbb764c4ca7a4440f_9d6d1ad41abe37f1, // 5^244
+    0xea53df5fd18d5513_84c86189216dc5ed, // 5^245
+    0x92746b9be2f8552c_32fd3cf5b4e49bb4, // 5^246
+    0xb7118682dbb66a77_3fbc8c33221dc2a1, // 5^247
+    0xe4d5e82392a40515_0fabaf3feaa5334a, // 5^248
+    0x8f05b1163ba6832d_29cb4d87f2a7400e, // 5^249
+    0xb2c71d5bca9023f8_743e20e9ef511012, // 5^250
+    0xdf78e4b2bd342cf6_914da9246b255416, // 5^251
+    0x8bab8eefb6409c1a_1ad089b6c2f7548e, // 5^252
+    0xae9672aba3d0c320_a184ac2473b529b1, // 5^253
+    0xda3c0f568cc4f3e8_c9e5d72d90a2741e, // 5^254
+    0x8865899617fb1871_7e2fa67c7a658892, // 5^255
+    0xaa7eebfb9df9de8d_ddbb901b98feeab7, // 5^256
+    0xd51ea6fa85785631_552a74227f3ea565, // 5^257
+    0x8533285c936b35de_d53a88958f87275f, // 5^258
+    0xa67ff273b8460356_8a892abaf368f137, // 5^259
+    0xd01fef10a657842c_2d2b7569b0432d85, // 5^260
+    0x8213f56a67f6b29b_9c3b29620e29fc73, // 5^261
+    0xa298f2c501f45f42_8349f3ba91b47b8f, // 5^262
+    0xcb3f2f7642717713_241c70a936219a73, // 5^263
+    0xfe0efb53d30dd4d7_ed238cd383aa0110, // 5^264
+    0x9ec95d1463e8a506_f4363804324a40aa, // 5^265
+    0xc67bb4597ce2ce48_b143c6053edcd0d5, // 5^266
+    0xf81aa16fdc1b81da_dd94b7868e94050a, // 5^267
+    0x9b10a4e5e9913128_ca7cf2b4191c8326, // 5^268
+    0xc1d4ce1f63f57d72_fd1c2f611f63a3f0, // 5^269
+    0xf24a01a73cf2dccf_bc633b39673c8cec, // 5^270
+    0x976e41088617ca01_d5be0503e085d813, // 5^271
+    0xbd49d14aa79dbc82_4b2d8644d8a74e18, // 5^272
+    0xec9c459d51852ba2_ddf8e7d60ed1219e, // 5^273
+    0x93e1ab8252f33b45_cabb90e5c942b503, // 5^274
+    0xb8da1662e7b00a17_3d6a751f3b936243, // 5^275
+    0xe7109bfba19c0c9d_0cc512670a783ad4, // 5^276
+    0x906a617d450187e2_27fb2b80668b24c5, // 5^277
+    0xb484f9dc9641e9da_b1f9f660802dedf6, // 5^278
+    0xe1a63853bbd26451_5e7873f8a0396973, // 5^279
+    0x8d07e33455637eb2_db0b487b6423e1e8, // 5^280
+    0xb049dc016abc5e5f_91ce1a9a3d2cda62, // 5^281
+    0xdc5c5301c56b75f7_7641a140cc7810fb, // 5^282
+    0x89b9b3e11b6329ba_a9e904c87fcb0a9d, // 5^283
+    0xac2820d9623bf429_546345fa9fbdcd44, // 5^284
+    0xd732290fbacaf133_a97c177947ad4095, // 5^285
+    0x867f59a9d4bed6c0_49ed8eabcccc485d, // 5^286
+    0xa81f301449ee8c70_5c68f256bfff5a74, // 5^287
+    0xd226fc195c6a2f8c_73832eec6fff3111, // 5^288
+    0x83585d8fd9c25db7_c831fd53c5ff7eab, // 5^289
+    0xa42e74f3d032f525_ba3e7ca8b77f5e55, // 5^290
+    0xcd3a1230c43fb26f_28ce1bd2e55f35eb, // 5^291
+    0x80444b5e7aa7cf85_7980d163cf5b81b3, // 5^292
+    0xa0555e361951c366_d7e105bcc332621f, // 5^293
+    0xc86ab5c39fa63440_8dd9472bf3fefaa7, // 5^294
+    0xfa856334878fc150_b14f98f6f0feb951, // 5^295
+    0x9c935e00d4b9d8d2_6ed1bf9a569f33d3, // 5^296
+    0xc3b8358109e84f07_0a862f80ec4700c8, // 5^297
+    0xf4a642e14c6262c8_cd27bb612758c0fa, // 5^298
+    0x98e7e9cccfbd7dbd_8038d51cb897789c, // 5^299
+    0xbf21e44003acdd2c_e0470a63e6bd56c3, // 5^300
+    0xeeea5d5004981478_1858ccfce06cac74, // 5^301
+    0x95527a5202df0ccb_0f37801e0c43ebc8, // 5^302
+    0xbaa718e68396cffd_d30560258f54e6ba, // 5^303
+    0xe950df20247c83fd_47c6b82ef32a2069, // 5^304
+    0x91d28b7416cdd27e_4cdc331d57fa5441, // 5^305
+    0xb6472e511c81471d_e0133fe4adf8e952, // 5^306
+    0xe3d8f9e563a198e5_58180fddd97723a6, // 5^307
+    0x8e679c2f5e44ff8f_570f09eaa7ea7648, // 5^308
+];
+
+pub(super) static RYU_POW5: [u128; 326] = [
+    0x1000000000000000_0000000000000000, // 5^0
+    0x1400000000000000_0000000000000000, // 5^1
+    0x1900000000000000_0000000000000000, // 5^2
+    0x1f40000000000000_0000000000000000, // 5^3
+    0x1388000000000000_0000000000000000, // 5^4
+    0x186a000000000000_0000000000000000, // 5^5
+    0x1e84800000000000_0000000000000000, // 5^6
+    0x1312d00000000000_0000000000000000, // 5^7
+    0x17d7840000000000_0000000000000000, // 5^8
+    0x1dcd650000000000_0000000000000000, // 5^9
+    0x12a05f2000000000_0000000000000000, // 5^10
+    0x174876e800000000_0000000000000000, // 5^11
+    0x1d1a94a200000000_0000000000000000, // 5^12
+    0x12309ce540000000_0000000000000000, // 5^13
+    0x16bcc41e90000000_0000000000000000, // 5^14
+    0x1c6bf52634000000_0000000000000000, // 5^15
+    0x11c37937e0800000_0000000000000000, // 5^16
+    0x16345785d8a00000_0000000000000000, // 5^17
+    0x1bc16d674ec80000_0000000000000000, // 5^18
+    0x1158e460913d0000_0000000000000000, // 5^19
+    0x15af1d78b58c4000_0000000000000000, // 5^20
+    0x1b1ae4d6e2ef5000_0000000000000000, // 5^21
+    0x10f0cf064dd59200_0000000000000000, // 5^22
+    0x152d02c7e14af680_0000000000000000, // 5^23
+    0x1a784379d99db420_0000000000000000, // 5^24
+    0x108b2a2c28029094_0000000000000000, // 5^25
+    0x14adf4b7320334b9_0000000000000000, // 5^26
+    0x19d971e4fe8401e7_4000000000000000, // 5^27
+    0x1027e72f1f128130_8800000000000000, // 5^28
+    0x1431e0fae6d7217c_aa00000000000000, // 5^29
+    0x193e5939a08ce9db_d480000000000000, // 5^30
+    0x1f8def8808b02452_c9a0000000000000, // 5^31
+    0x13b8b5b5056e16b3_be04000000000000, // 5^32
+    0x18a6e32246c99c60_ad85000000000000, // 5^33
+    0x1ed09bead87c0378_d8e6400000000000, // 5^34
+    0x13426172c74d822b_878fe80000000000, // 5^35
+    0x1812f9cf7920e2b6_6973e20000000000, // 5^36
+    0x1e17b84357691b64_03d0da8000000000, // 5^37
+    0x12ced32a16a1b11e_8262889000000000, // 5^38
+    0x178287f49c4a1d66_22fb2ab400000000, // 5^39
+    0x1d6329f1c35ca4bf_abb9f56100000000, // 5^40
+    0x125dfa371a19e6f7_cb54395ca0000000, // 5^41
+    0x16f578c4e0a060b5_be2947b3c8000000, // 5^42
+    0x1cb2d6f618c878e3_2db399a0ba000000, // 5^43
+    0x11efc659cf7d4b8d_fc90400474400000, // 5^44
+    0x166bb7f0435c9e71_7bb4500591500000, // 5^45
+    0x1c06a5ec5433c60d_daa16406f5a40000, // 5^46
+    0x118427b3b4a05bc8_a8a4de8459868000, // 5^47
+    0x15e531a0a1c872ba_d2ce16256fe82000, // 5^48
+    0x1b5e7e08ca3a8f69_87819baecbe22800, // 5^49
+    0x111b0ec57e6499a1_f4b1014d3f6d5900, // 5^50
+    0x1561d276ddfdc00a_71dd41a08f48af40, // 5^51
+    0x1aba4714957d300d_0e549208b31adb10, // 5^52
+    0x10b46c6cdd6e3e08_28f4db456ff0c8ea, // 5^53
+    0x14e1878814c9cd8a_33321216cbecfb24, // 5^54
+    0x1a19e96a19fc40ec_bffe969c7ee839ed, // 5^55
+    0x105031e2503da893_f7ff1e21cf512434, // 5^56
+    0x14643e5ae44d12b8_f5fee5aa43256d41, // 5^57
+    0x197d4df19d605767_337e9f14d3eec892, // 5^58
+    0x1fdca16e04b86d41_005e46da08ea7ab6, // 5^59
+    0x13e9e4e4c2f34448_a03aec4845928cb2, // 5^60
+    0x18e45e1df3b0155a_c849a75a56f72fde, // 5^61
+    0x1f1d75a5709c1ab1_7a5c1130ecb4fbd6, // 5^62
+    0x13726987666190ae_ec798abe93f11d65, // 5^63
+    0x184f03e93ff9f4da_a797ed6e38ed64bf, // 5^64
+    0x1e62c4e38ff87211_517de8c9c728bdef, // 5^65
+    0x12fdbb0e39fb474a_d2eeb17e1c7976b5, // 5^66
+    0x17bd29d1c87a191d_87aa5ddda397d462, // 5^67
+    0x1dac74463a989f64_e994f5550c7dc97b, // 5^68
+    0x128bc8abe49f639f_11fd195527ce9ded, // 5^69
+    0x172ebad6ddc73c86_d67c5faa71c24568, // 5^70
+    0x1cfa698c95390ba8_8c1b77950e32d6c2, // 5^71
+    0x121c81f7dd43a749_57912abd28dfc639, // 5^72
+    0x16a3a275d494911b_ad75756c7317b7c8, // 5^73
+    0x1c4c8b1349b9b562_98d2d2c78fdda5ba, // 5^74
+    0x11afd6ec0e14115d_9f83c3bcb9ea8794, // 5^75
+    0x161bcca7119915b5_0764b4abe8652979, // 5^76
+    0x1ba2bfd0d5ff5b22_493de1d6e27e73d7, // 5^77
+    0x1145b7e285bf98f5_6dc6ad264d8f0866, // 5^78
+    0x159725db272f7f32_c938586fe0f2ca80, // 5^79
+    0x1afcef51f0fb5eff_7b866e8bd92f7d20, // 5^80
+    0x10de1593369d1b5f_ad34051767bdae34, // 5^81
+    0x15159af804446237_9881065d41ad19c1, // 5^82
+    0x1a5b01b605557ac5_7ea147f492186032, // 5^83
+    0x1078e111c3556cbb_6f24ccf8db4f3c1f, // 5^84
+    0x14971956342ac7ea_4aee003712230b27, // 5^85
+    0x19bcdfabc13579e4_dda98044d6abcdf0, // 5^86
+    0x10160bcb58c16c2f_0a89f02b062b60b6, // 5^87
+    0x141b8ebe2ef1c73a_cd2c6c35c7b638e4, // 5^88
+    0x1922726dbaae3909_8077874339a3c71d, // 5^89
+    0x1f6b0f092959c74b_e0956914080cb8e4, // 5^90
+    0x13a2e965b9d81c8f_6c5d61ac8507f38e, // 5^91
+    0x188ba3bf284e23b3_4774ba17a649f072, // 5^92
+    0x1eae8caef261aca0_1951e89d8fdc6c8f, // 5^93
+    0x132d17ed577d0be4_0fd3316279e9c3d9, // 5^94
+    0x17f85de8ad5c4edd_13c7fdbb186434cf, // 5^95
+    0x1df67562d8b36294_58b9fd29de7d4203, // 5^96
+    0x12ba095dc7701d9c_b7743e3a2b0e4942, // 5^97
+    0x17688bb5394c2503_e5514dc8b5d1db92, // 5^98
+    0x1d42aea2879f2e44_dea5a13ae3465277, // 5^99
+    0x1249ad2594c37ceb_0b2784c4ce0bf38a, // 5^100
+    0x16dc186ef9f45c25_cdf165f6018ef06d, // 5^101
+    0x1c931e8ab871732f_416dbf7381f2ac88, // 5^102
+    0x11dbf316b346e7fd_88e497a83137abd5, // 5^103
+    0x1652efdc6018a1fc_eb1dbd923d8596ca, // 5^104
+    0x1be7abd3781eca7c_25e52cf6cce6fc7d, // 5^105
+    0x1170cb642b133e8d_97af3c1a40105dce, // 5^106
+    0x15ccfe3d35d80e30_fd9b0b20d0147542, // 5^107
+    0x1b403dcc834e11bd_3d01cde904199292, // 5^108
+    0x1108269fd210cb16_462120b1a28ffb9b, // 5^109
+    0x154a3047c694fddb_d7a968de0b33fa82, // 5^110
+    0x1a9cbc59b83a3d52_cd93c3158e00f923, // 5^111
+    0x10a1f5b813246653_c07c59ed78c09bb6, // 5^112
+    0x14ca732617ed7fe8_b09b7068d6f0c2a3, // 5^113
+    0x19fd0fef9de8dfe2_dcc24c830cacf34c, // 5^114
+    0x103e29f5c2b18bed_c9f96fd1e7ec180f, // 5^115
+    0x144db473335deee9_3c77cbc661e71e13, // 5^116
+    0x1961219000356aa3_8b95beb7fa60e598, // 5^117
+    0x1fb969f40042c54c_6e7b2e65f8f91efe, // 5^118
+    0x13d3e2388029bb4f_c50cfcffbb9bb35f, // 5^119
+    0x18c8dac6a0342a23_b6503c3faa82a037, // 5^120
+    0x1efb1178484134ac_a3e44b4f95234844, // 5^121
+    0x135ceaeb2d28c0eb_e66eaf11bd360d2b, // 5^122
+    0x183425a5f872f126_e00a5ad62c839075, // 5^123
+    0x1e412f0f768fad70_980cf18bb7a47493, // 5^124
+    0x12e8bd69aa19cc66_5f0816f752c6c8dc, // 5^125
+    0x17a2ecc414a03f7f_f6ca1cb527787b13, // 5^126
+    0x1d8ba7f519c84f5f_f47ca3e2715699d7, // 5^127
+    0x127748f9301d319b_f8cde66d86d62026, // 5^128
+    0x17151b377c247e02_f7016008e88ba830, // 5^129
+    0x1cda62055b2d9d83_b4c1b80b22ae923c, // 5^130
+    0x12087d4358fc8272_50f91306f5ad1b65, // 5^131
+    0x168a9c942f3ba30e_e53757c8b318623f, // 5^132
+    0x1c2d43b93b0a8bd2_9e852dbadfde7acf, // 5^133
+    0x119c4a53c4e69763_a3133c94cbeb0cc1, // 5^134
+    0x16035ce8b6203d3c_8bd80bb9fee5cff1, // 5^135
+    0x1b843422e3a84c8b_aece0ea87e9f43ee, // 5^136
+    0x1132a095ce492fd7_4d40c9294f238a75, // 5^137
+    0x157f48bb41db7bcd_2090fb73a2ec6d12, // 5^138
+    0x1adf1aea12525ac0_68b53a508ba78856, // 5^139
+    0x10cb70d24b7378b8_417144725748b536, // 5^140
+    0x14fe4d06de5056e6_51cd958eed1ae283, // 5^141
+    0x1a3de04895e46c9f_e640faf2a8619b24, // 5^142
+    0x1066ac2d5daec3e3_efe89cd7a93d00f7, // 5^143
+    0x14805738b51a74dc_ebe2c40d938c4134, // 5^144
+    0x19a06d06e2611214_26db7510f86f5181, // 5^145
+    0x100444244d7cab4c_9849292a9b4592f1, // 5^146
+    0x1405552d60dbd61f_be5b73754216f7ad, // 5^147
+    0x1906aa78b912cba7_adf25052929cb598, // 5^148
+    0x1f485516e7577e91_996ee4673743e2ff, // 5^149
+    0x138d352e5096af1a_ffe54ec0828a6ddf, // 5^150
+    0x18708279e4bc5ae1_bfdea270a32d0957, // 5^151
+    0x1e8ca3185deb719a_2fd64b0ccbf84bad, // 5^152
+    0x1317e5ef3ab32700_5de5eee7ff7b2f4c, // 5^153
+    0x17dddf6b095ff0c0_755f6aa1ff59fb1f, // 5^154
+    0x1dd55745cbb7ecf0_92b7454a7f3079e7, // 5^155
+    0x12a5568b9f52f416_5bb28b4e8f7e4c30, // 5^156
+    0x174eac2e8727b11b_f29f2e22335ddf3c, // 5^157
+    0x1d22573a28f19d62_ef46f9aac035570b, // 5^158
+    0x123576845997025d_d58c5c0ab8215667, // 5^159
+    0x16c2d4256ffcc2f5_4aef730d6629ac01, // 5^160
+    0x1c73892ecbfbf3b2_9dab4fd0bfb41701, // 5^161
+    0x11c835bd3f7d784f_a28b11e277d08e60, // 5^162
+    0x163a432c8f5cd663_8b2dd65b15c4b1f9, // 5^163
+    0x1bc8d3f7b3340bfc_6df94bf1db35de77, // 5^164
+    0x115d847ad000877d_c4bbcf772901ab0a, // 5^165
+    0x15b4e5998400a95d_35eac354f34215cd, // 5^166
+    0x1b221effe500d3b4_8365742a30129b40, // 5^167
+    0x10f5535fef208450_d21f689a5e0ba108, // 5^168
+    0x1532a837eae8a565_06a742c0f58e894a, // 5^169
+    0x1a7f5245e5a2cebe_4851137132f22b9d, // 5^170
+    0x108f936baf85c136_ed32ac26bfd75b42, // 5^171
+    0x14b378469b673184_a87f57306fcd3212, // 5^172
+    0x19e056584240fde5_d29f2cfc8bc07e97, // 5^173
+    0x102c35f729689eaf_a3a37c1dd7584f1e, // 5^174
+    0x14374374f3c2c65b_8c8c5b254d2e62e6, // 5^175
+    0x1945145230b377f2_6faf71eea079fb9f, // 5^176
+    0x1f965966bce055ef_0b9b4e6a48987a87, // 5^177
+    0x13bdf7e0360c35b5_674111026d5f4c94, // 5^178
+    0x18ad75d8438f4322_c111554308b71fba, // 5^179
+    0x1ed8d34e547313eb_7155aa93cae4e7a8, // 5^180
+    0x13478410f4c7ec73_26d58a9c5ecf10c9, // 5^181
+    0x1819651531f9e78f_f08aed437682d4fb, // 5^182
+    0x1e1fbe5a7e786173_ecada89454238a3a, // 5^183
+    0x12d3d6f88f0b3ce8_73ec895cb4963664, // 5^184
+    0x1788ccb6b2ce0c22_90e7abb3e1bbc3fd, // 5^185
+    0x1d6affe45f818f2b_352196a0da2ab4fd, // 5^186
+    0x1262dfeebbb0f97b_0134fe24885ab11e, // 5^187
+    0x16fb97ea6a9d37d9_c1823dadaa715d65, // 5^188
+    0x1cba7de5054485d0_31e2cd19150db4bf, // 5^189
+    0x11f48eaf234ad3a2_1f2dc02fad2890f7, // 5^190
+    0x1671b25aec1d888a_a6f9303b9872b535, // 5^191
+    0x1c0e1ef1a724eaad_50b77c4a7e8f6282, // 5^192
+    0x1188d357087712ac_5272adae8f199d91, // 5^193
+    0x15eb082cca94d757_670f591a32e004f6, // 5^194
+    0x1b65ca37fd3a0d2d_40d32f60bf980633, // 5^195
+    0x111f9e62fe44483c_4883fd9c77bf03e0, // 5^196
+    0x156785fbbdd55a4b_5aa4fd0395aec4d8, // 5^197
+    0x1ac1677aad4ab0de_314e3c447b1a760e, // 5^198
+    0x10b8e0acac4eae8a_ded0e5aaccf089c9, // 5^199
+    0x14e718d7d7625a2d_96851f15802cac3b, // 5^200
+    0x1a20df0dcd3af0b8_fc2666dae037d74a, // 5^201
+    0x10548b68a044d673_9d980048cc22e68e, // 5^202
+    0x1469ae42c8560c10_84fe005aff2ba032, // 5^203
+    0x198419d37a6b8f14_a63d8071bef6883e, // 5^204
+    0x1fe52048590672d9_cfcce08e2eb42a4e, // 5^205
+    0x13ef342d37a407c8_21e00c58dd309a70, // 5^206
+    0x18eb0138858d09ba_2a580f6f147cc10d, // 5^207
+    0x1f25c186a6f04c28_b4ee134ad99bf150, // 5^208
+    0x137798f428562f99_7114cc0ec80176d2, // 5^209
+    0x18557f31326bbb7f_cd59ff127a01d486, // 5^210
+    0x1e6adefd7f06aa5f_c0b07ed7188249a8, // 5^211
+    0x1302cb5e6f642a7b_d86e4f466f516e09, // 5^212
+    0x17c37e360b3d351a_ce89e3180b25c98b, // 5^213
+    0x1db45dc38e0c8261_822c5bde0def3bee, // 5^214
+    0x1290ba9a38c7d17c_f15bb96ac8b58575, // 5^215
+    0x1734e940c6f9c5dc_2db2a7c57ae2e6d2, // 5^216
+    0x1d022390f8b83753_391f51b6d99ba086, // 5^217
+    0x1221563a9b732294_03b3931248014454, // 5^218
+    0x16a9abc9424feb39_04a077d6da019569, // 5^219
+    0x1c5416bb92e3e607_45c895cc9081fac3, // 5^220
+    0x11b48e353bce6fc4_8b9d5d9fda513cba, // 5^221
+    0x1621b1c28ac20bb5_ae84b507d0e58be8, // 5^222
+    0x1baa1e332d728ea3_1a25e249c51eeee3, // 5^223
+    0x114a52dffc679925_f057ad6e1b33554d, // 5^224
+    0x159ce797fb817f6f_6c6d98c9a2002aa1, // 5^225
+    0x1b04217dfa61df4b_4788fefc0a803549, // 5^226
+    0x10e294eebc7d2b8f_0cb59f5d8690214e, // 5^227
+    0x151b3a2a6b9c7672_cfe30734e83429a1, // 5^228
+    0x1a6208b50683940f_83dbc9022241340a, // 5^229
+    0x107d457124123c89_b2695da15568c086, // 5^230
+    0x149c96cd6d16cbac_1f03b509aac2f0a7, // 5^231
+    0x19c3bc80c85c7e97_26c4a24c1573acd1, // 5^232
+    0x101a55d07d39cf1e_783ae56f8d684c03, // 5^233
+    0x1420eb449c8842e6_16499ecb70c25f03, // 5^234
+    0x19292615c3aa539f_9bdc067e4cf2f6c4, // 5^235
+    0x1f736f9b3494e887_82d3081de02fb476, // 5^236
+    0x13a825c100dd1154_b1c3e512ac1dd0c9, // 5^237
+    0x18922f31411455a9_de34de57572544fc, // 5^238
+    0x1eb6bafd91596b14_55c215ed2cee963b, // 5^239
+    0x133234de7ad7e2ec_b5994db43c151de5, // 5^240
+    0x17fec216198ddba7_e2ffa1214b1a655e, // 5^241
+    0x1dfe729b9ff15291_dbbf89699de0feb6, // 5^242
+    0x12bf07a143f6d39b_2957b5e202ac9f31, // 5^243
+    0x176ec98994f48881_f3ada35a8357c6fe, // 5^244
+    0x1d4a7bebfa31aaa2_70990c31242db8bd, // 5^245
+    0x124e8d737c5f0aa5_865fa79eb69c9376, // 5^246
+    0x16e230d05b76cd4e_e7f791866443b854, // 5^247
+    0x1c9abd04725480a2_a1f575e7fd54a669, // 5^248
+    0x11e0b622c774d065_a53969b0fe54e801, // 5^249
+    0x1658e3ab7952047f_0e87c41d3dea2202, // 5^250
+    0x1bef1c9657a6859e_d229b5248d64aa82, // 5^251
+    0x117571ddf6c81383_435a1136d85eea91, // 5^252
+    0x15d2ce55747a1864_143095848e76a536, // 5^253
+    0x1b4781ead1989e7d_193cbae5b2144e83, // 5^254
+    0x110cb132c2ff630e_2fc5f4cf8f4cb112, // 5^255
+    0x154fdd7f73bf3bd1_bbb77203731fdd56, // 5^256
+    0x1aa3d4df50af0ac6_2aa54e844fe7d4ac, // 5^257
+    0x10a6650b926d66bb_daa75112b1f0e4eb, // 5^258
+    0x14cffe4e7708c06a_d15125575e6d1e26, // 5^259
+    0x1a03fde214caf085_85a56ead360865b0, // 5^260
+    0x10427ead4cfed653_7387652c41c53f8e, // 5^261
+    0x14531e58a03e8be8_50693e7752368f71, // 5^262
+    0x1967e5eec84e2ee2_64838e1526c4334e, // 5^263
+    0x1fc1df6a7a61ba9a_fda4719a70754022, // 5^264
+    0x13d92ba28c7d14a0_de86c70086494815, // 5^265
+    0x18cf768b2f9c59c9_162878c0a7db9a1a, // 5^266
+    0x1f03542dfb83703b_5bb296f0d1d280a1, // 5^267
+    0x1362149cbd322625_194f9e5683239064, // 5^268
+    0x183a99c3ec7eafae_5fa385ec23ec747e, // 5^269
+    0x1e494034e79e5b99_f78c67672ce7919d, // 5^270
+    0x12edc82110c2f940_3ab7c0a07c10bb02, // 5^271
+    0x17a93a2954f3b790_4965b0c89b14e9c3, // 5^272
+    0x1d9388b3aa30a574_5bbf1cfac1da2433, // 5^273
+    0x127c35704a5e6768_b957721cb92856a0, // 5^274
+    0x171b42cc5cf60142_e7ad4ea3e7726c48, // 5^275
+    0x1ce2137f74338193_a198a24ce14f075a, // 5^276
+    0x120d4c2fa8a030fc_44ff65700cd16498, // 5^277
+    0x16909f3b92c83d3b_563f3ecc1005bdbe, // 5^278
+    0x1c34c70a777a4c8a_2bcf0e7f14072d2e, // 5^279
+    0x11a0fc668aac6fd6_5b61690f6c847c3d, // 5^280
+    0x16093b802d578bcb_f239c35347a59b4c, // 5^281
+    0x1b8b8a6038ad6ebe_eec83428198f021f, // 5^282
+    0x1137367c236c6537_553d20990ff96153, // 5^283
+    0x1585041b2c477e85_2a8c68bf53f7b9a8, // 5^284
+    0x1ae64521f7595e26_752f82ef28f5a812, // 5^285
+    0x10cfeb353a97dad8_093db1d57999890b, // 5^286
+    0x1503e602893dd18e_0b8d1e4ad7ffeb4e, // 5^287
+    0x1a44df832b8d45f1_8e7065dd8dffe622, // 5^288
+    0x106b0bb1fb384bb6_f9063faa78bfefd5, // 5^289
+    0x1485ce9e7a065ea4_b747cf9516efebca, // 5^290
+    0x19a742461887f64d_e519c37a5cabe6bd, // 5^291
+    0x1008896bcf54f9f0_af301a2c79eb7036, // 5^292
+    0x140aabc6c32a386c_dafc20b798664c43, // 5^293
+    0x190d56b873f4c688_11bb28e57e7fdf54, // 5^294
+    0x1f50ac6690f1f82a_1629f31ede1fd72a, // 5^295
+    0x13926bc01a973b1a_4dda37f34ad3e67a, // 5^296
+    0x187706b0213d09e0_e150c5f01d88e019, // 5^297
+    0x1e94c85c298c4c59_19a4f76c24eb181f, // 5^298
+    0x131cfd3999f7afb7_b0071aa39712ef13, // 5^299
+    0x17e43c8800759ba5_9c08e14c7cd7aad8, // 5^300
+    0x1ddd4baa0093028f_030b199f9c0d958e, // 5^301
+    0x12aa4f4a405be199_61e6f003c1887d79, // 5^302
+    0x1754e31cd072d9ff_ba60ac04b1ea9cd7, // 5^303
+    0x1d2a1be4048f907f_a8f8d705de65440d, // 5^304
+    0x123a516e82d9ba4f_c99b8663aaff4a88, // 5^305
+    0x16c8e5ca239028e3_bc0267fc95bf1d2a, // 5^306
+    0x1c7b1f3cac74331c_ab0301fbbb2ee474, // 5^307
+    0x11ccf385ebc89ff1_eae1e13d54fd4ec9, // 5^308
+    0x1640306766bac7ee_659a598caa3ca27b, // 5^309
+    0x1bd03c81406979e9_ff00efefd4cbcb1a, // 5^310
+    0x116225d0c841ec32_3f6095f5e4ff5ef0, // 5^311
+    0x15baaf44fa52673e_cf38bb735e3f36ac, // 5^312
+    0x1b295b1638e7010e_8306ea5035cf0457, // 5^313
+    0x10f9d8ede39060a9_11e4527221a162b6, // 5^314
+    0x15384f295c7478d3_565d670eaa09bb64, // 5^315
+    0x1a8662f3b3919708_2bf4c0d2548c2a3d, // 5^316
+    0x1093fdd8503afe65_1b78f88374d79a66, // 5^317
+    0x14b8fd4e6449bdfe_625736a4520d8100, // 5^318
+    0x19e73ca1fd5c2d7d_faed044d6690e140, // 5^319
+    0x103085e53e599c6e_bcd422b0601a8cc8, // 5^320
+    0x143ca75e8df0038a_6c092b5c78212ffa, // 5^321
+    0x194bd136316c046d_070b763396297bf8, // 5^322
+    0x1f9ec583bdc70588_48ce53c07bb3daf6, // 5^323
+    0x13c33b72569c6375_2d80f4584d5068da, // 5^324
+    0x18b40a4eec437c52_78e1316e60a48310, // 5^325
+];
+
+pub(super) static RYU_POW5_INV: [u128; 342] = [
+    0x2000000000000000_0000000000000001, // 5^-0
+    0x1999999999999999_999999999999999a, // 5^-1
+    0x147ae147ae147ae1_47ae147ae147ae15, // 5^-2
+    0x10624dd2f1a9fbe7_6c8b4395810624de, // 5^-3
+    0x1a36e2eb1c432ca5_7a786c226809d496, // 5^-4
+    0x14f8b588e368f084_61f9f01b866e43ab, // 5^-5
+    0x10c6f7a0b5ed8d36_b4c7f34938583622, // 5^-6
+    0x1ad7f29abcaf4857_87a6520ec08d236a, // 5^-7
+    0x15798ee2308c39df_9fb841a566d74f88, // 5^-8
+    0x112e0be826d694b2_e62d01511f12a607, // 5^-9
+    0x1b7cdfd9d7bdbab7_d6ae6881cb5109a4, // 5^-10
+    0x15fd7fe17964955f_def1ed34a2a73aea, // 5^-11
+    0x119799812dea1119_7f27f0f6e885c8bb, // 5^-12
+    0x1c25c268497681c2_650cb4be40d60df8, // 5^-13
+    0x16849b86a12b9b01_ea70909833de7193, // 5^-14
+    0x1203af9ee756159b_21f3a6e0297ec143, // 5^-15
+    0x1cd2b297d889bc2b_6985d7cd0f313537, // 5^-16
+    0x170ef54646d49689_2137dfd73f5a90f9, // 5^-17
+    0x12725dd1d243aba0_e75fe645cc4873fa, // 5^-18
+    0x1d83c94fb6d2ac34_a5663d3c7a0d865d, // 5^-19
+    0x179ca10c9242235d_511e976394d79eb1, // 5^-20
+    0x12e3b40a0e9b4f7d_da7edf82dd794bc1, // 5^-21
+    0x1e392010175ee596_2a6498d1625bac68, // 5^-22
+    0x182db34012b25144_eeb6e0a781e2f053, // 5^-23
+    0x1357c299a88ea76a_58924d52ce4f26a9, // 5^-24
+    0x1ef2d0f5da7dd8aa_27507bb7b07ea441, // 5^-25
+    0x18c240c4aecb13bb_52a6c95fc0655034, // 5^-26
+    0x13ce9a36f23c0fc9_0eebd44c99eaa690, // 5^-27
+    0x1fb0f6be50601941_b17953adc3110a80, // 5^-28
+    0x195a5efea6b34767_c12ddc8b02740867, // 5^-29
+    0x14484bfeebc29f86_3424b06f3529a052, // 5^-30
+    0x1039d66589687f9e_901d59f290ee19db, // 5^-31
+    0x19f623d5a8a73297_4cfbc31db4b0295f, // 5^-32
+    0x14c4e977ba1f5bac_3d9635b15d59bab2, // 5^-33
+    0x109d8792fb4c4956_97ab5e277de16228, // 5^-34
+    0x1a95a5b7f87a0ef0_f2abc9d8c9689d0d, // 5^-35
+    0x154484932d2e725a_5bbca17a3aba173e, // 5^-36
+    0x11039d428a8b8eae_afca1ac82efb45cb, // 5^-37
+    0x1b38fb9daa78e44a_b2dcf7a6b1920945, // 5^-38
+    0x15c72fb1552d836e_f57d92ebc141a104, // 5^-39
+    0x116c262777579c58_c46475896767b403, // 5^-40
+    0x1be03d0bf225c6f4_6d6d88dbd8a5ecd2, // 5^-41
+    0x164cfda3281e38c3_8abe071646eb23db, // 5^-42
+    0x11d7314f534b609c_6efe6c11d255b649, // 5^-43
+    0x1c8b821885456760_b197134fb6ef8a0e, // 5^-44
+    0x16d601ad376ab91a_27ac0f72f8bfa1a5, // 5^-45
+    0x1244ce242c5560e1_b95672c260994e1e, // 5^-46
+    0x1d3ae36d13bbce35_f5571e03cdc21695, // 5^-47
+    0x17624f8a762fd82b_2aac18030b01abab, // 5^-48
+    0x12b50c6ec4f31355_bbbce0026f348956, // 5^-49
+    0x1dee7a4ad4b81eef_92c7ccd0b1eda889, // 5^-50
+    0x17f1fb6f10934bf2_dbd30a408e57ba07, // 5^-51
+    0x1327fc58da0f6ff5_7ca8d50071dfc806, // 5^-52
+    0x1ea6608e29b24cbb_faa7bb33e9660cd6, // 5^-53
+    0x18851a0b548ea3c9_9552fc298784d711, // 5^-54
+    0x139dae6f76d88307_aaa8c9bad2d0ac0e, // 5^-55
+    0x1f62b0b257c0d1a5_dddadc5e1e1aace3, // 5^-56
+    0x191bc08eac9a4151_7e48b04b4b488a4f, // 5^-57
+    0x141633a556e1cdda_cb6d59d5d5d3a1d9, // 5^-58
+    0x1011c2eaabe7d7e2_3c577b1177dc817b, // 5^-59
+    0x19b604aaaca62636_c6f25e825960cf2a, // 5^-60
+    0x14919d5556eb51c5_6bf518684780a5bb, // 5^-61
+    0x10747ddddf22a7d1_232a79ed06008496, // 5^-62
+    0x1a53fc9631d10c81_d1dd8fe1a3340756, // 5^-63
+    0x150ffd44f4a73d34_a7e4731ae8f66c45, // 5^-64
+    0x10d9976a5d52975d_531d28e253f8569e, // 5^-65
+    0x1af5bf109550f22e_eb61db03b98d5762, // 5^-66
+    0x159165a6ddda5b58_bc4e48cfc7a445e8, // 5^-67
+    0x11411e1f17e1e2ad_6371d3d96c836b20, // 5^-68
+    0x1b9b6364f3030448_9f1c8628ad9f11cd, // 5^-69
+    0x1615e91d8f359d06_e5b06b53be18db0b, // 5^-70
+    0x11ab20e472914a6b_eaf3890fcb4715a2, // 5^-71
+    0x1c45016d841baa46_44b8db4c7871bc37, // 5^-72
+    0x169d9abe03495505_03c715d6c6c1635f, // 5^-73
+    0x1217aefe69077737_3638de456bcde919, // 5^-74
+    0x1cf2b1970e725858_56c163a2461641c1, // 5^-75
+    0x17288e1271f51379_df011c81d1ab67ce, // 5^-76
+    0x1286d80ec190dc61_7f3416ce4155eca5, // 5^-77
+    0x1da48ce468e7c702_6520247d3556476e, // 5^-78
+    0x17b6d71d20b96c01_ea801d30f7783925, // 5^-79
+    0x12f8ac174d612334_bb99b0f3f92cfa84, // 5^-80
+    0x1e5aacf215683854_5f5c4e532847f739, // 5^-81
+    0x18488a5b44536043_7f7d0b75b9d32c2e, // 5^-82
+    0x136d3b7c36a919cf_9930d5f7c7dc2358, // 5^-83
+    0x1f152bf9f10e8fb2_8eb4898c72f9d226, // 5^-84
+    0x18ddbcc7f40ba628_722a07a38f2e41b8, // 5^-85
+    0x13e497065cd61e86_c1bb394fa5be9afa, // 5^-86
+    0x1fd424d6faf030d7_9c5ec2190930f7f6, // 5^-87
+    0x197683df2f268d79_49e56814075a5ff8, // 5^-88
+    0x145ecfe5bf520ac7_6e51201005e1e660, // 5^-89
+    0x104bd984990e6f05_f1da800cd181851a, // 5^-90
+    0x1a12f5a0f4e3e4d6_4fc400148268d4f5, // 5^-91
+    0x14dbf7b3f71cb711_d96999aa01ed772b, // 5^-92
+    0x10aff95cc5b09274_adee1488018ac5bc, // 5^-93
+    0x1ab328946f80ea54_497ceda668de092c, // 5^-94
+    0x155c2076bf9a5510_3aca57b853e4d424, // 5^-95
+    0x1116805effaeaa73_623b7960431d7683, // 5^-96
+    0x1b5733cb32b110b8_9d2bf566d1c8bd9e, // 5^-97
+    0x15df5ca28ef40d60_7dbcc452416d647f, // 5^-98
+    0x117f7d4ed8c33de6_cafd69db678ab6cc, // 5^-99
+    0x1bff2ee48e052fd7_ab2f0fc572778adf, // 5^-100
+    0x1665bf1d3e6a8cac_88f273045b92d580, // 5^-101
+    0x11eaff4a98553d56_d3f528d049424466, // 5^-102
+    0x1cab3210f3bb9557_b988414d4203a0a3, // 5^-103
+    0x16ef5b40c2fc7779_6139cdd76802e6e9, // 5^-104
+    0x125915cd68c9f92d_e761717920025254, // 5^-105
+    0x1d5b561574765b7c_a568b58e999d5086, // 5^-106
+    0x177c44ddf6c515fd_5120913ee14aa6d2, // 5^-107
+    0x12c9d0b1923744ca_a74d40ff1aa21f0e, // 5^-108
+    0x1e0fb44f50586e11_0baece64f769cb4a, // 5^-109
+    0x180c903f7379f1a7_3c8bd850c5ee3c3b, // 5^-110
+    0x133d4032c2c7f485_ca0979da37f1c9c9, // 5^-111
+    0x1ec866b79e0cba6f_a9a8c2f6bfe942db, // 5^-112
+    0x18a0522c7e709526_2153cf2bccba9be3, // 5^-113
+    0x13b374f06526ddb8_1aa9728970954982, // 5^-114
+    0x1f8587e7083e2f8c_f775840f1a88759d, // 5^-115
+    0x19379fec0698260a_5f9136727ba05e17, // 5^-116
+    0x142c7ff0054684d5_1940f85b9619e4df, // 5^-117
+    0x1023998cd1053710_e100c6afab47ea4c, // 5^-118
+    0x19d28f47b4d524e7_ce67a44c453fdd47, // 5^-119
+    0x14a8729fc3ddb71f_d852e9d69dccb106, // 5^-120
+    0x1086c219697e2c19_79dbee454b0a2738, // 5^-121
+    0x1a71368f0f30468f_295fe3a211a9d859, // 5^-122
+    0x15275ed8d8f36ba5_bab31c81a7bb137a, // 5^-123
+    0x10ec4be0ad8f8951_6228e39aec95a92f, // 5^-124
+    0x1b13ac9aaf4c0ee8_9d0e38f7e0ef7517, // 5^-125
+    0x15a956e225d67253_b0d82d931a592a79, // 5^-126
+    0x11544581b7dec1dc_8d79be0f4847552e, // 5^-127
+    0x1bba08cf8c979c94_158f967eda0bbb7c, // 5^-128
+    0x162e6d72d6dfb076_77a611ff14d62f97, // 5^-129
+    0x11bebdf578b2f391_f951a7ff43de8c79, // 5^-130
+    0x1c6463225ab7ec1c_c21c3ffed2fdad8e, // 5^-131
+    0x16b6b5b5155ff017_01b0333242648ad8, // 5^-132
+    0x122bc490dde659ac_0159c28e9b83a246, // 5^-133
+    0x1d12d41afca3c2ac_cef604175f3903a3, // 5^-134
+    0x17424348ca1c9bbd_725e69ac4c2d9c83, // 5^-135
+    0x129b69070816e2fd_f5185489d68ae39c, // 5^-136
+    0x1dc574d80cf16b2f_ee8d540fbdab05c6, // 5^-137
+    0x17d12a4670c1228c_bed77672fe226b05, // 5^-138
+    0x130dbb6b8d674ed6_ff12c528cb4ebc04, // 5^-139
+    0x1e7c5f127bd87e24_cb513b74787df9a0, // 5^-140
+    0x18637f41fcad31b7_090dc929f9fe614d, // 5^-141
+    0x1382cc34ca2427c5_a0d7d42194cb810a, // 5^-142
+    0x1f37ad21436d0c6f_67bfb9cf5478ce77, // 5^-143
+    0x18f9574dcf8a7059_1fcc94a5dd2d71f9, // 5^-144
+    0x13faac3e3fa1f37a_7fd6dd517dbdf4c7, // 5^-145
+    0x1ff779fd329cb8c3_ffbe2ee8c92fee0b, // 5^-146
+    0x1992c7fdc216fa36_6631bf20a0f324d6, // 5^-147
+    0x14756ccb01abfb5e_b827cc1a1a5c1d78, // 5^-148
+    0x105df0a267bcc918_935309ae7b7ce460, // 5^-149
+    0x1a2fe76a3f9474f4_1eeb42b0c594a099, // 5^-150
+    0x14f31f8832dd2a5c_e58902270476e6e1, // 5^-151
+    0x10c27fa028b0eeb0_b7a0ce859d2bebe7, // 5^-152
+    0x1ad0cc33744e4ab4_59014a6f61dfdfd8, // 5^-153
+    0x1573d68f903ea229_e0cdd525e7e64cad, // 5^-154
+    0x11297872d9cbb4ee_4d7177518651d6f1, // 5^-155
+    0x1b758d848fac54b0_7be8bee8d6e957e8, // 5^-156
+    0x15f7a46a0c89dd59_fcba3253df211320, // 5^-157
+    0x1192e9ee706e4aae_63c8284318e74280, // 5^-158
+    0x1c1e43171a4a1117_060d0d3827d86a66, // 5^-159
+    0x167e9c127b6e7412_6b3da42cecad21eb, // 5^-160
+    0x11fee341fc585cdb_88fe1cf0bd574e56, // 5^-161
+    0x1ccb0536608d615f_419694b462254a23, // 5^-162
+    0x1708d0f84d3de77f_67abaa29e81dd4e9, // 5^-163
+    0x126d73f9d764b932_b95621bb2017dd87, // 5^-164
+    0x1d7becc2f23ac1ea_c223692b668c95a5, // 5^-165
+    0x179657025b6234bb_ce82ba891ed6de1d, // 5^-166
+    0x12deac01e2b4f6fc_a53562074bdf1818, // 5^-167
+    0x1e3113363787f194_3b889cd87964f359, // 5^-168
+    0x18274291c6065adc_fc6d4a46c783f5e1, // 5^-169
+    0x13529ba7d19eaf17_30576e9f06032b1a, // 5^-170
+    0x1eea92a61c311825_1a257dcb3cd1de90, // 5^-171
+    0x18bba884e35a79b7_481dfe3c30a7e540, // 5^-172
+    0x13c9539d82aec7c5_d34b31c9c0865100, // 5^-173
+    0x1fa885c8d117a609_5211e942cda3b4cd, // 5^-174
+    0x19539e3a40dfb807_74db21023e1c90a4, // 5^-175
+    0x1442e4fb67196005_f715b401cb4a0d50, // 5^-176
+    0x103583fc527ab337_f8de299b09080aa7, // 5^-177
+    0x19ef3993b72ab859_8e304291a80cddd7, // 5^-178
+    0x14bf6142f8eef9e1_3e8d020e200a4b13, // 5^-179
+    0x10991a9bfa58c7e7_653d9b3e80083c0f, // 5^-180
+    0x1a8e90f9908e0ca5_6ec8f864000d2ce4, // 5^-181
+    0x153eda614071a3b7_8bd3f9e999a423ea, // 5^-182
+    0x10ff151a99f482f9_3ca994bae1501cbb, // 5^-183
+    0x1b31bb5dc320d18e_c775bac49bb3612b, // 5^-184
+    0x15c162b168e70e0b_d2c4956a16291a89, // 5^-185
+    0x11678227871f3e6f_dbd0778811ba7ba1, // 5^-186
+    0x1bd8d03f3e9863e6_2c80bf401c5d929b, // 5^-187
+    0x16470cff6546b651_bd33cc3349e47549, // 5^-188
+    0x11d270cc51055ea7_ca8fd68f6e505dd4, // 5^-189
+    0x1c83e7ad4e6efdd9_4419574be3b3c953, // 5^-190
+    0x16cfec8aa52597e1_0347790982f63aa9, // 5^-191
+    0x123ff06eea847980_cf6c60d468c4fbba, // 5^-192
+    0x1d331a4b10d3f59a_e57a34870e07f92a, // 5^-193
+    0x175c1508da432ae2_512e906c0b399422, // 5^-194
+    0x12b010d3e1cf5581_da8ba6bcd5c7a9b5, // 5^-195
+    0x1de6815302e5559c_90df712e22d90f87, // 5^-196
+    0x17eb9aa8cf1dde16_da4c5a8b4f140c6c, // 5^-197
+    0x1322e220a5b17e78_aea37ba2a5a9a38a, // 5^-198
+    0x1e9e369aa2b59727_7dd25f6aa2a905a9, // 5^-199
+    0x187e92154ef7ac1f_97db7f888220d154, // 5^-200
+    0x139874ddd8c6234c_797c6606ce80a777, // 5^-201
+    0x1f5a549627a36bad_8f2d700ae4010bf1, // 5^-202
+    0x191510781fb5efbe_0c2459a25000d65a, // 5^-203
+    0x1410d9f9b2f7f2fe_701d1481d99a4515, // 5^-204
+    0x100d7b2e28c65bfe_c017439b147b6a77, // 5^-205
+    0x19af2b7d0e0a2cca_ccf205c4ed9243f2, // 5^-206
+    0x148c22ca71a1bd6f_0a5b37d0be0e9cc2, // 5^-207
+    0x10701bd527b4978c_0848f973cb3ee3ce, // 5^-208
+    0x1a4cf9550c5425ac_da0e5bec78649fb0, // 5^-209
+    0x150a6110d6a9b7bd_7b3eaff060507fc0, // 5^-210
+    0x10d51a73deee2c97_95cbbff380406633, // 5^-211
+    0x1aee90b964b04758_efac665266cd7052, // 5^-212
+    0x158ba6fab6f36c47_2623850eb8a459db, // 5^-213
+    0x113c85955f29236c_1e82d0d893b6ae49, // 5^-214
+    0x1b9408eefea838ac_fd9e1af41f8ab075, // 5^-215
+    0x16100725988693bd_97b1af29b2d559f7, // 5^-216
+    0x11a66c1e139edc97_ac8e25baf5777b2c, // 5^-217
+    0x1c3d79c9b8fe2dbf_7a7d092b2258c513, // 5^-218
+    0x169794a160cb57cc_61fda0ef4ead6a76, // 5^-219
+    0x1212dd4de7091309_e7fe1a590bbdeec5, // 5^-220
+    0x1ceafbafd80e84dc_a6635d5b45fcb13a, // 5^-221
+    0x172262f3133ed0b0_851c4aaf6b308dc8, // 5^-222
+    0x1281e8c275cbda26_d0e36ef2bc26d7d4, // 5^-223
+    0x1d9ca79d894629d7_b49f17eac6a48c86, // 5^-224
+    0x17b08617a104ee46_2a18dfef0550706b, // 5^-225
+    0x12f39e794d9d8b6b_54e0b3259dd9f389, // 5^-226
+    0x1e5297287c2f4578_87cdeb6f62f65274, // 5^-227
+    0x18421286c9bf6ac6_d30b22bf825ea85d, // 5^-228
+    0x13680ed23aff889f_0f3c1bcc684bb9e4, // 5^-229
+    0x1f0ce4839198da98_18602c7a4079296d, // 5^-230
+    0x18d71d360e13e213_46b356c833942124, // 5^-231
+    0x13df4a91a4dcb4dc_388f78a029434db6, // 5^-232
+    0x1fcbaa82a1612160_5a7f2766a86baf8a, // 5^-233
+    0x196fbb9bb44db44d_153285ebb9efbfa2, // 5^-234
+    0x145962e2f6a4903d_aa8ed189618c994e, // 5^-235
+    0x1047824f2bb6d9ca_eed8a7a11ad6e10c, // 5^-236
+    0x1a0c03b1df8af611_7e27729b5e249b45, // 5^-237
+    0x14d6695b193bf80d_fe85f549181d4904, // 5^-238
+    0x10ab877c142ff9a4_cb9e5dd4134aa0d0, // 5^-239
+    0x1aac0bf9b9e65c3a_df63c9535211014d, // 5^-240
+    0x15566ffafb1eb02f_191ca10f74da6771, // 5^-241
+    0x1111f32f2f4bc025_adb080d92a4852c1, // 5^-242
+    0x1b4feb7eb212cd09_15e7348eaa0d5134, // 5^-243
+    0x15d98932280f0a6d_ab1f5d3eee710dc4, // 5^-244
+    0x117ad428200c0857_bc1917658b8da49d, // 5^-245
+    0x1bf7b9d9cce00d59_2cf4f23c127c3a94, // 5^-246
+    0x165fc7e170b33de0_f0c3f4fcdb969543, // 5^-247
+    0x11e6398126f5cb1a_5a365d9716121103, // 5^-248
+    0x1ca38f350b22de90_9056fc24f01ce804, // 5^-249
+    0x16e93f5da2824ba6_d9df301d8ce3ecd0, // 5^-250
+    0x125432b14ecea2eb_e17f59b13d8323da, // 5^-251
+    0x1d53844ee47dd179_68cbc2b52f38395c, // 5^-252
+    0x177603725064a794_53d6355dbf602de3, // 5^-253
+    0x12c4cf8ea6b6ec76_a9782ab165e68b1c, // 5^-254
+    0x1e07b27dd78b13f1_0f26aab56fd744fa, // 5^-255
+    0x18062864ac6f4327_3f52222abfdf6a62, // 5^-256
+    0x1338205089f29c1f_65db4e88997f884e, // 5^-257
+    0x1ec033b40fea9365_6fc54a7428cc0d4a, // 5^-258
+    0x1899c2f673220f84_596aa1f68709a43b, // 5^-259
+    0x13ae3591f5b4d936_adeee7f86c07b696, // 5^-260
+    0x1f7d228322baf524_497e3ff3e00c5756, // 5^-261
+    0x1930e868e89590e9_d464fff64cd6ac45, // 5^-262
+    0x14272053ed4473ee_4383fff83d7889d1, // 5^-263
+    0x101f4d0ff1038ff1_cf9cccc69793a174, // 5^-264
+    0x19cbae7fe805b31c_7f6147a425b90252, // 5^-265
+    0x14a2f1ffecd15c16_cc4dd2e9b7c7350f, // 5^-266
+    0x10825b3323dab012_3d0b0f215fd290d9, // 5^-267
+    0x1a6a2b85062ab350_61ab4b689950e7c1, // 5^-268
+    0x1521bc6a6b555c40_4e22a2ba1440b967, // 5^-269
+    0x10e7c9eebc4449cd_0b4ee894dd009453, // 5^-270
+    0x1b0c764ac6d3a948_1217da87c800ed51, // 5^-271
+    0x15a391d56bdc876c_db46486ca000bdda, // 5^-272
+    0x114fa7ddefe39f8a_490506bd4ccd64af, // 5^-273
+    0x1bb2a62fe638ff43_a8080ac87ae23ab1, // 5^-274
+    0x162884f31e93ff69_5339a239fbe82ef4, // 5^-275
+    0x11ba03f5b20fff87_75c7b4fb2fecf25d, // 5^-276
+    0x1c5cd322b67fff3f_22d92191e647ea2e, // 5^-277
+    0x16b0a8e891ffff65_b57a8141850654f2, // 5^-278
+    0x1226ed86db3332b7_c4620101373843f5, // 5^-279
+    0x1d0b15a491eb8459_3a366801f1f39fee, // 5^-280
+    0x173c115074bc69e0_fb5eb99b27f6198b, // 5^-281
+    0x129674405d6387e7_2f7efae2865e7ad6, // 5^-282
+    0x1dbd86cd6238d971_e597f7d0d6fd9156, // 5^-283
+    0x17cad23de82d7ac1_8479930d78cadaab, // 5^-284
+    0x1308a831868ac89a_d06142712d6f1556, // 5^-285
+    0x1e74404f3daada91_4d686a4eaf182222, // 5^-286
+    0x185d003f6488aeda_a453883ef279b4e8, // 5^-287
+    0x137d99cc506d58ae_e9dc6cff28615d87, // 5^-288
+    0x1f2f5c7a1a488de4_a960ae650d6895a4, // 5^-289
+    0x18f2b061aea07183_bab3beb73ded4483, // 5^-290
+    0x13f559e7bee6c136_2ef6322c318a9d36, // 5^-291
+    0x1feef63f97d79b89_e4bd1d13827761f0, // 5^-292
+    0x198bf832dfdfafa1_83ca7da9352c4e5a, // 5^-293
+    0x146ff9c24cb2f2e7_9ca1fe20f756a515, // 5^-294
+    0x1059949b708f28b9_4a1b31b3f9121daa, // 5^-295
+    0x1a28edc580e50df5_435eb5ecc1b695dd, // 5^-296
+    0x14ed8b04671da4c4_35e55e57015ede4a, // 5^-297
+    0x10be08d0527e1d69_c4b77eac0118b1d5, // 5^-298
+    0x1ac9a7b3b7302f0f_a12597799b5ab622, // 5^-299
+    0x156e1fc2f8f358d9_4db7ac6149155e81, // 5^-300
+    0x1124e63593f5e0ad_d7c6238107444b9b, // 5^-301
+    0x1b6e3d2286563449_593d059b3ed3ac2b, // 5^-302
+    0x15f1ca820511c36d_e0fd9e15cbdc89bc, // 5^-303
+    0x118e3b9b37416924_b3fe18116fe3a163, // 5^-304
+    0x1c16c5c525357507_866359b57fd29bd1, // 5^-305
+    0x16789e3750f790d2_d1e91491330ee30e, // 5^-306
+    0x11fa182c40c60d75_74ba76da8f3f1c0b, // 5^-307
+    0x1cc359e067a348bb_edf72490e531c678, // 5^-308
+    0x1702ae4d1fb5d3c9_8b2c1d40b75b052d, // 5^-309
+    0x12688b70e62b0fd4_6f567dcd5f7c0424, // 5^-310
+    0x1d74124e3d11b2ed_7ef0c94898c66d06, // 5^-311
+    0x17900ea4fda7c257_98c0a106e09ebd9f, // 5^-312
+    0x12d9a550caec9b79_470080d24d4bcae6, // 5^-313
+    0x1e29088144adc58e_d800ce1d487944a2, // 5^-314
+    0x1820d39a9d57d13f_1333d8176d2dd082, // 5^-315
+    0x134d76154aaca765_a8f646792424a6ce, // 5^-316
+    0x1ee25688777aa56f_74bd3d8ea03aa47d, // 5^-317
+    0x18b51206c5fbb78c_5d64313ee6955064, // 5^-318
+    0x13c40e6bd1962c70_4ab68dcbebaaa6b7, // 5^-319
+    0x1fa01712e8f0471a_1124161312aaa457, // 5^-320
+    0x194cdf4253f36c14_da8344dc0eeee9df, // 5^-321
+    0x143d7f6843292343_e2029d7cd8bf2180, // 5^-322
+    0x103132b9cf541c36_4e687dfd7a328133, // 5^-323
+    0x19e851294bb9c6bd_4a40c9959050ceb8, // 5^-324
+    0x14b9da876fc7d231_0833d477a6a70bc6, // 5^-325
+    0x1094aed2bfd30e8d_a02976c61eec096b, // 5^-326
+    0x1a877e1dffb81749_004257a364acdbdf, // 5^-327
+    0x153931b1996012a0_cd01dfb5ea23e319, // 5^-328
+    0x10fa8e27ade6754d_70ce4c91881cb5ae, // 5^-329
+    0x1b2a7d0c4970bbaf_1ae3adb5a69455e2, // 5^-330
+    0x15bb973d078d62f2_7be957c4854377e8, // 5^-331
+    0x1162df64060ab58e_c987796a0435f987, // 5^-332
+    0x1bd1656cd67788e4_75a58f1006bcc271, // 5^-333
+    0x16411df0ab92d3e9_f7b7a5a66bca3527, // 5^-334
+    0x11cdb18d560f0fee_5fc61e1ebca1c41f, // 5^-335
+    0x1c7c4f4889b1b316_ffa363646102d365, // 5^-336
+    0x16c9d906d48e28df_32e91c504d9bdc51, // 5^-337
+    0x123b140576d820b2_8f20e37371497d0e, // 5^-338
+    0x1d2b533bf159cdea_7e9b0585820f2e7c, // 5^-339
+    0x1755dc2ff447d7ee_cbaf379e01a5beca, // 5^-340
+    0x12ab168cc36cacbf_0958f94b348498a1, // 5^-341
+];
@@ -0,0 +1,401 @@
+//! RGBA to greyscale, `LANES` pixels at a time.
+//!
+//! Luma is a weighted sum of R, G and B. The weights depend on which primaries the
+//! image was mastered for, hence [`LumaStandard`]. They're applied in 8.8 fixed
+//! point, so they're rounded to 256ths that add up to exactly 256: then white
+//! (255, 255, 255) comes out as 255 rather than a step or two under.
+
+mod tables;
+
+use std::{
+    array,
+    simd::{
+        Select, Simd, StdFloat, Swizzle,
+        cmp::SimdPartialOrd,
+        num::{SimdFloat, SimdUint},
+    },
+};
+
+use tables::SRGB_TO_LINEAR;
+
+use crate::{
+    LOGICAL_LANES_,
+    pixel::{Argb, B, Bgr, Bgra, Channel, G, Layout, PixelLayout, R, Rgb, Rgba, pixel_count},
+};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Which R, G, B weights to turn colour into luma with.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LumaStandard {
+    /// SD video and JPEG: 0.299, 0.587, 0.114.
+    Bt601,
+    /// HD video and sRGB: 0.2126, 0.7152, 0.0722.
+    #[default]
+    Bt709,
+    /// UHD/HDR video: 0.2627, 0.6780, 0.0593.
+    Bt2020,
+    /// A third each.
+    Average,
+    /// Your own R, G, B weights, normalised to sum to 1. They must be finite and
+    /// non-negative, and not all zero.
+    Custom([f32; 3]),
+}
+
+impl LumaStandard {
+    /// The R, G, B weights in 256ths, rounded to the nearest and adding up to 256.
+    ///
+    /// # Panics
+    /// If a [`LumaStandard::Custom`] weight is negative or not finite, or they're all zero.
+    pub fn weights(self) -> [u16; 3] {
+        fixed_point(self.coefficients())
+    }
+
+    /// The R, G, B weights as fractions adding up to 1.
+    ///
+    /// # Panics
+    /// Like [`LumaStandard::weights`].
+    pub fn coefficients(self) -> [f64; 3] {
+        let weights = match self {
+            Self::Bt601 => [0.299, 0.587, 0.114],
+            Self::Bt709 => [0.2126, 0.7152, 0.0722],
+            Self::Bt2020 => [0.2627, 0.6780, 0.0593],
+            Self::Average => [1.0; 3],
+            Self::Custom(weights) => {
+                assert!(
+                    weights.iter().all(|w| w.is_finite() && *w >= 0.0)
+                        && weights.iter().any(|w| *w > 0.0),
+                    "luma weights must be finite, non-negative and not all zero: {weights:?}"
+                );
+                weights.map(f64::from)
+            }
+        };
+        let sum: f64 = weights.iter().sum();
+        weights.map(|w| w / sum)
+    }
+}
+
+/// `coefficients` (which sum to 1) in 256ths, rounded, keeping the sum.
+fn fixed_point(coefficients: [f64; 3]) -> [u16; 3] {
+    let exact = coefficients.map(|w| w * 256.0);
+    let mut fixed = exact.map(|w| w.round() as u16);
+
+    // Each rounding is off by at most a half, so the total is at most one off 256.
+    // Take it from (or give it to) whichever weight rounding moved furthest that way.
+    let total: u16 = fixed.iter().sum();
+    let error = |i: usize| fixed[i] as f64 - exact[i];
+    let by_error = |a: &usize, b: &usize| error(*a).total_cmp(&error(*b));
+    if total > 256 {
+        let i = (0..3).max_by(by_error).unwrap();
+        fixed[i] -= total - 256;
+    } else if total < 256 {
+        let i = (0..3).min_by(by_error).unwrap();
+        fixed[i] += 256 - total;
+    }
+
+    fixed
+}
+
+/// Convert RGBA (`[u8;4]`) to grayscale (`[u8;3]`) using SIMD, with the weights of `standard`.
+///
+/// That's the luma three times over, for code that wants RGB back; prefer
+/// [`rgba_to_luma_simd`] otherwise, which is a third of the memory.
+pub fn rgba_to_gray_simd_u8(rgba: &[[u8; 4]], standard: LumaStandard) -> Vec<[u8; 3]> {
+    rgba_to_luma_simd(rgba, standard)
+        .into_iter()
+        .map(|l| [l, l, l])
+        .collect()
+}
+
+/// Luma of each RGBA pixel, one byte per pixel.
+pub fn rgba_to_luma_simd(rgba: &[[u8; 4]], standard: LumaStandard) -> Vec<u8> {
+    let mut output = vec![0; rgba.len()];
+    rgba_to_luma_into(rgba, &mut output, standard);
+    output
+}
+
+/// [`rgba_to_luma_simd`] into a buffer you already have, e.g. to reuse one across
+/// the frames of a video.
+///
+/// # Panics
+/// If `output` isn't the same length as `rgba`.
+pub fn rgba_to_luma_into(rgba: &[[u8; 4]], output: &mut [u8], standard: LumaStandard) {
+    pixels_to_luma_into(rgba.as_flattened(), PixelLayout::Rgba, output, standard);
+}
+
+/// Luma of each pixel of `pixels`, which are laid out as `layout`, one byte per pixel.
+///
+/// # Panics
+/// If `pixels` isn't a whole number of `layout` pixels.
+pub fn pixels_to_luma_simd(pixels: &[u8], layout: PixelLayout, standard: LumaStandard) -> Vec<u8> {
+    let mut output = vec![0; pixel_count(pixels, layout)];
+    pixels_to_luma_into(pixels, layout, &mut output, standard);
+    output
+}
+
+/// [`pixels_to_luma_simd`] into a buffer you already have.
+///
+/// Each `LANES` pixels are loaded as one vector of interleaved bytes (`BGRABGRA…`,
+/// say) and split into R, G and B vectors with swizzles worked out for `layout` at
+/// compile time, so the channels never leave the vector registers.
+///
+/// # Panics
+/// If `pixels` isn't a whole number of `layout` pixels, or `output` isn't one byte
+/// for each of them.
+pub fn pixels_to_luma_into(
+    pixels: &[u8],
+    layout: PixelLayout,
+    output: &mut [u8],
+    standard: LumaStandard,
+) {
+    assert_eq!(
+        pixel_count(pixels, layout),
+        output.len(),
+        "luma output needs one byte per pixel"
+    );
+
+    let weights = standard.weights();
+    match layout {
+        PixelLayout::Rgb => luma_kernel::<Rgb, { 3 * LANES }>(pixels, output, weights),
+        PixelLayout::Bgr => luma_kernel::<Bgr, { 3 * LANES }>(pixels, output, weights),
+        PixelLayout::Rgba => luma_kernel::<Rgba, { 4 * LANES }>(pixels, output, weights),
+        PixelLayout::Bgra => luma_kernel::<Bgra, { 4 * LANES }>(pixels, output, weights),
+        PixelLayout::Argb => luma_kernel::<Argb, { 4 * LANES }>(pixels, output, weights),
+    }
+}
+
+/// `LANES` pixels at a time, `W` being that many `L` pixels in bytes.
+fn luma_kernel<L: Layout, const W: usize>(pixels: &[u8], output: &mut [u8], weights: [u16; 3]) {
+    let chunks = pixels.chunks_exact(W);
+    let remainder = chunks.remainder();
+    let mut out_chunks = output.chunks_exact_mut(LANES);
+
+    for (chunk, out) in chunks.zip(&mut out_chunks) {
+        let pixels = Simd::<u8, W>::from_slice(chunk);
+        let r = Channel::<L, R>::swizzle(pixels);
+        let g = Channel::<L, G>::swizzle(pixels);
+        let b = Channel::<L, B>::swizzle(pixels);
+        luma(r, g, b, weights).copy_to_slice(out);
+    }
+
+    // Handle remaining pixels, although in the land of images, that come from cameras
+    // you're going to find powers of two (most of the time), so this code will likely do little (if anything)
+    // in most applications.
+    let [wr, wg, wb] = weights;
+    let channel = |pixel: &[u8], c: usize| pixel[L::CHANNELS[c].unwrap()] as u16;
+    for (pixel, out) in remainder
+        .chunks_exact(L::BYTES_PER_PIXEL)
+        .zip(out_chunks.into_remainder())
+    {
+        let l = wr * channel(pixel, R) + wg * channel(pixel, G) + wb * channel(pixel, B);
+        *out = ((l + 128) >> 8) as u8;
+    }
+}
+
+/// [`rgba_to_luma_into`] with the channels pulled apart by a scalar loop over each
+/// chunk, then loaded into vectors. This is how the greyscale kernel started out, and
+/// it's only public so the greyscale bin can time it: not part of the API.
+///
+/// # Panics
+/// If `output` isn't the same length as `rgba`.
+#[doc(hidden)]
+pub fn rgba_to_luma_into_scalar_gather(
+    rgba: &[[u8; 4]],
+    output: &mut [u8],
+    standard: LumaStandard,
+) {
+    assert_eq!(
+        rgba.len(),
+        output.len(),
+        "luma output needs one byte per pixel"
+    );
+
+    let weights = standard.weights();
+    let mut out_chunks = output.chunks_exact_mut(LANES);
+    for (chunk, out) in rgba.chunks_exact(LANES).zip(&mut out_chunks) {
+        let (mut r, mut g, mut b) = ([0u8; LANES], [0u8; LANES], [0u8; LANES]);
+
+        // Extract R, G, B components (ignore alpha)
+        for (i, &[ri, gi, bi, _]) in chunk.iter().enumerate() {
+            r[i] = ri;
+            g[i] = gi;
+            b[i] = bi;
+        }
+
+        let (r, g, b) = (
+            Simd::from_array(r),
+            Simd::from_array(g),
+            Simd::from_array(b),
+        );
+        luma(r, g, b, weights).copy_to_slice(out);
+    }
+
+    // Less than a chunk left, which the kernel does one pixel at a time
+    let rest = rgba.chunks_exact(LANES).remainder().as_flattened();
+    luma_kernel::<Rgba, { 4 * LANES }>(rest, out_chunks.into_remainder(), weights);
+}
+
+/// (wr*R + wg*G + wb*B) / 256, rounded.
+#[inline(always)]
+fn luma(
+    r: Simd<u8, LANES>,
+    g: Simd<u8, LANES>,
+    b: Simd<u8, LANES>,
+    [wr, wg, wb]: [u16; 3],
+) -> Simd<u8, LANES> {
+    // Convert u8 -> u16 to avoid overflow during multiplication. The weights sum to
+    // 256, so this tops out at 255 * 256 + 128 and fits.
+    let gray = (r.cast::<u16>() * Simd::splat(wr)
+        + g.cast::<u16>() * Simd::splat(wg)
+        + b.cast::<u16>() * Simd::splat(wb)
+        + Simd::splat(128))
+        >> 8;
+    gray.cast::<u8>()
+}
+
+/// Luma of each RGBA pixel computed on linear light, one byte per pixel.
+///
+/// sRGB values are gamma encoded, so weighting them directly (as
+/// [`rgba_to_luma_simd`] does) darkens saturated colours: pure blue comes out as
+/// 19 rather than 76 with BT.709. This decodes each channel through a 256 entry
+/// table, takes the weighted sum in `f32`, and encodes the result back to sRGB.
+///
+/// The encode's `y^(1/2.4)` is a degree 5 polynomial in `sqrt(y)`, which stays
+/// within 0.1 of an 8-bit step of the exact curve, so only values right on a
+/// rounding boundary ever come out one off.
+pub fn rgba_to_gray_linear(rgba: &[[u8; 4]], standard: LumaStandard) -> Vec<u8> {
+    let [wr, wg, wb] = standard.coefficients().map(|w| Simd::splat(w as f32));
+    let linear = |pixels: &[[u8; 4]; LANES], channel: usize| {
+        Simd::<f32, LANES>::from_array(array::from_fn(|i| {
+            SRGB_TO_LINEAR[pixels[i][channel] as usize]
+        }))
+    };
+    let gray = |pixels: &[[u8; 4]; LANES]| {
+        let y = linear(pixels, 0) * wr + linear(pixels, 1) * wg + linear(pixels, 2) * wb;
+        encode_srgb(y)
+    };
+
+    let mut output = vec![0; rgba.len()];
+    let (chunks, rest) = rgba.as_chunks::<LANES>();
+    let (out_chunks, out_rest) = output.as_chunks_mut::<LANES>();
+    for (chunk, out) in chunks.iter().zip(out_chunks) {
+        *out = gray(chunk).to_array();
+    }
+
+    // Pad the last few out to a whole chunk, so they get the same approximation
+    if !rest.is_empty() {
+        let mut padded = [[0; 4]; LANES];
+        padded[..rest.len()].copy_from_slice(rest);
+        out_rest.copy_from_slice(&gray(&padded).as_array()[..rest.len()]);
+    }
+
+    output
+}
+
+/// Linear light in `[0, 1]` to 8-bit sRGB.
+#[inline(always)]
+fn encode_srgb(y: Simd<f32, LANES>) -> Simd<u8, LANES> {
+    // Least-squares fit (Lawson-weighted towards minimax) of 1.055 * s^(5/6) - 0.055
+    // for s = sqrt(y) over [sqrt(0.0031308), 1], i.e. the curve above the linear toe.
+    const CURVE: [f32; 6] = [
+        -0.040_109_18,
+        1.517_941_5,
+        -1.402_677_4,
+        2.031_424_6,
+        -1.623_495_1,
+        0.517_239_2,
+    ];
+
+    let y = y.simd_clamp(Simd::splat(0.0), Simd::splat(1.0));
+    let s = y.sqrt();
+    let curve = CURVE
+        .iter()
+        .rev()
+        .fold(Simd::splat(0.0), |acc, &c| acc * s + Simd::splat(c));
+    let srgb = y
+        .simd_le(Simd::splat(0.003_130_8))
+        .select(y * Simd::splat(12.92), curve);
+
+    // Float to int casts saturate, so this can't wrap past 255
+    (srgb * Simd::splat(255.0) + Simd::splat(0.5)).cast::<u8>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STANDARDS: [LumaStandard; 8] = [
+        LumaStandard::Bt601,
+        LumaStandard::Bt709,
+        LumaStandard::Bt2020,
+        LumaStandard::Average,
+        LumaStandard::Custom([0.5, 0.3, 0.2]),
+        LumaStandard::Custom([1.0, 1.0, 1.0]),
+        LumaStandard::Custom([0.0, 0.0, 1e-30]),
+        LumaStandard::Custom([1e-30, 1e30, 0.0]),
+    ];
+
+    /// Less than a chunk, exactly one, and some chunks plus a remainder.
+    const COUNTS: [usize; 5] = [1, LANES - 1, LANES, 2 * LANES + 3, 67];
+
+    #[test]
+    fn weights_sum_to_256() {
+        for standard in STANDARDS {
+            assert_eq!(standard.weights().iter().sum::<u16>(), 256, "{standard:?}");
+        }
+    }
+
+    #[test]
+    fn black_and_white_stay_put() {
+        for standard in STANDARDS {
+            for count in COUNTS {
+                for (value, alpha) in [(0, 255), (255, 255), (0, 0), (255, 0)] {
+                    let rgba = vec![[value, value, value, alpha]; count];
+                    assert!(
+                        rgba_to_luma_simd(&rgba, standard)
+                            .iter()
+                            .all(|&y| y == value),
+                        "{standard:?}, {count} pixels of {value}"
+                    );
+                    assert!(
+                        rgba_to_gray_simd_u8(&rgba, standard)
+                            .iter()
+                            .all(|&p| p == [value; 3]),
+                        "{standard:?}, {count} pixels of {value}"
+                    );
+                    assert!(
+                        rgba_to_gray_linear(&rgba, standard)
+                            .iter()
+                            .all(|&y| y == value),
+                        "{standard:?}, {count} pixels of {value} (linear)"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn black_and_white_stay_put_in_every_layout() {
+        let layouts = [
+            PixelLayout::Rgb,
+            PixelLayout::Bgr,
+            PixelLayout::Rgba,
+            PixelLayout::Bgra,
+            PixelLayout::Argb,
+        ];
+        for standard in STANDARDS {
+            for layout in layouts {
+                for count in COUNTS {
+                    for value in [0, 255] {
+                        let pixels = vec![value; count * layout.bytes_per_pixel()];
+                        assert_eq!(
+                            pixels_to_luma_simd(&pixels, layout, standard),
+                            vec![value; count],
+                            "{standard:?}, {layout}, {count} pixels of {value}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
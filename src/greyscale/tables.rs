@@ -0,0 +1,267 @@
+//! Generated sRGB decoding table, don't edit by hand.
+//!
+//! ```python
+//! for c in range(256):
+//!     v = c / 255
+//!     v / 12.92 if v <= 0.04045 else ((v + 0.055) / 1.055) ** 2.4  # rounded to f32
+//! ```
+
+/// Linear light for each 8-bit sRGB value.
+pub(super) static SRGB_TO_LINEAR: [f32; 256] = [
+    0.0,          // 0
+    0.000303527,  // 1
+    0.000607054,  // 2
+    0.000910581,  // 3
+    0.001214108,  // 4
+    0.001517635,  // 5
+    0.001821162,  // 6
+    0.0021246888, // 7
+    0.002428216,  // 8
+    0.0027317428, // 9
+    0.00303527,   // 10
+    0.0033465358, // 11
+    0.0036765074, // 12
+    0.004024717,  // 13
+    0.004391442,  // 14
+    0.0047769533, // 15
+    0.0051815165, // 16
+    0.0056053917, // 17
+    0.006048833,  // 18
+    0.0065120906, // 19
+    0.00699541,   // 20
+    0.007499032,  // 21
+    0.008023193,  // 22
+    0.008568126,  // 23
+    0.009134059,  // 24
+    0.009721218,  // 25
+    0.010329823,  // 26
+    0.010960094,  // 27
+    0.011612245,  // 28
+    0.012286488,  // 29
+    0.0129830325, // 30
+    0.013702083,  // 31
+    0.014443844,  // 32
+    0.015208514,  // 33
+    0.015996294,  // 34
+    0.016807375,  // 35
+    0.017641954,  // 36
+    0.01850022,   // 37
+    0.019382361,  // 38
+    0.020288562,  // 39
+    0.02121901,   // 40
+    0.022173885,  // 41
+    0.023153367,  // 42
+    0.024157632,  // 43
+    0.02518686,   // 44
+    0.026241222,  // 45
+    0.027320892,  // 46
+    0.02842604,   // 47
+    0.029556835,  // 48
+    0.030713445,  // 49
+    0.031896032,  // 50
+    0.033104766,  // 51
+    0.034339808,  // 52
+    0.035601314,  // 53
+    0.03688945,   // 54
+    0.038204372,  // 55
+    0.039546236,  // 56
+    0.0409152,    // 57
+    0.04231141,   // 58
+    0.04373503,   // 59
+    0.045186203,  // 60
+    0.046665087,  // 61
+    0.048171826,  // 62
+    0.049706567,  // 63
+    0.051269457,  // 64
+    0.052860647,  // 65
+    0.054480277,  // 66
+    0.05612849,   // 67
+    0.05780543,   // 68
+    0.059511237,  // 69
+    0.061246052,  // 70
+    0.063010015,  // 71
+    0.064803265,  // 72
+    0.06662594,   // 73
+    0.06847817,   // 74
+    0.070360094,  // 75
+    0.07227185,   // 76
+    0.07421357,   // 77
+    0.07618538,   // 78
+    0.07818742,   // 79
+    0.08021982,   // 80
+    0.08228271,   // 81
+    0.08437621,   // 82
+    0.08650046,   // 83
+    0.08865558,   // 84
+    0.09084171,   // 85
+    0.093058966,  // 86
+    0.09530747,   // 87
+    0.09758735,   // 88
+    0.099898726,  // 89
+    0.10224173,   // 90
+    0.104616486,  // 91
+    0.107023105,  // 92
+    0.10946171,   // 93
+    0.11193243,   // 94
+    0.114435375,  // 95
+    0.116970666,  // 96
+    0.11953843,   // 97
+    0.122138776,  // 98
+    0.12477182,   // 99
+    0.12743768,   // 100
+    0.13013647,   // 101
+    0.13286832,   // 102
+    0.13563333,   // 103
+    0.13843161,   // 104
+    0.14126329,   // 105
+    0.14412847,   // 106
+    0.14702727,   // 107
+    0.14995979,   // 108
+    0.15292615,   // 109
+    0.15592647,   // 110
+    0.15896083,   // 111
+    0.16202937,   // 112
+    0.1651322,    // 113
+    0.1682694,    // 114
+    0.17144111,   // 115
+    0.1746474,    // 116
+    0.17788842,   // 117
+    0.18116425,   // 118
+    0.18447499,   // 119
+    0.18782078,   // 120
+    0.19120169,   // 121
+    0.19461784,   // 122
+    0.19806932,   // 123
+    0.20155625,   // 124
+    0.20507874,   // 125
+    0.20863687,   // 126
+    0.21223076,   // 127
+    0.2158605,    // 128
+    0.2195262,    // 129
+    0.22322796,   // 130
+    0.22696587,   // 131
+    0.23074006,   // 132
+    0.23455058,   // 133
+    0.23839757,   // 134
+    0.24228112,   // 135
+    0.24620132,   // 136
+    0.25015828,   // 137
+    0.2541521,    // 138
+    0.25818285,   // 139
+    0.26225066,   // 140
+    0.2663556,    // 141
+    0.2704978,    // 142
+    0.2746773,    // 143
+    0.27889428,   // 144
+    0.28314874,   // 145
+    0.28744084,   // 146
+    0.29177064,   // 147
+    0.29613826,   // 148
+    0.30054379,   // 149
+    0.3049873,    // 150
+    0.30946892,   // 151
+    0.31398872,   // 152
+    0.31854677,   // 153
+    0.3231432,    // 154
+    0.3277781,    // 155
+    0.33245152,   // 156
+    0.33716363,   // 157
+    0.34191442,   // 158
+    0.34670407,   // 159
+    0.3515326,    // 160
+    0.35640013,   // 161
+    0.3613068,    // 162
+    0.3662526,    // 163
+    0.3712377,    // 164
+    0.37626213,   // 165
+    0.38132602,   // 166
+    0.38642943,   // 167
+    0.39157248,   // 168
+    0.39675522,   // 169
+    0.40197778,   // 170
+    0.4072402,    // 171
+    0.4125426,    // 172
+    0.41788507,   // 173
+    0.42326766,   // 174
+    0.4286905,    // 175
+    0.43415365,   // 176
+    0.43965718,   // 177
+    0.4452012,    // 178
+    0.4507858,    // 179
+    0.45641103,   // 180
+    0.462077,     // 181
+    0.4677838,    // 182
+    0.47353148,   // 183
+    0.47932017,   // 184
+    0.48514995,   // 185
+    0.49102086,   // 186
+    0.49693298,   // 187
+    0.5028865,    // 188
+    0.50888133,   // 189
+    0.5149177,    // 190
+    0.52099556,   // 191
+    0.5271151,    // 192
+    0.5332764,    // 193
+    0.5394795,    // 194
+    0.54572445,   // 195
+    0.55201143,   // 196
+    0.5583404,    // 197
+    0.5647115,    // 198
+    0.57112485,   // 199
+    0.57758045,   // 200
+    0.58407843,   // 201
+    0.59061885,   // 202
+    0.59720176,   // 203
+    0.60382736,   // 204
+    0.61049557,   // 205
+    0.6172066,    // 206
+    0.6239604,    // 207
+    0.63075715,   // 208
+    0.63759685,   // 209
+    0.6444797,    // 210
+    0.65140563,   // 211
+    0.65837485,   // 212
+    0.6653873,    // 213
+    0.67244315,   // 214
+    0.6795425,    // 215
+    0.6866853,    // 216
+    0.69387174,   // 217
+    0.7011019,    // 218
+    0.70837575,   // 219
+    0.7156935,    // 220
+    0.7230551,    // 221
+    0.73046076,   // 222
+    0.7379104,    // 223
+    0.7454042,    // 224
+    0.7529422,    // 225
+    0.7605245,    // 226
+    0.76815116,   // 227
+    0.7758222,    // 228
+    0.7835378,    // 229
+    0.7912979,    // 230
+    0.7991027,    // 231
+    0.80695224,   // 232
+    0.8148466,    // 233
+    0.82278574,   // 234
+    0.8307699,    // 235
+    0.838799,     // 236
+    0.8468732,    // 237
+    0.8549926,    // 238
+    0.8631572,    // 239
+    0.8713671,    // 240
+    0.8796224,    // 241
+    0.8879231,    // 242
+    0.8962694,    // 243
+    0.9046612,    // 244
+    0.91309863,   // 245
+    0.92158186,   // 246
+    0.9301109,    // 247
+    0.9386857,    // 248
+    0.9473065,    // 249
+    0.9559733,    // 250
+    0.9646863,    // 251
+    0.9734453,    // 252
+    0.9822506,    // 253
+    0.9911021,    // 254
+    1.0,          // 255
+];
@@ -0,0 +1,148 @@
+//! Hex encoding and decoding, `LANES` bytes at a time.
+
+use std::{
+    fmt,
+    simd::{Select, Simd, cmp::SimdPartialOrd},
+};
+
+use crate::LOGICAL_LANES_;
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// Hex needs two digits per byte, this input had an odd number.
+    OddLength(usize),
+    /// The first character that isn't `[0-9a-fA-F]`, and its byte offset.
+    InvalidChar { offset: usize, ch: char },
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OddLength(len) => write!(f, "odd number of hex digits ({len})"),
+            Self::InvalidChar { offset, ch } => {
+                write!(f, "invalid hex character {ch:?} at offset {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+/// Lowercase hex encoding of `bytes`.
+pub fn simd_hex_encode(bytes: &[u8]) -> String {
+    let low_nibble = Simd::<u8, LANES>::splat(0x0f);
+    let mut out = vec![0u8; bytes.len() * 2];
+
+    let chunks = bytes.chunks_exact(LANES);
+    let remainder = chunks.remainder();
+    let mut out_chunks = out.chunks_exact_mut(2 * LANES);
+
+    for (chunk, dst) in chunks.zip(&mut out_chunks) {
+        let v = Simd::<u8, LANES>::from_slice(chunk);
+        let hi = to_ascii(v >> 4);
+        let lo = to_ascii(v & low_nibble);
+
+        // hi0 lo0 hi1 lo1 ...
+        let (first, second) = hi.interleave(lo);
+        first.copy_to_slice(&mut dst[..LANES]);
+        second.copy_to_slice(&mut dst[LANES..]);
+    }
+
+    for (&b, dst) in remainder
+        .iter()
+        .zip(out_chunks.into_remainder().chunks_exact_mut(2))
+    {
+        dst[0] = HEX_DIGITS[(b >> 4) as usize];
+        dst[1] = HEX_DIGITS[(b & 0x0f) as usize];
+    }
+
+    // SAFETY: every byte written is an ASCII hex digit.
+    unsafe { String::from_utf8_unchecked(out) }
+}
+
+/// Decode a string of hex digits (either case) back into bytes.
+pub fn simd_hex_decode(hex: &str) -> Result<Vec<u8>, HexError> {
+    let input = hex.as_bytes();
+    if !input.len().is_multiple_of(2) {
+        return Err(HexError::OddLength(input.len()));
+    }
+
+    let mut out = Vec::with_capacity(input.len() / 2);
+
+    let chunks = input.chunks_exact(2 * LANES);
+    let remainder = chunks.remainder();
+
+    for (i, chunk) in chunks.enumerate() {
+        let (a, a_invalid) = nibbles(Simd::<u8, LANES>::from_slice(&chunk[..LANES]));
+        let (b, b_invalid) = nibbles(Simd::<u8, LANES>::from_slice(&chunk[LANES..]));
+
+        let invalid = a_invalid | b_invalid << LANES;
+        if invalid != 0 {
+            return Err(invalid_char(
+                hex,
+                i * 2 * LANES + invalid.trailing_zeros() as usize,
+            ));
+        }
+
+        let (hi, lo) = a.deinterleave(b);
+        out.extend_from_slice(((hi << 4) | lo).as_array());
+    }
+
+    let base = input.len() - remainder.len();
+    for (i, pair) in remainder.chunks_exact(2).enumerate() {
+        let offset = base + 2 * i;
+        let hi = nibble(pair[0]).ok_or_else(|| invalid_char(hex, offset))?;
+        let lo = nibble(pair[1]).ok_or_else(|| invalid_char(hex, offset + 1))?;
+        out.push((hi << 4) | lo);
+    }
+
+    Ok(out)
+}
+
+/// Lane-wise nibble to ASCII hex digit.
+///
+/// This is a compare and add rather than a `swizzle_dyn` table lookup, because
+/// `swizzle_dyn` only becomes a single `pshufb` with SSSE3, and without it (i.e. the
+/// baseline x86_64 target) it gets scalarised and is slower than the plain loop.
+#[inline(always)]
+fn to_ascii(n: Simd<u8, LANES>) -> Simd<u8, LANES> {
+    n + n
+        .simd_gt(Simd::splat(9))
+        .select(Simd::splat(b'a' - 10), Simd::splat(b'0'))
+}
+
+/// Map ASCII hex digits to their value, plus a bitmask of the lanes that weren't hex digits.
+#[inline(always)]
+fn nibbles(chars: Simd<u8, LANES>) -> (Simd<u8, LANES>, u128) {
+    // Wrapping subtraction pushes everything below the range up past it,
+    // so one unsigned compare checks both ends.
+    let digit = chars - Simd::splat(b'0');
+    let alpha = (chars | Simd::splat(0x20)) - Simd::splat(b'a');
+
+    let is_digit = digit.simd_lt(Simd::splat(10));
+    let is_alpha = alpha.simd_lt(Simd::splat(6));
+
+    let value = is_digit.select(digit, alpha + Simd::splat(10));
+    let invalid = !(is_digit | is_alpha);
+
+    (value, invalid.to_bitmask() as u128)
+}
+
+fn nibble(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn invalid_char(hex: &str, offset: usize) -> HexError {
+    // Everything before `offset` was an ASCII hex digit, so it's always a char boundary.
+    let ch = hex[offset..].chars().next().unwrap_or_default();
+    HexError::InvalidChar { offset, ch }
+}
@@ -0,0 +1,256 @@
+//! RGB to HSV and HSL and back in `f32`, `LANES` pixels at a time, plus the usual
+//! hue and saturation adjustment built on them.
+//!
+//! The hue's six sectors are handled without branching: the forward direction
+//! picks the sector's formula with masks off whichever channel is the max, and the
+//! way back uses the piecewise-linear form `f(n) = v - v·s·clamp(min(k, 4 - k), 0, 1)`
+//! (HSL has its own), evaluated for all three channels at once.
+
+use std::simd::{
+    Select, Simd, Swizzle,
+    cmp::{SimdPartialEq, SimdPartialOrd},
+    num::{SimdFloat, SimdInt, SimdUint},
+};
+
+use crate::{
+    LOGICAL_LANES_,
+    pixel::{A, B, Channel, G, R, Rgba, interleave},
+};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+type Lanes = Simd<f32, LANES>;
+
+/// `rgba` as `[h, s, v, a]`, with the hue in degrees in `[0, 360)` and the rest in
+/// `[0, 1]`. Greys (no saturation) have a hue of 0.
+pub fn simd_rgba_to_hsv(rgba: &[[u8; 4]]) -> Vec<[f32; 4]> {
+    map_pixels(rgba, |pixels| {
+        let ([r, g, b], a) = load_rgba(pixels);
+        let [h, s, v] = to_hsv([r, g, b]);
+        store_f32(h, s, v, a)
+    })
+}
+
+/// [`simd_rgba_to_hsv`] the other way. Hues outside `[0, 360)` wrap around, and
+/// everything else is clamped to `[0, 1]`.
+pub fn simd_hsv_to_rgba(hsv: &[[f32; 4]]) -> Vec<[u8; 4]> {
+    map_pixels(hsv, |pixels| {
+        let ([h, s, v], a) = load_f32(pixels);
+        store_rgba(from_hsv([h, s, v]), a)
+    })
+}
+
+/// `rgba` as `[h, s, l, a]`, with the hue in degrees in `[0, 360)` and the rest in
+/// `[0, 1]`. Greys (no saturation) have a hue of 0.
+pub fn simd_rgba_to_hsl(rgba: &[[u8; 4]]) -> Vec<[f32; 4]> {
+    map_pixels(rgba, |pixels| {
+        let ([r, g, b], a) = load_rgba(pixels);
+        let [h, s, l] = to_hsl([r, g, b]);
+        store_f32(h, s, l, a)
+    })
+}
+
+/// [`simd_rgba_to_hsl`] the other way. Hues outside `[0, 360)` wrap around, and
+/// everything else is clamped to `[0, 1]`.
+pub fn simd_hsl_to_rgba(hsl: &[[f32; 4]]) -> Vec<[u8; 4]> {
+    map_pixels(hsl, |pixels| {
+        let ([h, s, l], a) = load_f32(pixels);
+        store_rgba(from_hsl([h, s, l]), a)
+    })
+}
+
+/// Rotates the hue of `rgba` by `hue_shift` degrees and scales its (HSV) saturation
+/// by `saturation`, in place. Alpha is left alone.
+///
+/// A `saturation` of 0 gives greys with the same value (max channel), not luma; use
+/// [`crate::rgba_to_luma_simd`] for that.
+pub fn simd_adjust_hue_saturation(rgba: &mut [[u8; 4]], hue_shift: f32, saturation: f32) {
+    let adjust = |pixels: &[[u8; 4]; LANES]| {
+        let ([r, g, b], a) = load_rgba(pixels);
+        let [h, s, v] = to_hsv([r, g, b]);
+        // `from_hsv` does the wrapping and clamping
+        let h = h + Simd::splat(hue_shift);
+        let s = s * Simd::splat(saturation);
+        store_rgba(from_hsv([h, s, v]), a)
+    };
+
+    let (chunks, rest) = rgba.as_chunks_mut::<LANES>();
+    for chunk in chunks {
+        *chunk = adjust(chunk);
+    }
+
+    // Pad the last few out to a whole chunk
+    if !rest.is_empty() {
+        let mut padded = [[0; 4]; LANES];
+        padded[..rest.len()].copy_from_slice(rest);
+        let len = rest.len();
+        rest.copy_from_slice(&adjust(&padded)[..len]);
+    }
+}
+
+/// `f` over `input`, `LANES` pixels at a time.
+fn map_pixels<I: Copy + Default, O: Copy + Default>(
+    input: &[I],
+    f: impl Fn(&[I; LANES]) -> [O; LANES],
+) -> Vec<O> {
+    let mut output = vec![O::default(); input.len()];
+    let (chunks, rest) = input.as_chunks::<LANES>();
+    let (out_chunks, out_rest) = output.as_chunks_mut::<LANES>();
+    for (chunk, out) in chunks.iter().zip(out_chunks) {
+        *out = f(chunk);
+    }
+
+    // Pad the last few out to a whole chunk
+    if !rest.is_empty() {
+        let mut padded = [I::default(); LANES];
+        padded[..rest.len()].copy_from_slice(rest);
+        out_rest.copy_from_slice(&f(&padded)[..rest.len()]);
+    }
+
+    output
+}
+
+/// R, G and B in `[0, 1]`, and alpha as it is.
+#[inline(always)]
+fn load_rgba(pixels: &[[u8; 4]; LANES]) -> ([Lanes; 3], Simd<u8, LANES>) {
+    let bytes = Simd::<u8, { 4 * LANES }>::from_slice(pixels.as_flattened());
+    let unit = |channel: Simd<u8, LANES>| channel.cast::<f32>() / Simd::splat(255.0);
+    let rgb = [
+        unit(Channel::<Rgba, R>::swizzle(bytes)),
+        unit(Channel::<Rgba, G>::swizzle(bytes)),
+        unit(Channel::<Rgba, B>::swizzle(bytes)),
+    ];
+    (rgb, Channel::<Rgba, A>::swizzle(bytes))
+}
+
+#[inline(always)]
+fn store_rgba([r, g, b]: [Lanes; 3], a: Simd<u8, LANES>) -> [[u8; 4]; LANES] {
+    // Float to int casts saturate, so nothing can wrap past 0 or 255
+    let byte = |channel: Lanes| (channel * Simd::splat(255.0) + Simd::splat(0.5)).cast::<u8>();
+    let bytes: Simd<u8, { 4 * LANES }> = interleave::<_, Rgba, _>(byte(r), byte(g), byte(b), a);
+    let mut pixels = [[0; 4]; LANES];
+    bytes.copy_to_slice(pixels.as_flattened_mut());
+    pixels
+}
+
+/// The first three channels, and alpha back as a byte.
+#[inline(always)]
+fn load_f32(pixels: &[[f32; 4]; LANES]) -> ([Lanes; 3], Simd<u8, LANES>) {
+    let values = Simd::<f32, { 4 * LANES }>::from_slice(pixels.as_flattened());
+    let channels = [
+        Channel::<Rgba, R>::swizzle(values),
+        Channel::<Rgba, G>::swizzle(values),
+        Channel::<Rgba, B>::swizzle(values),
+    ];
+    let alpha = clamp_unit(Channel::<Rgba, A>::swizzle(values)) * Simd::splat(255.0);
+    (channels, (alpha + Simd::splat(0.5)).cast::<u8>())
+}
+
+#[inline(always)]
+fn store_f32(h: Lanes, s: Lanes, x: Lanes, a: Simd<u8, LANES>) -> [[f32; 4]; LANES] {
+    let a = a.cast::<f32>() / Simd::splat(255.0);
+    let values: Simd<f32, { 4 * LANES }> = interleave::<_, Rgba, _>(h, s, x, a);
+    let mut pixels = [[0.0; 4]; LANES];
+    values.copy_to_slice(pixels.as_flattened_mut());
+    pixels
+}
+
+/// Hue in degrees, shared by HSV and HSL, from RGB and their max and max - min.
+#[inline(always)]
+fn hue([r, g, b]: [Lanes; 3], max: Lanes, delta: Lanes) -> Lanes {
+    // Which sector is decided by the largest channel, red winning ties
+    let sector = max.simd_eq(r).select(
+        (g - b) / delta,
+        max.simd_eq(g).select(
+            Simd::splat(2.0) + (b - r) / delta,
+            Simd::splat(4.0) + (r - g) / delta,
+        ),
+    );
+    let degrees = sector * Simd::splat(60.0);
+    let degrees = degrees
+        .simd_lt(Simd::splat(0.0))
+        .select(degrees + Simd::splat(360.0), degrees);
+    // Greys divide by zero above, which is thrown away here
+    delta
+        .simd_eq(Simd::splat(0.0))
+        .select(Simd::splat(0.0), degrees)
+}
+
+#[inline(always)]
+fn to_hsv([r, g, b]: [Lanes; 3]) -> [Lanes; 3] {
+    let max = r.simd_max(g).simd_max(b);
+    let min = r.simd_min(g).simd_min(b);
+    let delta = max - min;
+
+    let s = max
+        .simd_gt(Simd::splat(0.0))
+        .select(delta / max, Simd::splat(0.0));
+    [hue([r, g, b], max, delta), s, max]
+}
+
+#[inline(always)]
+fn from_hsv([h, s, v]: [Lanes; 3]) -> [Lanes; 3] {
+    let sixths = wrap(h / Simd::splat(60.0), 6.0);
+    let (s, v) = (clamp_unit(s), clamp_unit(v));
+    [5.0, 3.0, 1.0].map(|n| {
+        let k = wrap_once(sixths + Simd::splat(n), 6.0);
+        let ramp = clamp_unit(k.simd_min(Simd::splat(4.0) - k));
+        v - v * s * ramp
+    })
+}
+
+#[inline(always)]
+fn to_hsl([r, g, b]: [Lanes; 3]) -> [Lanes; 3] {
+    let max = r.simd_max(g).simd_max(b);
+    let min = r.simd_min(g).simd_min(b);
+    let delta = max - min;
+
+    let l = (max + min) * Simd::splat(0.5);
+    let spread = Simd::splat(1.0) - (l * Simd::splat(2.0) - Simd::splat(1.0)).abs();
+    let s = delta
+        .simd_gt(Simd::splat(0.0))
+        .select(clamp_unit(delta / spread), Simd::splat(0.0));
+    [hue([r, g, b], max, delta), s, l]
+}
+
+#[inline(always)]
+fn from_hsl([h, s, l]: [Lanes; 3]) -> [Lanes; 3] {
+    let twelfths = wrap(h / Simd::splat(30.0), 12.0);
+    let (s, l) = (clamp_unit(s), clamp_unit(l));
+    let a = s * l.simd_min(Simd::splat(1.0) - l);
+    [0.0, 8.0, 4.0].map(|n| {
+        let k = wrap_once(twelfths + Simd::splat(n), 12.0);
+        let ramp = (k - Simd::splat(3.0))
+            .simd_min(Simd::splat(9.0) - k)
+            .simd_clamp(Simd::splat(-1.0), Simd::splat(1.0));
+        l - a * ramp
+    })
+}
+
+/// `x` modulo `period`, into `[0, period)`.
+///
+/// `floor` has no SSE2 instruction and ends up as a call per lane, so this floors
+/// by truncating through `i32` and stepping down where that rounded up. Anything
+/// past ±2³¹ periods saturates, which no sensible hue gets near.
+#[inline(always)]
+fn wrap(x: Lanes, period: f32) -> Lanes {
+    let period = Simd::splat(period);
+    let quotient = x / period;
+    let truncated = quotient.cast::<i32>().cast::<f32>();
+    let floor = truncated
+        .simd_gt(quotient)
+        .select(truncated - Simd::splat(1.0), truncated);
+    x - floor * period
+}
+
+/// `x` modulo `period`, for `x` already in `[0, 2 * period)`.
+#[inline(always)]
+fn wrap_once(x: Lanes, period: f32) -> Lanes {
+    let period = Simd::splat(period);
+    x.simd_ge(period).select(x - period, x)
+}
+
+#[inline(always)]
+fn clamp_unit(x: Lanes) -> Lanes {
+    x.simd_clamp(Simd::splat(0.0), Simd::splat(1.0))
+}
@@ -0,0 +1,106 @@
+//! SIMD operations on `image` crate buffers, behind the `image` feature.
+//!
+//! The kernels here all take byte slices, and an `ImageBuffer`'s container already
+//! is one, so [`SimdImageExt`] hands it over as is: packed RGB, RGBA and Luma are
+//! never copied into `Vec<[u8; 4]>` or similar first.
+
+use std::ops::Deref;
+
+use image::{DynamicImage, GrayImage, ImageBuffer, Luma, Pixel, Rgb, Rgba};
+
+use crate::{LumaStandard, PixelLayout, pixels_to_luma_into, simd_histogram_single};
+
+/// SIMD versions of the usual image operations.
+pub trait SimdImageExt {
+    /// Greyscale with [`LumaStandard::default`], like `DynamicImage::to_luma8`.
+    fn simd_grayscale(&self) -> GrayImage {
+        self.simd_grayscale_with(LumaStandard::default())
+    }
+
+    fn simd_grayscale_with(&self, standard: LumaStandard) -> GrayImage;
+
+    /// Histogram of the luma (with [`LumaStandard::default`]), or of the values
+    /// themselves for a greyscale image.
+    fn simd_histogram(&self) -> [u32; 256];
+}
+
+impl<C: Deref<Target = [u8]>> SimdImageExt for ImageBuffer<Rgb<u8>, C> {
+    fn simd_grayscale_with(&self, standard: LumaStandard) -> GrayImage {
+        grayscale(self, Some(PixelLayout::Rgb), standard)
+    }
+
+    fn simd_histogram(&self) -> [u32; 256] {
+        histogram(self.simd_grayscale().as_raw())
+    }
+}
+
+impl<C: Deref<Target = [u8]>> SimdImageExt for ImageBuffer<Rgba<u8>, C> {
+    fn simd_grayscale_with(&self, standard: LumaStandard) -> GrayImage {
+        grayscale(self, Some(PixelLayout::Rgba), standard)
+    }
+
+    fn simd_histogram(&self) -> [u32; 256] {
+        histogram(self.simd_grayscale().as_raw())
+    }
+}
+
+impl<C: Deref<Target = [u8]>> SimdImageExt for ImageBuffer<Luma<u8>, C> {
+    /// Already grey, so just a copy.
+    fn simd_grayscale_with(&self, standard: LumaStandard) -> GrayImage {
+        grayscale(self, None, standard)
+    }
+
+    fn simd_histogram(&self) -> [u32; 256] {
+        histogram(samples(self))
+    }
+}
+
+impl SimdImageExt for DynamicImage {
+    /// Zero-copy for 8-bit RGB, RGBA and Luma, anything else goes through
+    /// `to_rgba8` first.
+    fn simd_grayscale_with(&self, standard: LumaStandard) -> GrayImage {
+        match self {
+            Self::ImageRgb8(img) => img.simd_grayscale_with(standard),
+            Self::ImageRgba8(img) => img.simd_grayscale_with(standard),
+            Self::ImageLuma8(img) => img.simd_grayscale_with(standard),
+            img => img.to_rgba8().simd_grayscale_with(standard),
+        }
+    }
+
+    fn simd_histogram(&self) -> [u32; 256] {
+        match self {
+            Self::ImageLuma8(img) => img.simd_histogram(),
+            img => histogram(img.simd_grayscale().as_raw()),
+        }
+    }
+}
+
+/// The samples of `img`'s pixels, without any slack at the end of its container.
+fn samples<P: Pixel<Subpixel = u8>, C: Deref<Target = [u8]>>(img: &ImageBuffer<P, C>) -> &[u8] {
+    let len = img.width() as usize * img.height() as usize * P::CHANNEL_COUNT as usize;
+    &img.as_raw()[..len]
+}
+
+/// `img` as luma, `layout` being `None` when it already is.
+fn grayscale<P: Pixel<Subpixel = u8>, C: Deref<Target = [u8]>>(
+    img: &ImageBuffer<P, C>,
+    layout: Option<PixelLayout>,
+    standard: LumaStandard,
+) -> GrayImage {
+    let pixels = samples(img);
+    let luma = match layout {
+        Some(layout) => {
+            let mut luma = vec![0; img.width() as usize * img.height() as usize];
+            pixels_to_luma_into(pixels, layout, &mut luma, standard);
+            luma
+        }
+        None => pixels.to_vec(),
+    };
+    GrayImage::from_raw(img.width(), img.height(), luma).expect("one byte per pixel")
+}
+
+fn histogram(luma: &[u8]) -> [u32; 256] {
+    let mut histogram = [0; 256];
+    simd_histogram_single(luma, &mut histogram);
+    histogram
+}
@@ -0,0 +1,261 @@
+//! ASCII decimal integer parsing, 16 digits per SIMD step.
+//!
+//! The digits are right-aligned into a 16 byte vector (zero padded on the left) and
+//! combined pairwise with multiply-adds: digits into 2-digit `u16`s, those into
+//! 4-digit `u32`s, then 8-digit `u64`s, then the final value. That's four vector
+//! steps instead of sixteen dependent `acc * 10 + d`s.
+
+use std::{
+    fmt,
+    simd::{Simd, ToBytes, cmp::SimdPartialOrd},
+};
+
+/// Digits handled by one SIMD step (this is independent of `LOGICAL_LANES_`, it's
+/// what fits the multiply-add ladder above).
+const DIGITS: usize = 16;
+
+const POW10_16: u64 = 10_000_000_000_000_000;
+
+const POW10: [u64; DIGITS + 1] = {
+    let mut table = [1; DIGITS + 1];
+    let mut i = 1;
+    while i <= DIGITS {
+        table[i] = table[i - 1] * 10;
+        i += 1;
+    }
+    table
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntParseError {
+    /// No digits at all (possibly just a sign).
+    Empty,
+    /// A byte that isn't an ASCII digit, and its offset in the input.
+    InvalidDigit { offset: usize, byte: u8 },
+    /// The value doesn't fit in the target type.
+    Overflow,
+}
+
+impl fmt::Display for IntParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidDigit { offset, byte } => {
+                write!(f, "invalid digit {:?} at offset {offset}", *byte as char)
+            }
+            Self::Overflow => write!(f, "number too large to fit in target type"),
+        }
+    }
+}
+
+impl std::error::Error for IntParseError {}
+
+pub fn simd_parse_u32(s: &[u8]) -> Result<u32, IntParseError> {
+    simd_parse_u64(s).and_then(|n| u32::try_from(n).map_err(|_| IntParseError::Overflow))
+}
+
+/// Parse an optionally `+` prefixed decimal `u64`.
+pub fn simd_parse_u64(s: &[u8]) -> Result<u64, IntParseError> {
+    match s {
+        [b'+', digits @ ..] => parse_digits(digits, 1),
+        digits => parse_digits(digits, 0),
+    }
+}
+
+/// Parse an optionally `+` or `-` prefixed decimal `i64`.
+pub fn simd_parse_i64(s: &[u8]) -> Result<i64, IntParseError> {
+    match s {
+        [b'-', digits @ ..] => {
+            let magnitude = parse_digits(digits, 1)?;
+            // `i64::MIN` has no positive counterpart, so negate via the unsigned magnitude.
+            if magnitude > i64::MIN.unsigned_abs() {
+                return Err(IntParseError::Overflow);
+            }
+            Ok((magnitude as i64).wrapping_neg())
+        }
+        [b'+', digits @ ..] => parse_digits(digits, 1)
+            .and_then(|n| i64::try_from(n).map_err(|_| IntParseError::Overflow)),
+        digits => parse_digits(digits, 0)
+            .and_then(|n| i64::try_from(n).map_err(|_| IntParseError::Overflow)),
+    }
+}
+
+/// Parse one `i64` per line of newline separated `text`.
+///
+/// A trailing `\r` on each line is ignored, as is a final empty line. Error offsets
+/// are relative to the start of their line.
+pub fn parse_ints_in_lines(text: &[u8]) -> Vec<Result<i64, IntParseError>> {
+    let text = text.strip_suffix(b"\n").unwrap_or(text);
+    if text.is_empty() {
+        return Vec::new();
+    }
+
+    let mut results = Vec::new();
+    let mut rest = text;
+    loop {
+        let (line, next) = match crate::simd_find_byte(rest, b'\n') {
+            Some(end) => (&rest[..end], Some(&rest[end + 1..])),
+            None => (rest, None),
+        };
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        results.push(simd_parse_i64(line));
+
+        match next {
+            Some(next) => rest = next,
+            None => break,
+        }
+    }
+
+    results
+}
+
+/// `digits` starts at `offset` in the caller's input, for error reporting.
+fn parse_digits(digits: &[u8], offset: usize) -> Result<u64, IntParseError> {
+    if digits.is_empty() {
+        return Err(IntParseError::Empty);
+    }
+
+    if digits.len() <= DIGITS {
+        return parse_16(digits, offset);
+    }
+
+    // Leading zeros don't count towards the 20 digits a u64 can hold.
+    let zeros = digits.iter().take_while(|&&b| b == b'0').count();
+    let (digits, offset) = (&digits[zeros..], offset + zeros);
+    if digits.len() <= DIGITS {
+        return parse_16(digits, offset);
+    }
+
+    // Split so the SIMD step takes the low 16 digits, and whatever's in front is the high part.
+    let (high, low) = digits.split_at(digits.len() - DIGITS);
+    if high.len() > DIGITS {
+        // Way past u64::MAX, but bad digits are still reported first.
+        for (i, chunk) in digits.chunks(DIGITS).enumerate() {
+            parse_16(chunk, offset + i * DIGITS)?;
+        }
+        return Err(IntParseError::Overflow);
+    }
+
+    let high = parse_16(high, offset)?;
+    let low = parse_16(low, offset + digits.len() - DIGITS)?;
+    high.checked_mul(POW10_16)
+        .and_then(|n| n.checked_add(low))
+        .ok_or(IntParseError::Overflow)
+}
+
+/// Parse up to 16 ASCII digits with the multiply-add ladder.
+#[inline(always)]
+fn parse_16(digits: &[u8], offset: usize) -> Result<u64, IntParseError> {
+    debug_assert!(digits.len() <= DIGITS);
+
+    let pad = DIGITS - digits.len();
+    let d = Simd::<u8, DIGITS>::from_array(load_right_aligned(digits)) - Simd::splat(b'0');
+
+    // Wrapping subtraction pushes everything below '0' up past 9 as well.
+    let invalid = d.simd_ge(Simd::splat(10)).to_bitmask();
+    if invalid != 0 {
+        let lane = invalid.trailing_zeros() as usize;
+        return Err(IntParseError::InvalidDigit {
+            offset: offset + lane - pad,
+            byte: digits[lane - pad],
+        });
+    }
+
+    Ok(ladder(d))
+}
+
+/// Combine 16 digit values (not ASCII), most significant first, into one number.
+#[inline(always)]
+fn ladder(d: Simd<u8, DIGITS>) -> u64 {
+    // Reinterpreting as wider little-endian lanes puts each odd digit in the high half
+    // next to its even partner, so every step is a mask, a shift and one multiply-add.
+    let v = Simd::<u16, 8>::from_le_bytes(d);
+    let pairs = (v & Simd::splat(0xff)) * Simd::splat(10) + (v >> 8);
+
+    let v = Simd::<u32, 4>::from_le_bytes(pairs.to_le_bytes());
+    let quads = (v & Simd::splat(0xffff)) * Simd::splat(100) + (v >> 16);
+
+    let v = Simd::<u64, 2>::from_le_bytes(quads.to_le_bytes());
+    let octs = (v & Simd::splat(0xffff_ffff)) * Simd::splat(10_000) + (v >> 32);
+
+    octs[0] * 100_000_000 + octs[1]
+}
+
+/// Up to 16 bytes, right aligned and padded on the left with `'0'`s.
+///
+/// A masked `load_or_default` or a `copy_from_slice` into a buffer both end up as a
+/// byte at a time (or a `memcpy` call) for short runtime lengths, so instead this does
+/// two overlapping fixed-size reads, one from each end, and shifts them into place
+/// as a `u128`.
+#[inline(always)]
+fn load_right_aligned(digits: &[u8]) -> [u8; DIGITS] {
+    const PADDING: u128 = u128::from_le_bytes([b'0'; DIGITS]);
+
+    let len = digits.len();
+    let aligned = if len >= 8 {
+        let first = u64::from_le_bytes(digits[..8].try_into().unwrap()) as u128;
+        let last = u64::from_le_bytes(digits[len - 8..].try_into().unwrap()) as u128;
+        (first << (8 * (DIGITS - len))) | (last << 64)
+    } else if len >= 4 {
+        let first = u32::from_le_bytes(digits[..4].try_into().unwrap()) as u128;
+        let last = u32::from_le_bytes(digits[len - 4..].try_into().unwrap()) as u128;
+        (first << (8 * (DIGITS - len))) | (last << 96)
+    } else {
+        digits
+            .iter()
+            .fold(0u128, |acc, &b| (acc >> 8) | ((b as u128) << 120))
+    };
+
+    // The low `16 - len` bytes are padding.
+    let padding = PADDING & u128::MAX.checked_shr(8 * len as u32).unwrap_or(0);
+    (aligned | padding).to_le_bytes()
+}
+
+/// `acc` with the (already validated) ASCII `digits` appended, wrapping on overflow.
+///
+/// For callers that have found the digit run themselves and know it fits.
+pub(crate) fn accumulate_digits(mut acc: u64, digits: &[u8]) -> u64 {
+    for chunk in digits.chunks(DIGITS) {
+        let value = parse_16(chunk, 0).unwrap_or_default();
+        acc = acc.wrapping_mul(POW10[chunk.len()]).wrapping_add(value);
+    }
+    acc
+}
+
+/// The value of the (already validated) ASCII digits in `span`, skipping the `.` at
+/// index `point`, in one SIMD step. `span` can be at most 16 bytes, point included.
+pub(crate) fn accumulate_around_point(span: &[u8], point: usize) -> u64 {
+    debug_assert!(span.len() <= DIGITS && span[point] == b'.');
+
+    // Close the gap by moving everything in front of the point up a byte, the byte
+    // that comes free at the bottom is one more leading zero.
+    let v = u128::from_le_bytes(load_right_aligned(span));
+    let at = 8 * (DIGITS - span.len() + point) as u32;
+    let before = (1 << at) - 1;
+    let v = (v & !before & !(0xff << at)) | ((v & before) << 8) | b'0' as u128;
+
+    ladder(Simd::from_array(v.to_le_bytes()) - Simd::splat(b'0'))
+}
+
+/// The 16 ASCII digits of `n` (which must be below 10^16), zero padded on the left.
+///
+/// The multiply-add ladder run backwards: each lane is split into quotient and
+/// remainder, which go in the low and high half of the next narrower lanes.
+pub(crate) fn format_16(n: u64) -> [u8; DIGITS] {
+    debug_assert!(n < POW10_16);
+
+    let (high, low) = ((n / 100_000_000) as u32, (n % 100_000_000) as u32);
+    let quads =
+        Simd::<u32, 4>::from_array([high / 10_000, high % 10_000, low / 10_000, low % 10_000]);
+
+    // x / 100 == (x * 5243) >> 19 for all x < 43699, and the product fits a u32.
+    let q = (quads * Simd::splat(5243)) >> 19;
+    let v = q | ((quads - q * Simd::splat(100)) << 16);
+    let pairs = Simd::<u16, 8>::from_le_bytes(v.to_le_bytes());
+
+    // x / 10 == (x * 205) >> 11 for all x < 1029, and the product fits a u16.
+    let q = (pairs * Simd::splat(205)) >> 11;
+    let v = q | ((pairs - q * Simd::splat(10)) << 8);
+
+    (Simd::<u8, DIGITS>::from_le_bytes(v.to_le_bytes()) + Simd::splat(b'0')).to_array()
+}
@@ -0,0 +1,188 @@
+//! JSON structural indexing, i.e. simdjson's stage 1.
+//!
+//! Every 64-byte block is classified into `u64` masks of backslashes, quotes, structural
+//! characters (`{}[]:,`) and control characters. Odd-length backslash runs give the
+//! escaped bytes, the unescaped quotes prefix-XOR'd give the in-string mask (carried
+//! across blocks exactly like the quotes in the CSV scanner), and whatever structural
+//! characters are left outside strings, plus the opening quote of each string, are
+//! the index. With that a consumer can jump straight to the fields it wants.
+
+use std::{
+    fmt,
+    simd::{
+        Simd,
+        cmp::{SimdPartialEq, SimdPartialOrd},
+    },
+};
+
+use crate::{LOGICAL_LANES_, prefix_xor};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Bytes classified per step, one bit each in a `u64`.
+const BLOCK: usize = 64;
+
+const _: () = assert!(BLOCK.is_multiple_of(LANES), "a block must be whole vectors");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonError {
+    /// Offsets are reported as `u32`, so bigger documents have to be split up.
+    InputTooLarge(usize),
+    /// The input ended inside the string that opens at this offset.
+    UnclosedString { offset: usize },
+    /// An unescaped control character (below `0x20`) inside a string.
+    ControlCharInString { offset: usize },
+    /// The input isn't valid UTF-8 from this offset on.
+    InvalidUtf8 { offset: usize },
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InputTooLarge(len) => write!(f, "input too large to index ({len} bytes)"),
+            Self::UnclosedString { offset } => {
+                write!(f, "unclosed string starting at offset {offset}")
+            }
+            Self::ControlCharInString { offset } => {
+                write!(
+                    f,
+                    "unescaped control character in string at offset {offset}"
+                )
+            }
+            Self::InvalidUtf8 { offset } => write!(f, "invalid UTF-8 at offset {offset}"),
+        }
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// Offsets of every `{`, `}`, `[`, `]`, `:` and `,` outside strings, and of the opening
+/// `"` of every string, in order.
+///
+/// Only the lexical structure is checked (strings are closed, have no raw control
+/// characters, and the input is UTF-8), not that the structure is valid JSON.
+pub fn json_structural_indices(json: &[u8]) -> Result<Vec<u32>, JsonError> {
+    if u32::try_from(json.len()).is_err() {
+        return Err(JsonError::InputTooLarge(json.len()));
+    }
+
+    // Roughly one structural per 8 bytes is typical for log lines.
+    let mut indices = Vec::with_capacity(json.len() / 8);
+    let mut prev_escaped = 0u64;
+    let mut prev_in_string = 0u64;
+    let mut first_non_ascii = None;
+
+    for (block_index, block) in json.chunks(BLOCK).enumerate() {
+        let block_start = block_index * BLOCK;
+        let masks = classify(block);
+
+        let escaped = escaped_mask(masks.backslashes, &mut prev_escaped);
+        let quotes = masks.quotes & !escaped;
+
+        let in_string = prefix_xor(quotes) ^ prev_in_string;
+        prev_in_string = ((in_string as i64) >> 63) as u64;
+
+        // The closing quote isn't in `in_string` but the opening one is.
+        let control = masks.control & in_string & !quotes;
+        if control != 0 {
+            return Err(JsonError::ControlCharInString {
+                offset: block_start + control.trailing_zeros() as usize,
+            });
+        }
+
+        if masks.non_ascii != 0 && first_non_ascii.is_none() {
+            first_non_ascii = Some(block_start);
+        }
+
+        let mut structurals = (masks.operators & !in_string) | (quotes & in_string);
+        while structurals != 0 {
+            indices.push((block_start + structurals.trailing_zeros() as usize) as u32);
+            structurals &= structurals - 1;
+        }
+    }
+
+    if prev_in_string != 0 {
+        // The last index pushed is the opening quote of the unclosed string.
+        let offset = indices.last().map_or(0, |&i| i as usize);
+        return Err(JsonError::UnclosedString { offset });
+    }
+
+    // Everything before the first non-ASCII block is ASCII, so it's a char boundary.
+    if let Some(start) = first_non_ascii
+        && let Err(e) = std::str::from_utf8(&json[start..])
+    {
+        return Err(JsonError::InvalidUtf8 {
+            offset: start + e.valid_up_to(),
+        });
+    }
+
+    Ok(indices)
+}
+
+/// Per-block character class bitmasks.
+struct BlockMasks {
+    backslashes: u64,
+    quotes: u64,
+    operators: u64,
+    control: u64,
+    non_ascii: u64,
+}
+
+fn classify(block: &[u8]) -> BlockMasks {
+    let splat = Simd::<u8, LANES>::splat;
+
+    let mut masks = BlockMasks {
+        backslashes: 0,
+        quotes: 0,
+        operators: 0,
+        control: 0,
+        non_ascii: 0,
+    };
+
+    for (i, offset) in (0..BLOCK).step_by(LANES).enumerate() {
+        let chunk = Simd::<u8, LANES>::load_or_default(block.get(offset..).unwrap_or_default());
+        let shift = i * LANES;
+
+        let operators = chunk.simd_eq(splat(b'{'))
+            | chunk.simd_eq(splat(b'}'))
+            | chunk.simd_eq(splat(b'['))
+            | chunk.simd_eq(splat(b']'))
+            | chunk.simd_eq(splat(b':'))
+            | chunk.simd_eq(splat(b','));
+
+        masks.backslashes |= chunk.simd_eq(splat(b'\\')).to_bitmask() << shift;
+        masks.quotes |= chunk.simd_eq(splat(b'"')).to_bitmask() << shift;
+        masks.operators |= operators.to_bitmask() << shift;
+        masks.control |= chunk.simd_lt(splat(0x20)).to_bitmask() << shift;
+        masks.non_ascii |= chunk.simd_ge(splat(0x80)).to_bitmask() << shift;
+    }
+
+    // The zero padding of a short last block would read as control characters.
+    if block.len() < BLOCK {
+        masks.control &= (1 << block.len()) - 1;
+    }
+
+    masks
+}
+
+/// Mask of the bytes escaped by a backslash, i.e. those after an odd-length run of them.
+///
+/// This is simdjson's trick: adding the run starts on odd bits to the backslash mask
+/// carries through each run, and whether the carry lands on an odd or even bit says
+/// whether the run was odd or even length. `prev_escaped` carries the one bit that
+/// can escape into the next block.
+fn escaped_mask(backslashes: u64, prev_escaped: &mut u64) -> u64 {
+    const EVEN_BITS: u64 = 0x5555_5555_5555_5555;
+
+    // A backslash that's itself escaped doesn't start a run.
+    let backslashes = backslashes & !*prev_escaped;
+    let follows_escape = (backslashes << 1) | *prev_escaped;
+
+    let odd_sequence_starts = backslashes & !EVEN_BITS & !follows_escape;
+    let (sequences_starting_on_even_bits, overflow) =
+        odd_sequence_starts.overflowing_add(backslashes);
+    *prev_escaped = overflow as u64;
+
+    let invert_mask = sequences_starting_on_even_bits << 1;
+    (EVEN_BITS ^ invert_mask) & follows_escape
+}
@@ -0,0 +1,155 @@
+//! Levenshtein (edit) distance, for ranking fuzzy title matches.
+//!
+//! The fast path is Myers' bit-parallel algorithm: the whole DP column for a query of
+//! up to 64 bytes lives in one `u64`, so each candidate byte costs a handful of word
+//! ops instead of `m` cell updates. The batched version runs that same column update
+//! for `LANES` different candidates at once, one per `u64` lane.
+
+use std::simd::{
+    Select, Simd,
+    cmp::{SimdPartialEq, SimdPartialOrd},
+};
+
+use crate::LOGICAL_LANES_;
+
+/// One candidate per `u64` lane.
+const LANES: usize = LOGICAL_LANES_;
+
+/// Longest query the single-word Myers kernel handles, longer ones take the DP path.
+const MAX_MYERS_LEN: usize = u64::BITS as usize;
+
+/// Classic O(n·m) dynamic programming edit distance, kept around as the reference.
+pub fn levenshtein_scalar(a: &[u8], b: &[u8]) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &ca) in a.iter().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Bit-parallel (Myers) edit distance between `query` and `candidate`.
+///
+/// Falls back to [`levenshtein_scalar`] for queries longer than 64 bytes.
+pub fn levenshtein_myers(query: &[u8], candidate: &[u8]) -> usize {
+    if query.is_empty() {
+        return candidate.len();
+    }
+    if query.len() > MAX_MYERS_LEN {
+        return levenshtein_scalar(query, candidate);
+    }
+
+    let peq = pattern_masks(query);
+    let high_bit = 1u64 << (query.len() - 1);
+
+    let (mut pv, mut mv) = (u64::MAX, 0u64);
+    let mut score = query.len();
+
+    for &c in candidate {
+        let eq = peq[c as usize];
+        let xv = eq | mv;
+        let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+        let mut ph = mv | !(xh | pv);
+        let mut mh = pv & xh;
+
+        if ph & high_bit != 0 {
+            score += 1;
+        } else if mh & high_bit != 0 {
+            score -= 1;
+        }
+
+        // Row 0 of the DP grows by one per candidate byte, hence the carried-in 1.
+        ph = (ph << 1) | 1;
+        mh <<= 1;
+        pv = mh | !(xv | ph);
+        mv = ph & xv;
+    }
+
+    score
+}
+
+/// Edit distance from `query` to every candidate, `LANES` candidates at a time.
+///
+/// Each lane runs its own Myers column against a different candidate; the only
+/// per-lane scalar work is gathering the match mask for that lane's next byte.
+/// Lanes whose candidate has run out are masked off until the longest one finishes,
+/// so sorting candidates by length keeps the lanes busy.
+pub fn simd_levenshtein_batch(query: &[u8], candidates: &[&[u8]]) -> Vec<usize> {
+    if query.is_empty() {
+        return candidates.iter().map(|c| c.len()).collect();
+    }
+    if query.len() > MAX_MYERS_LEN {
+        return candidates
+            .iter()
+            .map(|c| levenshtein_scalar(query, c))
+            .collect();
+    }
+
+    let peq = pattern_masks(query);
+    let mut distances = Vec::with_capacity(candidates.len());
+
+    for group in candidates.chunks(LANES) {
+        let longest = group.iter().map(|c| c.len()).max().unwrap_or(0);
+        let lens = Simd::<u64, LANES>::from_array(std::array::from_fn(|lane| {
+            group.get(lane).map_or(0, |c| c.len() as u64)
+        }));
+
+        let zero = Simd::<u64, LANES>::splat(0);
+        let one = Simd::<u64, LANES>::splat(1);
+        let high_bit = Simd::<u64, LANES>::splat(1 << (query.len() - 1));
+
+        let mut pv = Simd::<u64, LANES>::splat(u64::MAX);
+        let mut mv = zero;
+        let mut score = Simd::<u64, LANES>::splat(query.len() as u64);
+
+        for t in 0..longest {
+            let eq = Simd::from_array(std::array::from_fn(|lane| {
+                group
+                    .get(lane)
+                    .and_then(|c| c.get(t))
+                    .map_or(0, |&c| peq[c as usize])
+            }));
+            let active = Simd::splat(t as u64).simd_lt(lens);
+
+            let xv = eq | mv;
+            let xh = (((eq & pv) + pv) ^ pv) | eq;
+            let ph = mv | !(xh | pv);
+            let mh = pv & xh;
+
+            let up = active & (ph & high_bit).simd_ne(zero);
+            let down = active & !up & (mh & high_bit).simd_ne(zero);
+            score = score + up.select(one, zero) - down.select(one, zero);
+
+            let ph = (ph << 1) | one;
+            let mh = mh << 1;
+            pv = mh | !(xv | ph);
+            mv = ph & xv;
+        }
+
+        distances.extend(
+            score
+                .to_array()
+                .iter()
+                .take(group.len())
+                .map(|&d| d as usize),
+        );
+    }
+
+    distances
+}
+
+/// For each byte value, a bitmask of the positions where it occurs in `query`.
+fn pattern_masks(query: &[u8]) -> [u64; 256] {
+    let mut peq = [0u64; 256];
+    for (i, &c) in query.iter().enumerate() {
+        peq[c as usize] |= 1 << i;
+    }
+    peq
+}
@@ -1,22 +1,94 @@
 #![feature(portable_simd)]
 use std::{
     f32,
-    simd::{
-        Simd,
-        cmp::SimdPartialEq,
-        num::{SimdFloat, SimdUint},
-    },
+    simd::{Mask, Simd, cmp::SimdPartialEq, num::SimdFloat},
 };
 
 use rayon::iter::IndexedParallelIterator;
 use rayon::prelude::*;
 
+mod approx;
+mod base64;
+mod bitap;
+mod blur;
+mod compare;
+mod csv;
+mod edges;
+mod escape;
+mod finder;
+mod float;
+mod greyscale;
+mod hex;
+mod hsv;
+#[cfg(feature = "image")]
+mod image_ext;
+mod ints;
+mod json;
+mod levenshtein;
+mod pixel;
+mod text;
+mod title_index;
+mod two_way;
+mod ycbcr;
+pub use approx::{ApproxMatch, simd_find_approx};
+pub use base64::{
+    Base64Alphabet, Base64Config, Base64Error, simd_base64_decode, simd_base64_encode,
+};
+pub use bitap::{PatternError, ShiftOr};
+pub use blur::{
+    Blur, EdgeMode, GaussianKernel, KernelError, MAX_KERNEL_SIZE, simd_blur_luma, simd_blur_rgba,
+};
+pub use compare::{common_prefix_len, simd_cmp, simd_eq};
+pub use csv::{CsvRecord, CsvRecords, csv_unescape, simd_csv_records};
+pub use edges::{GradientOperator, Gradients, simd_gradients};
+pub use escape::{EscapeSet, simd_escape_json, simd_find_escape};
+pub use finder::{FindIter, Finder};
+pub use float::{
+    FloatParseError, simd_format_f32, simd_format_f64, simd_parse_f32, simd_parse_f64,
+};
+pub use greyscale::{
+    LumaStandard, pixels_to_luma_into, pixels_to_luma_simd, rgba_to_gray_linear,
+    rgba_to_gray_simd_u8, rgba_to_luma_into, rgba_to_luma_into_scalar_gather, rgba_to_luma_simd,
+};
+pub use hex::{HexError, simd_hex_decode, simd_hex_encode};
+pub use hsv::{
+    simd_adjust_hue_saturation, simd_hsl_to_rgba, simd_hsv_to_rgba, simd_rgba_to_hsl,
+    simd_rgba_to_hsv,
+};
+#[cfg(feature = "image")]
+pub use image_ext::SimdImageExt;
+pub use ints::{
+    IntParseError, parse_ints_in_lines, simd_parse_i64, simd_parse_u32, simd_parse_u64,
+};
+pub use json::{JsonError, json_structural_indices};
+pub use levenshtein::{levenshtein_myers, levenshtein_scalar, simd_levenshtein_batch};
+pub use pixel::{PixelLayout, simd_convert_layout, simd_convert_layout_into};
+pub use text::{simd_normalize_newlines, simd_trim_ascii, simd_trim_end_lines};
+pub use title_index::TitleIndex;
+use two_way::TwoWay;
+pub use ycbcr::{
+    ChromaSubsampling, YcbcrFormat, YcbcrImage, YcbcrMatrix, YcbcrRange, simd_rgb_to_ycbcr,
+    simd_ycbcr_to_rgb,
+};
+
 /// NOTE: the build.rs will set this for you assuming FLOATS
 /// so LOGICAL_LANES, for example might be 4, meaning 4 * f32 = 128
 /// if you have AVX12 registers that go up to 512bits you might see 16 * f32 = 512
 /// ... and so on.
 const LOGICAL_LANES_: usize = 4; // Auto-detected for x86_64-unknown-linux-gnu
 
+/// Running XOR of all the bits at or below each position, i.e. "inside quotes" given
+/// the quote positions. Shared by the CSV and JSON scanners.
+fn prefix_xor(mut x: u64) -> u64 {
+    x ^= x << 1;
+    x ^= x << 2;
+    x ^= x << 4;
+    x ^= x << 8;
+    x ^= x << 16;
+    x ^= x << 32;
+    x
+}
+
 /// prettly-formant nanos from our std::instant timing.
 pub fn format_ns(ns: f64) -> String {
     if ns >= 1_000_000_000.0 {
@@ -78,7 +150,6 @@ pub fn find_min_max_scalar(data: &[f32]) -> (f32, f32) {
 
 // patterns in strings
 pub fn simd_contains_pattern(haystack: &[u8], needle: &[u8]) -> bool {
-    const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
     if needle.is_empty() {
         return true;
     }
@@ -89,38 +160,74 @@ pub fn simd_contains_pattern(haystack: &[u8], needle: &[u8]) -> bool {
         return simd_contains_byte(haystack, needle[0]);
     }
 
-    // Use SIMD to quickly find first character candidates
+    simd_find_hybrid(haystack, needle).is_some()
+}
+
+/// Once candidate verification has done this many times more byte compares than
+/// we've scanned, the prefilter is losing and we hand over to Two-Way.
+const VERIFY_BUDGET_FACTOR: usize = 8;
+
+/// SIMD first-byte prefilter with a Two-Way fallback.
+///
+/// In the common case (rare first byte) this is just the SIMD scan plus the odd
+/// `memcmp`. On adversarial inputs like `"aaa…ab"` in `"aaa…a"` every lane is a
+/// candidate and naive verification goes O(n·m), so we track the verification
+/// work and switch to [`TwoWay`] once it outruns the scan, which keeps us O(n + m).
+fn simd_find_hybrid(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+    debug_assert!(needle.len() >= 2 && needle.len() <= haystack.len());
+
     let first_char = needle[0];
     let first_char_vec = Simd::<u8, LANES>::splat(first_char);
+    let last_start = haystack.len() - needle.len();
+
+    let mut work = 0;
+    let mut verify = |pos: usize| -> Result<bool, usize> {
+        if work > VERIFY_BUDGET_FACTOR * (pos + needle.len()) {
+            return Err(pos);
+        }
+        work += needle.len();
+        Ok(simd_eq(&haystack[pos..pos + needle.len()], needle))
+    };
 
     let mut i = 0;
-    while i + LANES <= haystack.len() {
-        let chunk = Simd::<u8, LANES>::from_slice(&haystack[i..i + LANES]);
-        let mask = chunk.simd_eq(first_char_vec);
-
-        if mask.any() {
-            // Check each potential match position
-            let mask_array = mask.to_array();
-            for j in 0..LANES {
-                if mask_array[j]
-                    && i + j + needle.len() <= haystack.len()
-                    && &haystack[i + j..i + j + needle.len()] == needle
-                {
-                    return true;
+    let handover = 'scan: {
+        while i + LANES <= haystack.len() {
+            let chunk = Simd::<u8, LANES>::from_slice(&haystack[i..i + LANES]);
+            let mut candidates = chunk.simd_eq(first_char_vec).to_bitmask();
+
+            while candidates != 0 {
+                let pos = i + candidates.trailing_zeros() as usize;
+                if pos > last_start {
+                    return None;
                 }
+                match verify(pos) {
+                    Ok(true) => return Some(pos),
+                    Ok(false) => {}
+                    Err(pos) => break 'scan pos,
+                }
+                candidates &= candidates - 1;
             }
+            i += LANES;
         }
-        i += LANES;
-    }
 
-    // Handle remaining bytes
-    for pos in i..=haystack.len() - needle.len() {
-        if haystack[pos] == first_char && &haystack[pos..pos + needle.len()] == needle {
-            return true;
+        // Handle remaining bytes
+        for (pos, _) in haystack[..=last_start]
+            .iter()
+            .enumerate()
+            .skip(i)
+            .filter(|&(_, &b)| b == first_char)
+        {
+            match verify(pos) {
+                Ok(true) => return Some(pos),
+                Ok(false) => {}
+                Err(pos) => break 'scan pos,
+            }
         }
-    }
+        return None;
+    };
 
-    false
+    TwoWay::new(needle).find_from(haystack, needle, handover)
 }
 
 fn simd_contains_byte(haystack: &[u8], target: u8) -> bool {
@@ -142,8 +249,6 @@ fn simd_contains_byte(haystack: &[u8], target: u8) -> bool {
 }
 
 pub fn simd_find_str(haystack: &str, needle: &str) -> Option<usize> {
-    const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
-
     if needle.is_empty() {
         return Some(0);
     }
@@ -159,56 +264,29 @@ pub fn simd_find_str(haystack: &str, needle: &str) -> Option<usize> {
         return simd_find_byte(haystack_bytes, needle_bytes[0]);
     }
 
-    // Use SIMD to quickly find first character candidates
-    let first_char = needle_bytes[0];
-    let first_char_vec = Simd::<u8, LANES>::splat(first_char);
-
-    let mut i = 0;
-    while i + LANES <= haystack_bytes.len() {
-        let chunk = Simd::<u8, LANES>::from_slice(&haystack_bytes[i..i + LANES]);
-        let mask = chunk.simd_eq(first_char_vec);
-
-        if mask.any() {
-            // Check each potential match position
-            let mask_array = mask.to_array();
-            for j in 0..LANES {
-                if mask_array[j]
-                    && i + j + needle_bytes.len() <= haystack_bytes.len()
-                    && &haystack_bytes[i + j..i + j + needle_bytes.len()] == needle_bytes
-                {
-                    return Some(i + j);
-                }
-            }
-        }
-        i += LANES;
-    }
-
-    // Handle remaining bytes
-    (i..=haystack_bytes.len() - needle_bytes.len()).find(|&pos| {
-        haystack_bytes[pos] == first_char
-            && &haystack_bytes[pos..pos + needle_bytes.len()] == needle_bytes
-    });
-
-    None
+    simd_find_hybrid(haystack_bytes, needle_bytes)
 }
 
 fn simd_find_byte(haystack: &[u8], target: u8) -> Option<usize> {
-    const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+    let target_vec = Simd::splat(target);
+    simd_find_by(haystack, |chunk| chunk.simd_eq(target_vec), |b| b == target)
+}
 
-    let target_vec = Simd::<u8, LANES>::splat(target);
+/// First position where `matches` finds a lane, checking `LANES` bytes at a time,
+/// with `matches_scalar` (the same test, one byte) for the tail.
+#[inline(always)]
+fn simd_find_by(
+    haystack: &[u8],
+    matches: impl Fn(Simd<u8, { LOGICAL_LANES_ * 4 }>) -> Mask<i8, { LOGICAL_LANES_ * 4 }>,
+    matches_scalar: impl Fn(u8) -> bool,
+) -> Option<usize> {
+    const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
 
     let mut i = 0;
     while i + LANES <= haystack.len() {
         let chunk = Simd::<u8, LANES>::from_slice(&haystack[i..i + LANES]);
-        let mask = chunk.simd_eq(target_vec);
-
-        if mask.any() {
-            let mask_array = mask.to_array();
-            for j in 0..LANES {
-                if mask_array[j] {
-                    return Some(i + j);
-                }
-            }
+        if let Some(j) = matches(chunk).first_set() {
+            return Some(i + j);
         }
         i += LANES;
     }
@@ -216,56 +294,31 @@ fn simd_find_byte(haystack: &[u8], target: u8) -> Option<usize> {
     // Check remaining
     haystack[i..]
         .iter()
-        .position(|&b| b == target)
+        .position(|&b| matches_scalar(b))
         .map(|pos| i + pos)
 }
 
-// greyscale an img:
-
-/// Convert RGBA (`[u8;4]`) to grayscale (`[u8;3]`) using SIMD.
-pub fn rgba_to_gray_simd_u8(rgba: &[[u8; 4]]) -> Vec<[u8; 3]> {
-    const LANES: usize = LOGICAL_LANES_ * 4;
-    let mut output = Vec::with_capacity(rgba.len());
-
-    // Weights scaled to fixed-point precision (0.2126 ≈ 54/255, etc.)
-    let r_weight = Simd::<u16, LANES>::splat(54); // 0.2126 * 255 ≈ 54
-    let g_weight = Simd::<u16, LANES>::splat(182); // 0.7152 * 255 ≈ 182
-    let b_weight = Simd::<u16, LANES>::splat(18); // 0.0722 * 255 ≈ 18
-
-    for chunk in rgba.chunks_exact(LANES) {
-        let (mut r, mut g, mut b) = ([0u8; LANES], [0u8; LANES], [0u8; LANES]);
-
-        // Extract R, G, B components (ignore alpha)
-        for (i, &[ri, gi, bi, _]) in chunk.iter().enumerate() {
-            r[i] = ri;
-            g[i] = gi;
-            b[i] = bi;
-        }
-
-        // Convert u8 -> u16 to avoid overflow during multiplication
-        let r_simd = Simd::from_array(r).cast::<u16>();
-        let g_simd = Simd::from_array(g).cast::<u16>();
-        let b_simd = Simd::from_array(b).cast::<u16>();
-
-        // Compute luminance: (54*R + 182*G + 18*B) >> 8 (equivalent to /255)
-        let gray = (r_simd * r_weight + g_simd * g_weight + b_simd * b_weight) >> 8;
-        let gray_u8 = gray.cast::<u8>().to_array();
+/// Like [`simd_find_by`], but the last position, scanning back from the end.
+#[inline(always)]
+fn simd_rfind_by(
+    haystack: &[u8],
+    matches: impl Fn(Simd<u8, { LOGICAL_LANES_ * 4 }>) -> Mask<i8, { LOGICAL_LANES_ * 4 }>,
+    matches_scalar: impl Fn(u8) -> bool,
+) -> Option<usize> {
+    const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
 
-        // Store as RGB (repeating luminance)
-        for &l in gray_u8.iter() {
-            output.push([l, l, l]);
+    let mut end = haystack.len();
+    while end >= LANES {
+        let chunk = Simd::<u8, LANES>::from_slice(&haystack[end - LANES..end]);
+        let found = matches(chunk).to_bitmask();
+        if found != 0 {
+            return Some(end - LANES + 63 - found.leading_zeros() as usize);
         }
+        end -= LANES;
     }
 
-    // Handle remaining pixels, although in the land of images, that come from cameras
-    // you're going to find powers of two (most of the time), so this code will likely do little (if anything)
-    // in most applications.
-    for &[r, g, b, _] in rgba.chunks_exact(LANES).remainder() {
-        let l = ((54 * r as u16 + 182 * g as u16 + 18 * b as u16) >> 8) as u8;
-        output.push([l, l, l]);
-    }
-
-    output
+    // Check remaining
+    haystack[..end].iter().rposition(|&b| matches_scalar(b))
 }
 
 pub fn simd_histogram_single(data: &[u8], histogram: &mut [u32; 256]) {
@@ -0,0 +1,290 @@
+//! Interleaved pixel layouts, and converting between them `LANES` pixels at a time.
+//!
+//! Each layout is a type with its channel offsets as constants, so the per-layout
+//! shuffles are worked out at compile time: [`Channel`] picks one channel out of a
+//! vector of pixels (the greyscale kernel uses this), [`Convert`] reorders a whole
+//! vector from one layout to another. [`PixelLayout`] is the runtime side, matched
+//! on once per call to get to the right instance.
+
+use std::{
+    fmt,
+    marker::PhantomData,
+    simd::{Simd, SimdElement, Swizzle},
+};
+
+use crate::LOGICAL_LANES_;
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// The order of the channels in each pixel, one byte per channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PixelLayout {
+    /// Packed 24-bit RGB, as most image files decode to.
+    Rgb,
+    Bgr,
+    Rgba,
+    /// What most cameras and Windows/Direct3D surfaces hand over.
+    Bgra,
+    Argb,
+}
+
+impl PixelLayout {
+    pub fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Rgb | Self::Bgr => 3,
+            Self::Rgba | Self::Bgra | Self::Argb => 4,
+        }
+    }
+
+    pub fn has_alpha(self) -> bool {
+        self.bytes_per_pixel() == 4
+    }
+}
+
+impl fmt::Display for PixelLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Rgb => "RGB",
+            Self::Bgr => "BGR",
+            Self::Rgba => "RGBA",
+            Self::Bgra => "BGRA",
+            Self::Argb => "ARGB",
+        };
+        f.pad(name)
+    }
+}
+
+/// A [`PixelLayout`] at the type level.
+pub(crate) trait Layout {
+    const BYTES_PER_PIXEL: usize;
+    /// Offsets of R, G, B and A within a pixel, `None` for no alpha.
+    const CHANNELS: [Option<usize>; 4];
+}
+
+pub(crate) struct Rgb;
+pub(crate) struct Bgr;
+pub(crate) struct Rgba;
+pub(crate) struct Bgra;
+pub(crate) struct Argb;
+
+impl Layout for Rgb {
+    const BYTES_PER_PIXEL: usize = 3;
+    const CHANNELS: [Option<usize>; 4] = [Some(0), Some(1), Some(2), None];
+}
+
+impl Layout for Bgr {
+    const BYTES_PER_PIXEL: usize = 3;
+    const CHANNELS: [Option<usize>; 4] = [Some(2), Some(1), Some(0), None];
+}
+
+impl Layout for Rgba {
+    const BYTES_PER_PIXEL: usize = 4;
+    const CHANNELS: [Option<usize>; 4] = [Some(0), Some(1), Some(2), Some(3)];
+}
+
+impl Layout for Bgra {
+    const BYTES_PER_PIXEL: usize = 4;
+    const CHANNELS: [Option<usize>; 4] = [Some(2), Some(1), Some(0), Some(3)];
+}
+
+impl Layout for Argb {
+    const BYTES_PER_PIXEL: usize = 4;
+    const CHANNELS: [Option<usize>; 4] = [Some(1), Some(2), Some(3), Some(0)];
+}
+
+pub(crate) const R: usize = 0;
+pub(crate) const G: usize = 1;
+pub(crate) const B: usize = 2;
+pub(crate) const A: usize = 3;
+
+/// Channel `C` (one of [`R`], [`G`], [`B`], [`A`]) of each pixel in a vector of `L` pixels.
+pub(crate) struct Channel<L, const C: usize>(PhantomData<L>);
+
+impl<L: Layout, const C: usize, const N: usize> Swizzle<N> for Channel<L, C> {
+    const INDEX: [usize; N] = {
+        let Some(offset) = L::CHANNELS[C] else {
+            panic!("no such channel in this layout");
+        };
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i * L::BYTES_PER_PIXEL + offset;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// A vector of `M` bytes of `S` pixels reordered as `D` pixels. Use it with
+/// `concat_swizzle` and a second vector of 255s, which is where an alpha channel
+/// missing from `S` comes from.
+pub(crate) struct Convert<S, D, const M: usize>(PhantomData<(S, D)>);
+
+impl<S: Layout, D: Layout, const M: usize, const N: usize> Swizzle<N> for Convert<S, D, M> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            let pixel = i / D::BYTES_PER_PIXEL;
+            let channel = channel_at::<D>(i % D::BYTES_PER_PIXEL);
+            index[i] = match S::CHANNELS[channel] {
+                Some(offset) => pixel * S::BYTES_PER_PIXEL + offset,
+                None => M,
+            };
+            i += 1;
+        }
+        index
+    };
+}
+
+/// `R`, `G`, `B` and `A` vectors of `N / L::BYTES_PER_PIXEL` lanes each, one after
+/// the other, reordered as `L` pixels. [`interleave`] does the concatenating.
+struct Interleave<L>(PhantomData<L>);
+
+impl<L: Layout, const N: usize> Swizzle<N> for Interleave<L> {
+    const INDEX: [usize; N] = {
+        let pixels = N / L::BYTES_PER_PIXEL;
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            let channel = channel_at::<L>(i % L::BYTES_PER_PIXEL);
+            index[i] = channel * pixels + i / L::BYTES_PER_PIXEL;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// Two vectors end to end.
+struct Concat;
+
+impl<const N: usize> Swizzle<N> for Concat {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = i;
+            i += 1;
+        }
+        index
+    };
+}
+
+/// `LANES` pixels from one vector per channel, as the `W` samples of `L` pixels
+/// (alpha is dropped if `L` has none).
+#[inline(always)]
+pub(crate) fn interleave<T: SimdElement, L: Layout, const W: usize>(
+    r: Simd<T, LANES>,
+    g: Simd<T, LANES>,
+    b: Simd<T, LANES>,
+    a: Simd<T, LANES>,
+) -> Simd<T, W> {
+    let rg: Simd<T, { 2 * LANES }> = Concat::concat_swizzle(r, g);
+    let ba: Simd<T, { 2 * LANES }> = Concat::concat_swizzle(b, a);
+    let planar: Simd<T, { 4 * LANES }> = Concat::concat_swizzle(rg, ba);
+    Interleave::<L>::swizzle(planar)
+}
+
+/// Which channel is at `offset` in an `L` pixel.
+const fn channel_at<L: Layout>(offset: usize) -> usize {
+    let mut channel = 0;
+    while channel < 4 {
+        if let Some(at) = L::CHANNELS[channel]
+            && at == offset
+        {
+            return channel;
+        }
+        channel += 1;
+    }
+    panic!("offset past the end of the pixel");
+}
+
+/// `pixels` in `from` layout converted to the `to` layout. Alpha is 255 when `from`
+/// has none, and dropped when `to` has none.
+///
+/// # Panics
+/// If `pixels` isn't a whole number of `from` pixels.
+pub fn simd_convert_layout(pixels: &[u8], from: PixelLayout, to: PixelLayout) -> Vec<u8> {
+    let count = pixel_count(pixels, from);
+    let mut output = vec![0; count * to.bytes_per_pixel()];
+    simd_convert_layout_into(pixels, from, &mut output, to);
+    output
+}
+
+/// [`simd_convert_layout`] into a buffer you already have.
+///
+/// # Panics
+/// If `pixels` isn't a whole number of `from` pixels, or `output` isn't the same
+/// number of `to` pixels.
+pub fn simd_convert_layout_into(
+    pixels: &[u8],
+    from: PixelLayout,
+    output: &mut [u8],
+    to: PixelLayout,
+) {
+    let count = pixel_count(pixels, from);
+    assert_eq!(
+        count * to.bytes_per_pixel(),
+        output.len(),
+        "output needs room for {count} {to} pixels"
+    );
+
+    match from {
+        PixelLayout::Rgb => convert_from::<Rgb, { 3 * LANES }>(pixels, output, to),
+        PixelLayout::Bgr => convert_from::<Bgr, { 3 * LANES }>(pixels, output, to),
+        PixelLayout::Rgba => convert_from::<Rgba, { 4 * LANES }>(pixels, output, to),
+        PixelLayout::Bgra => convert_from::<Bgra, { 4 * LANES }>(pixels, output, to),
+        PixelLayout::Argb => convert_from::<Argb, { 4 * LANES }>(pixels, output, to),
+    }
+}
+
+/// Number of `layout` pixels in `pixels`.
+pub(crate) fn pixel_count(pixels: &[u8], layout: PixelLayout) -> usize {
+    let bpp = layout.bytes_per_pixel();
+    assert!(
+        pixels.len().is_multiple_of(bpp),
+        "{} bytes isn't a whole number of {layout} pixels",
+        pixels.len()
+    );
+    pixels.len() / bpp
+}
+
+fn convert_from<S: Layout, const SW: usize>(pixels: &[u8], output: &mut [u8], to: PixelLayout) {
+    match to {
+        PixelLayout::Rgb => convert::<S, Rgb, SW, { 3 * LANES }>(pixels, output),
+        PixelLayout::Bgr => convert::<S, Bgr, SW, { 3 * LANES }>(pixels, output),
+        PixelLayout::Rgba => convert::<S, Rgba, SW, { 4 * LANES }>(pixels, output),
+        PixelLayout::Bgra => convert::<S, Bgra, SW, { 4 * LANES }>(pixels, output),
+        PixelLayout::Argb => convert::<S, Argb, SW, { 4 * LANES }>(pixels, output),
+    }
+}
+
+/// `LANES` pixels at a time, `SW` and `DW` being that many `S` and `D` pixels in bytes.
+fn convert<S: Layout, D: Layout, const SW: usize, const DW: usize>(
+    pixels: &[u8],
+    output: &mut [u8],
+) {
+    let opaque = Simd::<u8, SW>::splat(255);
+
+    let chunks = pixels.chunks_exact(SW);
+    let remainder = chunks.remainder();
+    let mut out_chunks = output.chunks_exact_mut(DW);
+
+    for (chunk, out) in chunks.zip(&mut out_chunks) {
+        let converted: Simd<u8, DW> =
+            Convert::<S, D, SW>::concat_swizzle(Simd::from_slice(chunk), opaque);
+        converted.copy_to_slice(out);
+    }
+
+    for (pixel, out) in remainder.chunks_exact(S::BYTES_PER_PIXEL).zip(
+        out_chunks
+            .into_remainder()
+            .chunks_exact_mut(D::BYTES_PER_PIXEL),
+    ) {
+        for channel in [R, G, B, A] {
+            if let Some(to) = D::CHANNELS[channel] {
+                out[to] = S::CHANNELS[channel].map_or(255, |from| pixel[from]);
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Whole-buffer line ending and whitespace cleanup.
+//!
+//! Text from Windows ends its lines with `\r\n` (and the odd old Mac file with a bare
+//! `\r`), which leaves a `\r` on the end of every line for code that splits on `\n`.
+//! These find the few places that need changing with the same SIMD scans as
+//! [`simd_find_str`](crate::simd_find_str), copy everything in between over in bulk,
+//! and borrow the input when there's nothing to do.
+
+use std::{
+    borrow::Cow,
+    simd::{
+        Mask, Simd,
+        cmp::{SimdPartialEq, SimdPartialOrd},
+    },
+};
+
+use crate::{LOGICAL_LANES_, simd_find_by, simd_find_byte, simd_rfind_by};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// `text` with every `\r\n` and lone `\r` turned into `\n`.
+pub fn simd_normalize_newlines(text: &str) -> Cow<'_, str> {
+    let bytes = text.as_bytes();
+    let Some(first) = simd_find_byte(bytes, b'\r') else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut out = String::with_capacity(text.len());
+    let mut start = 0;
+    let mut hit = Some(first);
+
+    while let Some(offset) = hit {
+        let pos = start + offset;
+        // `\r` is ASCII, so `pos` is always a char boundary.
+        out.push_str(&text[start..pos]);
+        out.push('\n');
+
+        start = if bytes.get(pos + 1) == Some(&b'\n') {
+            pos + 2
+        } else {
+            pos + 1
+        };
+        hit = simd_find_byte(&bytes[start..], b'\r');
+    }
+
+    out.push_str(&text[start..]);
+    Cow::Owned(out)
+}
+
+/// `text` without leading and trailing ASCII whitespace, like `str::trim_ascii` but
+/// checking `LANES` bytes at a time, for when there's a lot of it (padded fixed-width
+/// exports, say).
+pub fn simd_trim_ascii(text: &str) -> &str {
+    let bytes = text.as_bytes();
+    let Some(start) = simd_find_by(
+        bytes,
+        |chunk| !is_whitespace(chunk),
+        |b| !b.is_ascii_whitespace(),
+    ) else {
+        return "";
+    };
+    let end = simd_rfind_by(
+        bytes,
+        |chunk| !is_whitespace(chunk),
+        |b| !b.is_ascii_whitespace(),
+    )
+    .map_or(start, |last| last + 1);
+
+    // Both ends are next to ASCII bytes, so they're char boundaries.
+    &text[start..end]
+}
+
+/// `text` with the trailing whitespace of every line removed, `\r`s included, so
+/// this also turns `\r\n` into `\n`.
+pub fn simd_trim_end_lines(text: &str) -> Cow<'_, str> {
+    let bytes = text.as_bytes();
+
+    // Whitespace right before a newline, or at the very end, is the only thing that
+    // needs changing. Finding the newlines is the SIMD part, lines are checked from
+    // their end so only the trailing run is looked at.
+    let needs_trim = |line: &[u8]| line.last().is_some_and(|b| b.is_ascii_whitespace());
+
+    let Some(first) = Lines::new(bytes).position(|line| needs_trim(&bytes[line])) else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut out = String::with_capacity(text.len());
+    for (i, line) in Lines::new(bytes).enumerate() {
+        let end = if i >= first {
+            line.start + bytes[line.clone()].trim_ascii_end().len()
+        } else {
+            line.end
+        };
+        // Trimming only removes ASCII, so `end` is a char boundary.
+        out.push_str(&text[line.start..end]);
+        if line.end < bytes.len() {
+            out.push('\n');
+        }
+    }
+
+    Cow::Owned(out)
+}
+
+/// Byte ranges of the lines of `bytes`, without their `\n`.
+pub(crate) struct Lines<'a> {
+    bytes: &'a [u8],
+    start: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, start: 0 }
+    }
+}
+
+impl Iterator for Lines<'_> {
+    type Item = std::ops::Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start > self.bytes.len() {
+            return None;
+        }
+
+        let start = self.start;
+        let end =
+            simd_find_byte(&self.bytes[start..], b'\n').map_or(self.bytes.len(), |i| start + i);
+        self.start = end + 1;
+        Some(start..end)
+    }
+}
+
+/// Lanes holding `' '`, `\t`, `\n`, `\x0c` or `\r`, i.e. `u8::is_ascii_whitespace`.
+#[inline(always)]
+fn is_whitespace(chunk: Simd<u8, LANES>) -> Mask<i8, LANES> {
+    // \t \n are 9 and 10, \x0c \r are 12 and 13: one range check minus the \x0b hole.
+    let control = (chunk - Simd::splat(b'\t')).simd_le(Simd::splat(b'\r' - b'\t'))
+        & chunk.simd_ne(Simd::splat(0x0b));
+    control | chunk.simd_eq(Simd::splat(b' '))
+}
@@ -0,0 +1,71 @@
+//! A sorted, deduplicated index over a newline separated list of titles.
+//!
+//! Scanning the whole title dump with [`simd_find_str`](crate::simd_find_str) is fast,
+//! but it's still the whole dump every time. Sorting the titles once turns exact and
+//! prefix lookups into binary searches, where every probe is a [`simd_cmp`] of the
+//! query against a title.
+
+use rayon::slice::ParallelSliceMut;
+
+use crate::{common_prefix_len, simd_cmp, simd_eq, text::Lines};
+
+/// Titles borrowed from the text they were split out of, sorted byte-wise.
+#[derive(Debug, Clone, Default)]
+pub struct TitleIndex<'a> {
+    titles: Vec<&'a [u8]>,
+}
+
+impl<'a> TitleIndex<'a> {
+    /// Index every line of `text` (a trailing `\r` is dropped, empty lines skipped).
+    pub fn new(text: &'a [u8]) -> Self {
+        let mut titles: Vec<&[u8]> = Lines::new(text)
+            .map(|line| {
+                let line = &text[line];
+                line.strip_suffix(b"\r").unwrap_or(line)
+            })
+            .filter(|title| !title.is_empty())
+            .collect();
+
+        titles.par_sort_unstable_by(|a, b| simd_cmp(a, b));
+        titles.dedup_by(|a, b| simd_eq(a, b));
+
+        Self { titles }
+    }
+
+    pub fn len(&self) -> usize {
+        self.titles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.titles.is_empty()
+    }
+
+    /// All the titles, in order.
+    pub fn titles(&self) -> &[&'a [u8]] {
+        &self.titles
+    }
+
+    /// Position of `title` in [`titles`](Self::titles), if it's there.
+    pub fn position(&self, title: &[u8]) -> Option<usize> {
+        self.titles
+            .binary_search_by(|probe| simd_cmp(probe, title))
+            .ok()
+    }
+
+    pub fn contains(&self, title: &[u8]) -> bool {
+        self.position(title).is_some()
+    }
+
+    /// Every title starting with `prefix`, in order.
+    ///
+    /// They're contiguous in the sorted order: from the first title that isn't less
+    /// than `prefix`, up to the first one after that which doesn't start with it.
+    pub fn with_prefix(&self, prefix: &[u8]) -> &[&'a [u8]] {
+        let start = self
+            .titles
+            .partition_point(|title| simd_cmp(title, prefix).is_lt());
+        let len = self.titles[start..]
+            .partition_point(|title| common_prefix_len(title, prefix) == prefix.len());
+        &self.titles[start..start + len]
+    }
+}
@@ -0,0 +1,152 @@
+//! Crochemore-Perrin Two-Way string matching.
+//!
+//! This is the fallback the SIMD searchers hand over to when candidate
+//! verification starts costing more than the scan itself (think `"aaa…ab"` in
+//! `"aaa…a"`). It needs O(1) extra space and never compares more than `2n`
+//! haystack bytes, so the hybrid search stays linear no matter the input.
+
+/// Precomputed critical factorisation of a needle.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TwoWay {
+    /// Split point of the critical factorisation `needle = u · v`.
+    crit_pos: usize,
+    /// Period of the needle, or a safe shift when the needle is not periodic.
+    period: usize,
+    /// When `u` is a suffix of `v[..period]` we can remember how much of the
+    /// needle already matched after a period-sized shift.
+    short_period: bool,
+}
+
+impl TwoWay {
+    pub(crate) fn new(needle: &[u8]) -> Self {
+        if needle.is_empty() {
+            return Self {
+                crit_pos: 0,
+                period: 1,
+                short_period: false,
+            };
+        }
+
+        let (crit_pos_lt, period_lt) = maximal_suffix(needle, false);
+        let (crit_pos_gt, period_gt) = maximal_suffix(needle, true);
+        let (crit_pos, period) = if crit_pos_lt > crit_pos_gt {
+            (crit_pos_lt, period_lt)
+        } else {
+            (crit_pos_gt, period_gt)
+        };
+
+        if period + crit_pos <= needle.len()
+            && needle[..crit_pos] == needle[period..period + crit_pos]
+        {
+            Self {
+                crit_pos,
+                period,
+                short_period: true,
+            }
+        } else {
+            Self {
+                crit_pos,
+                period: crit_pos.max(needle.len() - crit_pos) + 1,
+                short_period: false,
+            }
+        }
+    }
+
+    /// Find the first occurrence of `needle` in `haystack` at or after `start`.
+    ///
+    /// `needle` must be the same one this `TwoWay` was built from.
+    pub(crate) fn find_from(&self, haystack: &[u8], needle: &[u8], start: usize) -> Option<usize> {
+        self.search(needle, haystack.len(), |i| haystack[i], start)
+    }
+
+    /// Find the last occurrence of a needle ending at or before `end`.
+    ///
+    /// This runs the forward algorithm over `haystack[..end]` read back to front,
+    /// so `needle_rev` must be the *reversed* needle this `TwoWay` was built from.
+    pub(crate) fn rfind_to(&self, haystack: &[u8], needle_rev: &[u8], end: usize) -> Option<usize> {
+        self.search(needle_rev, end, |i| haystack[end - 1 - i], 0)
+            .map(|pos| end - pos - needle_rev.len())
+    }
+
+    fn search(
+        &self,
+        needle: &[u8],
+        haystack_len: usize,
+        haystack_at: impl Fn(usize) -> u8,
+        start: usize,
+    ) -> Option<usize> {
+        let n = needle.len();
+        if n == 0 {
+            return (start <= haystack_len).then_some(start);
+        }
+
+        let mut pos = start;
+        let mut memory = 0;
+        'search: while pos + n <= haystack_len {
+            // Right half first: a mismatch here lets us skip past it entirely.
+            let right_start = if self.short_period {
+                self.crit_pos.max(memory)
+            } else {
+                self.crit_pos
+            };
+            for (i, &b) in needle.iter().enumerate().skip(right_start) {
+                if b != haystack_at(pos + i) {
+                    pos += i - self.crit_pos + 1;
+                    memory = 0;
+                    continue 'search;
+                }
+            }
+
+            // Then the left half, backwards, down to what we already know matches.
+            let left_stop = if self.short_period { memory } else { 0 };
+            for i in (left_stop..self.crit_pos).rev() {
+                if needle[i] != haystack_at(pos + i) {
+                    pos += self.period;
+                    if self.short_period {
+                        memory = n - self.period;
+                    }
+                    continue 'search;
+                }
+            }
+
+            return Some(pos);
+        }
+
+        None
+    }
+}
+
+/// Compute the maximal suffix of `needle` under `<` (or `>` when `order_greater`),
+/// returning its start and period.
+fn maximal_suffix(needle: &[u8], order_greater: bool) -> (usize, usize) {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+
+    while let Some(&a) = needle.get(right + offset) {
+        let b = needle[left + offset];
+        if (a < b && !order_greater) || (a > b && order_greater) {
+            // Suffix is smaller, the period is the whole prefix so far.
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            // Still walking through a repetition of the current period.
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // Suffix is larger, restart from here.
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+
+    (left, period)
+}
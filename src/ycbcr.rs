@@ -0,0 +1,467 @@
+//! RGB to YCbCr and back, `LANES` pixels at a time, with planar output and optional
+//! chroma subsampling, for handing frames to JPEG and video encoders.
+//!
+//! Same approach as the greyscale kernel: the matrix is rounded to fixed point so
+//! that each row keeps its exact sum. That's what makes white come out as exactly
+//! 255 (or 235) and every grey with chroma of exactly 128. Chroma needs more than 8
+//! fractional bits to stay within one step, so this uses 14 and `i32` lanes.
+
+use std::{
+    fmt,
+    simd::{
+        Simd, Swizzle,
+        cmp::SimdOrd,
+        num::{SimdInt, SimdUint},
+    },
+};
+
+use crate::{
+    LOGICAL_LANES_, LumaStandard,
+    pixel::{
+        Argb, B, Bgr, Bgra, Channel, G, Layout, PixelLayout, R, Rgb, Rgba, interleave, pixel_count,
+    },
+};
+
+const LANES: usize = LOGICAL_LANES_ * 4; // (there's 4 u8s of bits in an f32)
+
+/// Fractional bits of the fixed-point matrices.
+const SHIFT: i32 = 14;
+const ONE: f64 = (1 << SHIFT) as f64;
+
+/// Which luma weights the colour difference channels are built around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YcbcrMatrix {
+    /// SD video and JPEG.
+    #[default]
+    Bt601,
+    /// HD video.
+    Bt709,
+}
+
+impl YcbcrMatrix {
+    pub fn luma_standard(self) -> LumaStandard {
+        match self {
+            Self::Bt601 => LumaStandard::Bt601,
+            Self::Bt709 => LumaStandard::Bt709,
+        }
+    }
+}
+
+impl fmt::Display for YcbcrMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Bt601 => "BT.601",
+            Self::Bt709 => "BT.709",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum YcbcrRange {
+    /// Every channel uses all of 0–255, as in JPEG.
+    #[default]
+    Full,
+    /// Y in 16–235 and chroma in 16–240, what most video encoders expect.
+    Limited,
+}
+
+impl fmt::Display for YcbcrRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Full => "full",
+            Self::Limited => "limited",
+        };
+        f.pad(name)
+    }
+}
+
+/// How much chroma resolution to keep, as the number of samples in each plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChromaSubsampling {
+    /// Full resolution chroma.
+    #[default]
+    Yuv444,
+    /// Half horizontally.
+    Yuv422,
+    /// Half in both directions, the usual for video.
+    Yuv420,
+}
+
+impl ChromaSubsampling {
+    /// Width and height of the Cb and Cr planes of a `width` by `height` image. Odd
+    /// sizes round up, the last sample covering just the one column or row.
+    pub fn chroma_size(self, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Self::Yuv444 => (width, height),
+            Self::Yuv422 => (width.div_ceil(2), height),
+            Self::Yuv420 => (width.div_ceil(2), height.div_ceil(2)),
+        }
+    }
+}
+
+impl fmt::Display for ChromaSubsampling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Yuv444 => "4:4:4",
+            Self::Yuv422 => "4:2:2",
+            Self::Yuv420 => "4:2:0",
+        };
+        f.pad(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct YcbcrFormat {
+    pub matrix: YcbcrMatrix,
+    pub range: YcbcrRange,
+    pub subsampling: ChromaSubsampling,
+}
+
+/// A planar YCbCr image: a full resolution Y plane, and Cb and Cr planes of
+/// [`ChromaSubsampling::chroma_size`], all row by row with no padding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YcbcrImage {
+    pub width: usize,
+    pub height: usize,
+    pub format: YcbcrFormat,
+    pub y: Vec<u8>,
+    pub cb: Vec<u8>,
+    pub cr: Vec<u8>,
+}
+
+/// `pixels`, a `width` by `height` image in `layout` (alpha is ignored), as YCbCr.
+///
+/// Subsampled chroma is the average of each 2×1 or 2×2 block.
+///
+/// # Panics
+/// If `pixels` isn't `width * height` pixels.
+pub fn simd_rgb_to_ycbcr(
+    pixels: &[u8],
+    layout: PixelLayout,
+    width: usize,
+    height: usize,
+    format: YcbcrFormat,
+) -> YcbcrImage {
+    assert_eq!(
+        pixel_count(pixels, layout),
+        width * height,
+        "expected a {width}x{height} image"
+    );
+
+    let mut y = vec![0; width * height];
+    let mut cb = vec![0; width * height];
+    let mut cr = vec![0; width * height];
+    if y.is_empty() {
+        // Every plane is empty too, whatever the chroma size of the other dimension
+        return YcbcrImage {
+            width,
+            height,
+            format,
+            y,
+            cb,
+            cr,
+        };
+    }
+    let planes = [&mut y[..], &mut cb[..], &mut cr[..]];
+    let matrix = forward(format);
+
+    match layout {
+        PixelLayout::Rgb => encode::<Rgb, { 3 * LANES }>(pixels, planes, &matrix),
+        PixelLayout::Bgr => encode::<Bgr, { 3 * LANES }>(pixels, planes, &matrix),
+        PixelLayout::Rgba => encode::<Rgba, { 4 * LANES }>(pixels, planes, &matrix),
+        PixelLayout::Bgra => encode::<Bgra, { 4 * LANES }>(pixels, planes, &matrix),
+        PixelLayout::Argb => encode::<Argb, { 4 * LANES }>(pixels, planes, &matrix),
+    }
+
+    let cb = downsample(cb, width, height, format.subsampling);
+    let cr = downsample(cr, width, height, format.subsampling);
+    YcbcrImage {
+        width,
+        height,
+        format,
+        y,
+        cb,
+        cr,
+    }
+}
+
+/// `image` back to pixels in `layout`, with opaque alpha if it has any.
+///
+/// Subsampled chroma is repeated over the block it came from.
+///
+/// # Panics
+/// If the planes aren't the sizes `image`'s dimensions and format call for.
+pub fn simd_ycbcr_to_rgb(image: &YcbcrImage, layout: PixelLayout) -> Vec<u8> {
+    let (width, height) = (image.width, image.height);
+    let subsampling = image.format.subsampling;
+    let (chroma_width, chroma_height) = subsampling.chroma_size(width, height);
+    assert_eq!(image.y.len(), width * height, "Y plane is the wrong size");
+    assert_eq!(
+        (image.cb.len(), image.cr.len()),
+        (chroma_width * chroma_height, chroma_width * chroma_height),
+        "chroma planes are the wrong size"
+    );
+
+    let bpp = layout.bytes_per_pixel();
+    let mut output = vec![0; width * height * bpp];
+    if output.is_empty() {
+        return output;
+    }
+    let matrix = inverse(image.format);
+
+    // Chroma rows stretched back out to the full width
+    let mut cb_row = vec![0; width];
+    let mut cr_row = vec![0; width];
+
+    for (row, out) in output.chunks_exact_mut(width * bpp).enumerate() {
+        let chroma_row = match subsampling {
+            ChromaSubsampling::Yuv420 => row / 2,
+            _ => row,
+        };
+        let cb = &image.cb[chroma_row * chroma_width..][..chroma_width];
+        let cr = &image.cr[chroma_row * chroma_width..][..chroma_width];
+        let (cb, cr) = if subsampling == ChromaSubsampling::Yuv444 {
+            (cb, cr)
+        } else {
+            upsample(cb, &mut cb_row);
+            upsample(cr, &mut cr_row);
+            (&cb_row[..], &cr_row[..])
+        };
+
+        let planes = [&image.y[row * width..][..width], cb, cr];
+        match layout {
+            PixelLayout::Rgb => decode::<Rgb, { 3 * LANES }>(planes, out, &matrix),
+            PixelLayout::Bgr => decode::<Bgr, { 3 * LANES }>(planes, out, &matrix),
+            PixelLayout::Rgba => decode::<Rgba, { 4 * LANES }>(planes, out, &matrix),
+            PixelLayout::Bgra => decode::<Bgra, { 4 * LANES }>(planes, out, &matrix),
+            PixelLayout::Argb => decode::<Argb, { 4 * LANES }>(planes, out, &matrix),
+        }
+    }
+
+    output
+}
+
+/// A 3×3 fixed-point matrix, what to take off each input before it and what to add
+/// to each output after, in whole steps.
+struct Matrix {
+    inputs: [i32; 3],
+    rows: [[i32; 3]; 3],
+    offsets: [i32; 3],
+}
+
+/// RGB to YCbCr.
+fn forward(format: YcbcrFormat) -> Matrix {
+    let [kr, kg, kb] = format.matrix.luma_standard().coefficients();
+    let (y_scale, c_scale, y_offset) = match format.range {
+        YcbcrRange::Full => (1.0, 1.0, 0),
+        YcbcrRange::Limited => (219.0 / 255.0, 224.0 / 255.0, 16),
+    };
+
+    // Cb and Cr are B - Y and R - Y, scaled to span ±half the range
+    let y = [kr, kg, kb].map(|k| k * y_scale);
+    let cb = [-kr, -kg, 1.0 - kb].map(|k| k / (2.0 * (1.0 - kb)) * c_scale);
+    let cr = [1.0 - kr, -kg, -kb].map(|k| k / (2.0 * (1.0 - kr)) * c_scale);
+
+    Matrix {
+        inputs: [0; 3],
+        rows: [fixed_row(y), fixed_row(cb), fixed_row(cr)],
+        offsets: [y_offset, 128, 128],
+    }
+}
+
+/// YCbCr (less its offsets) to RGB.
+fn inverse(format: YcbcrFormat) -> Matrix {
+    let [kr, kg, kb] = format.matrix.luma_standard().coefficients();
+    let (y_scale, c_scale, y_offset) = match format.range {
+        YcbcrRange::Full => (1.0, 1.0, 0),
+        YcbcrRange::Limited => (255.0 / 219.0, 255.0 / 224.0, 16),
+    };
+
+    let cr_r = 2.0 * (1.0 - kr) * c_scale;
+    let cb_b = 2.0 * (1.0 - kb) * c_scale;
+    let cb_g = -2.0 * kb * (1.0 - kb) / kg * c_scale;
+    let cr_g = -2.0 * kr * (1.0 - kr) / kg * c_scale;
+
+    Matrix {
+        inputs: [y_offset, 128, 128],
+        rows: [
+            fixed_row([y_scale, 0.0, cr_r]),
+            fixed_row([y_scale, cb_g, cr_g]),
+            fixed_row([y_scale, cb_b, 0.0]),
+        ],
+        offsets: [0; 3],
+    }
+}
+
+/// `row` in fixed point, each rounded to the nearest but keeping the (rounded) sum.
+fn fixed_row(row: [f64; 3]) -> [i32; 3] {
+    let exact = row.map(|c| c * ONE);
+    let mut fixed = exact.map(|c| c.round() as i32);
+
+    // As with the luma weights, the total can be a step off, so take it from (or
+    // give it to) whichever coefficient rounding moved furthest that way.
+    let total = exact.iter().sum::<f64>().round() as i32;
+    let excess = fixed.iter().sum::<i32>() - total;
+    let error = |i: usize| fixed[i] as f64 - exact[i];
+    let by_error = |a: &usize, b: &usize| error(*a).total_cmp(&error(*b));
+    if excess > 0 {
+        let i = (0..3).max_by(by_error).unwrap();
+        fixed[i] -= excess;
+    } else if excess < 0 {
+        let i = (0..3).min_by(by_error).unwrap();
+        fixed[i] -= excess;
+    }
+
+    fixed
+}
+
+/// `matrix` row `row` applied to `inputs` (less their offsets), rounded and clamped
+/// to a byte.
+#[inline(always)]
+fn weigh(inputs: &[Simd<i32, LANES>; 3], matrix: &Matrix, row: usize) -> Simd<u8, LANES> {
+    let [a, b, c] = matrix.rows[row].map(Simd::splat);
+    let [x, y, z] = matrix.inputs.map(Simd::splat);
+    let inputs = [inputs[0] - x, inputs[1] - y, inputs[2] - z];
+    let offset = Simd::splat((matrix.offsets[row] << SHIFT) + (1 << (SHIFT - 1)));
+    let sum = inputs[0] * a + inputs[1] * b + inputs[2] * c + offset;
+    (sum >> SHIFT)
+        .simd_clamp(Simd::splat(0), Simd::splat(255))
+        .cast::<u8>()
+}
+
+/// `LANES` pixels at a time into full resolution Y, Cb and Cr planes, `W` being that
+/// many `L` pixels in bytes.
+fn encode<L: Layout, const W: usize>(pixels: &[u8], mut planes: [&mut [u8]; 3], matrix: &Matrix) {
+    let convert = |chunk: &[u8]| {
+        let pixels = Simd::<u8, W>::from_slice(chunk);
+        let rgb = [
+            Channel::<L, R>::swizzle(pixels).cast::<i32>(),
+            Channel::<L, G>::swizzle(pixels).cast::<i32>(),
+            Channel::<L, B>::swizzle(pixels).cast::<i32>(),
+        ];
+        [0, 1, 2].map(|row| weigh(&rgb, matrix, row))
+    };
+
+    let chunks = pixels.chunks_exact(W);
+    let rest = chunks.remainder();
+    let full = pixels.len() / W * LANES;
+    for (i, chunk) in chunks.enumerate() {
+        for (plane, values) in planes.iter_mut().zip(convert(chunk)) {
+            values.copy_to_slice(&mut plane[i * LANES..][..LANES]);
+        }
+    }
+
+    // Pad the last few out to a whole chunk
+    if !rest.is_empty() {
+        let mut padded = [0; W];
+        padded[..rest.len()].copy_from_slice(rest);
+        for (plane, values) in planes.into_iter().zip(convert(&padded)) {
+            let tail = &mut plane[full..];
+            tail.copy_from_slice(&values.as_array()[..tail.len()]);
+        }
+    }
+}
+
+/// One row of full resolution Y, Cb and Cr to `L` pixels, `LANES` at a time.
+fn decode<L: Layout, const W: usize>(planes: [&[u8]; 3], output: &mut [u8], matrix: &Matrix) {
+    let opaque = Simd::splat(255);
+    let convert = |[y, cb, cr]: [&[u8]; 3]| {
+        let load = |plane: &[u8]| Simd::<u8, LANES>::from_slice(plane).cast::<i32>();
+        let ycbcr = [load(y), load(cb), load(cr)];
+        let [r, g, b] = [0, 1, 2].map(|row| weigh(&ycbcr, matrix, row));
+        interleave::<_, L, W>(r, g, b, opaque)
+    };
+
+    let len = planes[0].len();
+    let mut out_chunks = output.chunks_exact_mut(W);
+    let mut x = 0;
+    for out in &mut out_chunks {
+        convert(planes.map(|plane| &plane[x..x + LANES])).copy_to_slice(out);
+        x += LANES;
+    }
+
+    // Pad the last few out to a whole chunk
+    let tail = out_chunks.into_remainder();
+    if x < len {
+        let padded = planes.map(|plane| {
+            let mut padded = [0; LANES];
+            padded[..len - x].copy_from_slice(&plane[x..]);
+            padded
+        });
+        let pixels = convert(padded.each_ref().map(|plane| &plane[..]));
+        tail.copy_from_slice(&pixels.as_array()[..tail.len()]);
+    }
+}
+
+/// `plane`, full resolution chroma, averaged down to `subsampling`'s size.
+fn downsample(
+    plane: Vec<u8>,
+    width: usize,
+    height: usize,
+    subsampling: ChromaSubsampling,
+) -> Vec<u8> {
+    if subsampling == ChromaSubsampling::Yuv444 {
+        return plane;
+    }
+
+    let (chroma_width, chroma_height) = subsampling.chroma_size(width, height);
+    let mut output = vec![0; chroma_width * chroma_height];
+
+    for (row, out) in output.chunks_exact_mut(chroma_width).enumerate() {
+        // 4:2:2 averages a row with itself, which is the same as just the pairs
+        let (top, bottom) = match subsampling {
+            ChromaSubsampling::Yuv420 => (2 * row, (2 * row + 1).min(height - 1)),
+            _ => (row, row),
+        };
+        let top = &plane[top * width..][..width];
+        let bottom = &plane[bottom * width..][..width];
+
+        let mut x = 0;
+        while 2 * (x + LANES) <= width {
+            let sum = |row: &[u8]| -> Simd<u16, LANES> {
+                let pairs = Simd::<u8, { 2 * LANES }>::from_slice(&row[2 * x..][..2 * LANES]);
+                Pair::<0>::swizzle(pairs).cast::<u16>() + Pair::<1>::swizzle(pairs).cast::<u16>()
+            };
+            let average = (sum(top) + sum(bottom) + Simd::splat(2)) >> 2;
+            average.cast::<u8>().copy_to_slice(&mut out[x..x + LANES]);
+            x += LANES;
+        }
+
+        // The rest, where an odd width's last sample is its one column twice
+        for (x, out) in out.iter_mut().enumerate().skip(x) {
+            let (left, right) = (2 * x, (2 * x + 1).min(width - 1));
+            let sum =
+                top[left] as u16 + top[right] as u16 + bottom[left] as u16 + bottom[right] as u16;
+            *out = ((sum + 2) >> 2) as u8;
+        }
+    }
+
+    output
+}
+
+/// Each sample of a subsampled chroma row twice over, to fill `row`.
+fn upsample(chroma: &[u8], row: &mut [u8]) {
+    let (pairs, last) = row.as_chunks_mut::<2>();
+    for (pair, &c) in pairs.iter_mut().zip(chroma) {
+        *pair = [c; 2];
+    }
+    if let [last] = last {
+        *last = chroma[chroma.len() - 1];
+    }
+}
+
+/// Every other byte, starting from `OFFSET`.
+struct Pair<const OFFSET: usize>;
+
+impl<const OFFSET: usize, const N: usize> Swizzle<N> for Pair<OFFSET> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = 2 * i + OFFSET;
+            i += 1;
+        }
+        index
+    };
+}